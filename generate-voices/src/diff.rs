@@ -0,0 +1,281 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::PriceType;
+use crate::parse::{VoiceData, VoiceSnapshot};
+
+/// Price change of a whole voice type, or of a voice that moved to another type
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Repricing {
+    VoiceType {
+        voice_type: String,
+        old: Option<PriceType>,
+        new: Option<PriceType>,
+    },
+    Voice {
+        voice: VoiceData,
+        old_type: String,
+        old: Option<PriceType>,
+        new: Option<PriceType>,
+    },
+}
+
+/// A change that removes or renames public items of the generated modules
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Breaking {
+    /// The voice's enum variant is gone
+    RemovedVoice { voice: VoiceData },
+    /// The voice's enum variant moved to another type or gender module
+    MovedVoice { from: VoiceData, to: VoiceData },
+    /// The language's module, `Language` variant and feature are gone
+    RemovedLanguage { language_code: String },
+}
+
+/// Changes between two voice snapshots
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CatalogDiff {
+    pub old_generated_at: String,
+    pub new_generated_at: String,
+    pub added: Vec<VoiceData>,
+    /// Voices no longer listed, whether or not they're kept as deprecated
+    pub removed: Vec<VoiceData>,
+    pub repriced: Vec<Repricing>,
+    pub breaking: Vec<Breaking>,
+}
+
+impl CatalogDiff {
+    /// Compares the voices listed in two snapshots.
+    ///
+    /// Removed voices that `new` keeps as deprecated aren't breaking.
+    pub fn between(old: &VoiceSnapshot, new: &VoiceSnapshot) -> Self {
+        let by_key = |voices: &[VoiceData]| {
+            voices
+                .iter()
+                .map(|voice| (voice.key(), voice.clone()))
+                .collect::<BTreeMap<_, _>>()
+        };
+        let (old_voices, new_voices) = (by_key(&old.voices), by_key(&new.voices));
+        let deprecated = by_key(&new.deprecated);
+        let mut diff = Self {
+            old_generated_at: old.generated_at.clone(),
+            new_generated_at: new.generated_at.clone(),
+            ..Default::default()
+        };
+
+        let voice_types = old.pricing.keys().chain(new.pricing.keys());
+        for voice_type in voice_types.collect::<BTreeSet<_>>() {
+            let (old_price, new_price) = (old.pricing.get(voice_type), new.pricing.get(voice_type));
+            if old_price != new_price {
+                diff.repriced.push(Repricing::VoiceType {
+                    voice_type: voice_type.clone(),
+                    old: old_price.copied(),
+                    new: new_price.copied(),
+                });
+            }
+        }
+
+        for (key, voice) in &new_voices {
+            match old_voices.get(key) {
+                None => diff.added.push(voice.clone()),
+                Some(previous) if previous != voice => {
+                    if previous.voice_type != voice.voice_type {
+                        diff.repriced.push(Repricing::Voice {
+                            voice: voice.clone(),
+                            old_type: previous.voice_type.clone(),
+                            old: old.pricing.get(&previous.voice_type).copied(),
+                            new: new.pricing.get(&voice.voice_type).copied(),
+                        });
+                    }
+                    diff.breaking.push(Breaking::MovedVoice {
+                        from: previous.clone(),
+                        to: voice.clone(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        for (key, voice) in &old_voices {
+            if !new_voices.contains_key(key) {
+                diff.removed.push(voice.clone());
+                if !deprecated.contains_key(key) {
+                    diff.breaking.push(Breaking::RemovedVoice {
+                        voice: voice.clone(),
+                    });
+                }
+            }
+        }
+
+        let languages = |snapshot: &VoiceSnapshot| {
+            snapshot
+                .voices
+                .iter()
+                .chain(&snapshot.deprecated)
+                .map(|voice| voice.language_code.clone())
+                .collect::<BTreeSet<_>>()
+        };
+        for language_code in languages(old).difference(&languages(new)) {
+            diff.breaking.push(Breaking::RemovedLanguage {
+                language_code: language_code.clone(),
+            });
+        }
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.repriced.is_empty()
+            && self.breaking.is_empty()
+    }
+}
+
+impl Display for CatalogDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn price(price: &Option<PriceType>) -> String {
+            price.map_or("unpriced".to_string(), |p| format!("${p}"))
+        }
+        fn voice(voice: &VoiceData) -> String {
+            format!(
+                "{} {} ({}, {})",
+                voice.language_code,
+                voice.id(),
+                voice.voice_type,
+                voice.gender
+            )
+        }
+
+        writeln!(
+            f,
+            "Voice catalog changes from {} to {}:",
+            self.old_generated_at, self.new_generated_at
+        )?;
+        if self.is_empty() {
+            return writeln!(f, "  none");
+        }
+
+        writeln!(f, "Added: {}", self.added.len())?;
+        for added in &self.added {
+            writeln!(f, "  + {}", voice(added))?;
+        }
+        writeln!(f, "Removed: {}", self.removed.len())?;
+        for removed in &self.removed {
+            writeln!(f, "  - {}", voice(removed))?;
+        }
+        writeln!(f, "Repriced: {}", self.repriced.len())?;
+        for repricing in &self.repriced {
+            match repricing {
+                Repricing::VoiceType {
+                    voice_type,
+                    old,
+                    new,
+                } => writeln!(
+                    f,
+                    "  ~ {voice_type} voices: {} -> {}",
+                    price(old),
+                    price(new)
+                )?,
+                Repricing::Voice {
+                    voice,
+                    old_type,
+                    old,
+                    new,
+                } => writeln!(
+                    f,
+                    "  ~ {} {}: {old_type} {} -> {} {}",
+                    voice.language_code,
+                    voice.id(),
+                    price(old),
+                    voice.voice_type,
+                    price(new)
+                )?,
+            }
+        }
+        writeln!(f, "Breaking: {}", self.breaking.len())?;
+        for breaking in &self.breaking {
+            match breaking {
+                Breaking::RemovedVoice { voice: removed } => {
+                    writeln!(f, "  ! removed {}", voice(removed))?
+                }
+                Breaking::MovedVoice { from, to } => writeln!(
+                    f,
+                    "  ! moved {} {} from {} {} to {} {}",
+                    to.language_code,
+                    to.id(),
+                    from.voice_type,
+                    from.gender,
+                    to.voice_type,
+                    to.gender
+                )?,
+                Breaking::RemovedLanguage { language_code } => {
+                    writeln!(f, "  ! removed language {language_code}")?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> VoiceSnapshot {
+        VoiceSnapshot::from_json(include_str!("../fixtures/voices.json")).unwrap()
+    }
+
+    #[test]
+    fn test_no_changes() {
+        let diff = CatalogDiff::between(&snapshot(), &snapshot());
+        assert!(diff.is_empty());
+        assert!(diff.to_string().ends_with("  none\n"));
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = snapshot();
+        let mut new = snapshot();
+        new.generated_at = "2025-06-01 10:00".to_string();
+        new.pricing.insert("Neural".to_string(), 0.004);
+        // Geraint is removed, Joanna moves to the neural voices, Matthew is added
+        new.voices.retain(|voice| voice.voice_name != "Geraint");
+        new.voices
+            .iter_mut()
+            .find(|voice| voice.voice_name == "Joanna")
+            .unwrap()
+            .voice_type = "Neural".to_string();
+        let mut matthew = new.voices[0].clone();
+        matthew.voice_name = "Matthew".to_string();
+        new.voices.push(matthew.clone());
+
+        let diff = CatalogDiff::between(&old, &new);
+        assert_eq!(diff.added, [matthew]);
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.repriced.len(), 2);
+        assert!(matches!(
+            &diff.repriced[1],
+            Repricing::Voice { old_type, old: Some(_), new: Some(_), .. } if old_type == "Standard"
+        ));
+        assert_eq!(diff.breaking.len(), 3);
+        assert!(matches!(
+            &diff.breaking[2],
+            Breaking::RemovedLanguage { language_code } if language_code == "en-GB-WLS"
+        ));
+
+        let report = diff.to_string();
+        assert!(report.contains("  ~ Neural voices: $0.0032 -> $0.004\n"));
+        assert!(report.contains("  ! removed en-GB-WLS Polly.Geraint (Standard, Male)\n"));
+
+        // Deprecating the removed voice keeps its variant and language
+        new.carry_deprecated(&old, true);
+        let diff = CatalogDiff::between(&old, &new);
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.breaking.len(), 1);
+        assert!(matches!(diff.breaking[0], Breaking::MovedVoice { .. }));
+    }
+}
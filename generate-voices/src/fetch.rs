@@ -0,0 +1,43 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use headless_chrome::{Browser, LaunchOptions};
+
+use crate::TWILIO_DOC_URL;
+
+/// Fetches HTML from Twilio docs using headless Chrome
+/// Uses a local cache to avoid refetching the same page multiple times per day
+pub fn fetch_html() -> Result<String, Box<dyn Error>> {
+    let today = chrono::Local::now().format("%Y%m%d").to_string();
+    let cache_path = format!("/tmp/voices_{today}.html");
+
+    // Check for cached version first
+    if Path::new(&cache_path).exists() {
+        println!("Using cached HTML file: {cache_path}");
+        let mut file = File::open(&cache_path)?;
+        let mut html_content = String::new();
+        file.read_to_string(&mut html_content)?;
+        return Ok(html_content);
+    }
+
+    println!("Cache not found, fetching: {TWILIO_DOC_URL} ...");
+    let options = LaunchOptions {
+        headless: true,
+        ..Default::default()
+    };
+    let browser = Browser::new(options)?;
+    let tab = browser.new_tab()?;
+
+    tab.navigate_to(TWILIO_DOC_URL)?;
+    println!("Waiting for navigation...");
+    tab.wait_until_navigated()?;
+
+    let html_content = tab.get_content()?;
+    let mut cache_file = File::create(&cache_path)?;
+    cache_file.write_all(html_content.as_bytes())?;
+    println!("Cached HTML to: {cache_path}");
+
+    Ok(html_content)
+}
@@ -0,0 +1,880 @@
+use std::collections::{BTreeMap, HashSet};
+use std::error::Error;
+use std::fmt::Write as FmtWrite;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use convert_case::{Case, Casing};
+
+use crate::parse::{VoiceData, VoiceSnapshot};
+use crate::{PriceType, TWILIO_DOC_URL};
+
+/// Derive macros for the top-level Voice enum, which can hold an unknown voice id
+const VOICE_ENUM_DERIVE: &str = "#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]";
+/// Common Rust derive macros for voice enums
+const ENUM_DERIVE: &str = "#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]";
+const ENUM_DERIVE_DISPLAY: &str =
+    "#[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]";
+
+/// Creates the directory structure and generates all voice module files
+pub fn generate_voice_module_structure(
+    dir: &Path,
+    snapshot: &VoiceSnapshot,
+) -> Result<(), Box<dyn Error>> {
+    std::fs::create_dir_all(dir)?;
+
+    // Generate main mod.rs first, which returns language groups for further processing
+    let voices = snapshot
+        .voices
+        .iter()
+        .chain(&snapshot.deprecated)
+        .cloned()
+        .collect::<HashSet<_>>();
+    let deprecated = snapshot
+        .deprecated
+        .iter()
+        .map(VoiceData::key)
+        .collect::<HashSet<_>>();
+    let lang_groups = generate_main_file(
+        dir,
+        &snapshot.generated_at,
+        snapshot.pricing.clone(),
+        &voices,
+    )?;
+
+    // Then generate a module file for each language
+    for (lang_code, voices_in_lang) in &lang_groups {
+        generate_lang_file(dir, lang_code, voices_in_lang, &deprecated)?;
+    }
+
+    Ok(())
+}
+
+// /// Generates gender alias convenience modules
+// fn generate_gender_aliases(
+//     lang_file: &mut String,
+//     type_groups: &BTreeMap<String, Vec<&VoiceData>>,
+// ) -> Result<(), Box<dyn Error>> {
+//     // Collect all genders first
+//     let mut all_genders = HashSet::new();
+//     for voices in type_groups.values() {
+//         for voice in voices {
+//             all_genders.insert(voice.gender.clone());
+//         }
+//     }
+
+//     let mut all_genders: Vec<String> = all_genders.into_iter().collect();
+//     all_genders.sort();
+
+//     // For each gender, generate alias modules
+//     for gender in all_genders {
+//         writeln!(lang_file, "\npub mod {} {{", gender.to_case(Case::Snake))?;
+
+//         // For each voice type that has this gender
+//         for (voice_type, voices) in type_groups {
+//             // Check if this voice type has voices of the current gender
+//             let voices_with_gender: Vec<_> = voices.iter().filter(|v| v.gender == gender).collect();
+
+//             if voices_with_gender.is_empty() {
+//                 continue;
+//             }
+
+//             let voice_type_module = voice_type.to_case(Case::Snake);
+
+//             writeln!(lang_file, "    pub mod {voice_type_module} {{",)?;
+
+//             // Group by provider
+//             let provider_map = group_voices_by(&voices_with_gender, |v| v.provider.clone());
+
+//             // For each provider
+//             for (provider, provider_voices) in provider_map {
+//                 let provider_module = provider.to_case(Case::Snake);
+//                 writeln!(lang_file, "        pub mod {provider_module} {{",)?;
+//                 writeln!(
+//                     lang_file,
+//                     "            use super::super::super::{voice_type_module}::{provider_module}::*;",
+//                 )?;
+
+//                 // For each voice in this provider/gender combo
+//                 let mut voices: Vec<_> = provider_voices
+//                     .iter()
+//                     .map(|v| extract_short_name(&v.voice_name))
+//                     .collect();
+//                 voices.sort();
+
+//                 for voice in &voices {
+//                     writeln!(
+//                         lang_file,
+//                         "            pub const {voice}: {gender} = {gender}::{voice};",
+//                     )?;
+//                 }
+
+//                 writeln!(lang_file, "        }}")?;
+//             }
+
+//             writeln!(lang_file, "    }}")?;
+//         }
+
+//         writeln!(lang_file, "}}")?;
+//     }
+
+//     Ok(())
+// }
+
+/// Generates a language-specific module file with voice types, providers, and genders
+/// Voices whose key is in `deprecated` are generated as `#[deprecated]` variants and left out
+/// of the catalog.
+fn generate_lang_file(
+    dir: &Path,
+    lang_code: &str,
+    voices_in_lang: &[&VoiceData],
+    deprecated: &HashSet<(String, String)>,
+) -> Result<(), Box<dyn Error>> {
+    let module_name = lang_code.to_case(Case::Snake);
+    let lang_variant = lang_code.to_case(Case::Pascal);
+
+    let mut lang_file = String::new();
+
+    // Group voices by type (Standard, Neural, Generative)
+    let type_groups = group_voices_by(voices_in_lang, |v| v.voice_type.clone());
+
+    let is_deprecated =
+        |full_name: &str| deprecated.contains(&(lang_code.to_string(), full_name.to_string()));
+
+    writeln!(lang_file)?;
+    writeln!(lang_file, "#![allow(non_upper_case_globals)]")?;
+    if voices_in_lang
+        .iter()
+        .any(|voice| is_deprecated(&voice.id()))
+    {
+        // Generated code still refers to the deprecated variants
+        writeln!(lang_file, "#![allow(deprecated)]")?;
+    }
+    writeln!(lang_file)?;
+    writeln!(
+        lang_file,
+        "use crate::{{PriceType, twiml::{{Gender, VoiceGender, VoicePrice, voices::{{Language, Provider, VoiceInfo, VoiceTier, "
+    )?;
+    for voice_type in type_groups.keys() {
+        writeln!(
+            lang_file,
+            "{}_VOICE_PRICE,",
+            voice_type.to_case(Case::Constant)
+        )?;
+    }
+    writeln!(lang_file, "}},}},}};\n")?;
+    writeln!(lang_file, "use serde::{{Serialize, Deserialize}};\n")?;
+
+    // Catalog entries for every voice, in the order they're generated
+    let mut catalog_entries = Vec::new();
+
+    // Generate modules for each voice type (Standard, Neural, Generative)
+    for (voice_type, voices_of_type) in &type_groups {
+        let type_module = voice_type.to_case(Case::Snake);
+        writeln!(lang_file, "pub mod {type_module} {{\n    use super::*;\n")?;
+
+        // Group voices by provider (Amazon, Google, etc.)
+        let provider_groups = group_voices_by(voices_of_type, |v| v.provider.clone());
+
+        // Generate modules for each provider
+        for (provider, voices_by_provider) in &provider_groups {
+            let provider_module = provider.to_case(Case::Snake);
+
+            // Group voices by gender for this provider
+            let mut gender_maps: BTreeMap<&str, BTreeMap<String, String>> = BTreeMap::new();
+
+            for voice in voices_by_provider {
+                let variant_name = extract_short_name(&voice.voice_name);
+                if variant_name.is_empty() {
+                    panic!(
+                        "variant_name is empty! voice.voice_name: {}",
+                        voice.voice_name
+                    );
+                }
+
+                let gender_map = gender_maps.entry(&voice.gender).or_default();
+                if !gender_map.contains_key(&variant_name) {
+                    gender_map.insert(
+                        variant_name.clone(),
+                        format!("{}.{}", voice.provider, voice.voice_name),
+                    );
+                }
+            }
+
+            writeln!(
+                lang_file,
+                "    pub mod {provider_module} {{\n        use super::*;\n"
+            )?;
+
+            // Generate enums for each gender
+            for (gender, voice_map) in &gender_maps {
+                if voice_map.is_empty() {
+                    continue;
+                }
+                writeln!(lang_file, "        {ENUM_DERIVE_DISPLAY}")?;
+                writeln!(lang_file, "        #[non_exhaustive]")?;
+                writeln!(lang_file, "        pub enum {gender} {{")?;
+                let mut keys: Vec<_> = voice_map.keys().collect();
+                keys.sort();
+                for key in keys {
+                    let full_name = &voice_map[key];
+                    if is_deprecated(full_name) {
+                        writeln!(
+                            lang_file,
+                            "            #[deprecated(note = \"no longer listed by Twilio\")]"
+                        )?;
+                    } else {
+                        catalog_entries.push(format!(
+                        r#"VoiceInfo {{
+                            voice: crate::twiml::Voice::{lang_variant}(Voice::{voice_type}({type_module}::Voice::{provider}(
+                                {type_module}::{provider_module}::Voice::{gender}({type_module}::{provider_module}::{gender}::{key}),
+                            ))),
+                            id: "{full_name}",
+                            language: Language::{lang_variant},
+                            gender: Gender::{gender},
+                            provider: Provider::{provider},
+                            tier: VoiceTier::{voice_type},
+                        }},"#
+                    ));
+                    }
+                    writeln!(lang_file, "            #[serde(rename = \"{full_name}\")]")?;
+                    writeln!(
+                        lang_file,
+                        "            #[strum(to_string = \"{full_name}\")]"
+                    )?;
+                    writeln!(lang_file, "            {key},")?;
+                }
+                writeln!(lang_file, "        }}\n")?;
+
+                write_voice_price_impl(&mut lang_file, gender, Some(voice_type), None)?;
+                write_voice_gender_impl(
+                    &mut lang_file,
+                    gender,
+                    Some(&format!("Gender::{gender}")),
+                    None,
+                )?;
+
+                writeln!(
+                    lang_file,
+                    r#"
+                        impl From<{gender}> for crate::twiml::Voice {{
+                            fn from(value: {gender}) -> Self {{
+                                Self::{lang_variant}(super::super::Voice::{voice_type}(super::Voice::{provider}(
+                                    Voice::{gender}(value),
+                                )))
+                            }}
+                        }}
+                    "#
+                )?;
+            }
+
+            // Create a Voice enum that contains all gender variants
+            writeln!(lang_file, "        {ENUM_DERIVE}")?;
+            writeln!(lang_file, "        #[serde(untagged)]")?;
+            writeln!(lang_file, "        pub enum Voice {{")?;
+
+            let mut gender_arms = Vec::new();
+            for gender in gender_maps.keys() {
+                writeln!(lang_file, "            {gender}({gender}),")?;
+
+                gender_arms.push((
+                    None,
+                    format!("Voice::{gender}(_)"),
+                    format!("Gender::{gender}"),
+                ));
+            }
+            writeln!(lang_file, "        }}\n")?;
+
+            write_voice_price_impl(&mut lang_file, "Voice", Some(voice_type), None)?;
+
+            write_voice_gender_impl(&mut lang_file, "Voice", None, Some(&gender_arms))?;
+
+            writeln!(lang_file, "    }}\n")?;
+        }
+
+        // Create a Voice enum that contains all provider variants
+        writeln!(lang_file, "     {ENUM_DERIVE}")?;
+        writeln!(lang_file, "    #[serde(untagged)]")?;
+        writeln!(lang_file, "    pub enum Voice {{")?;
+
+        for (provider, voices_by_provider) in &provider_groups {
+            if voices_by_provider.is_empty() {
+                continue;
+            }
+
+            let provider_module = provider.to_case(Case::Snake);
+            let variant_name = provider_module.to_case(Case::Pascal);
+            writeln!(
+                lang_file,
+                "        {variant_name}({provider_module}::Voice),"
+            )?;
+        }
+        writeln!(lang_file, "    }}\n")?;
+
+        write_voice_price_impl(&mut lang_file, "Voice", Some(voice_type), None)?;
+
+        let mut gender_arms = Vec::new();
+        for provider in provider_groups.keys() {
+            gender_arms.push((
+                None,
+                format!("Voice::{provider}(voice)"),
+                "voice.gender()".to_string(),
+            ));
+        }
+
+        write_voice_gender_impl(&mut lang_file, "Voice", None, Some(&gender_arms))?;
+
+        writeln!(lang_file, "}}\n")?;
+    }
+
+    // Create a top-level Voice enum for this language
+    writeln!(lang_file, "{ENUM_DERIVE}")?;
+    writeln!(lang_file, "#[serde(untagged)]")?;
+    writeln!(lang_file, "pub enum Voice {{")?;
+    for (voice_type, groups) in &type_groups {
+        if groups.is_empty() {
+            continue;
+        }
+
+        let variant_name = voice_type.to_case(Case::Pascal);
+        writeln!(
+            lang_file,
+            "    {variant_name}({}::Voice),",
+            voice_type.to_case(Case::Snake)
+        )?;
+    }
+    writeln!(lang_file, "}}")?;
+
+    // Implement price calculation for the language's Voice enum
+    let mut price_arms = Vec::new();
+    let mut gender_arms = Vec::new();
+
+    for voice_type in type_groups.keys() {
+        let voice_type_const = voice_type.to_case(Case::Constant);
+        price_arms.push((
+            None,
+            format!("Voice::{voice_type}(_)"),
+            format!("crate::price_type_from_f64_ok({voice_type_const}_VOICE_PRICE)"),
+        ));
+        gender_arms.push((
+            None,
+            format!("Voice::{voice_type}(voice)"),
+            "voice.gender()".to_string(),
+        ));
+    }
+
+    write_voice_price_impl(&mut lang_file, "Voice", None, Some(&price_arms))?;
+    write_voice_gender_impl(&mut lang_file, "Voice", None, Some(&gender_arms))?;
+
+    // Generate gender-based alias modules for easier access
+    // generate_gender_aliases(&mut lang_file, &type_groups)?;
+
+    // Catalog of every voice for this language
+    writeln!(
+        lang_file,
+        "/// Every voice for this language, see [`crate::twiml::Voice::all`]"
+    )?;
+    writeln!(lang_file, "pub const VOICES: &[VoiceInfo] = &[")?;
+    for entry in &catalog_entries {
+        writeln!(lang_file, "    {entry}")?;
+    }
+    writeln!(lang_file, "];")?;
+
+    // Write the file to disk
+    File::create(dir.join(format!("{module_name}.rs")))?.write_all(lang_file.as_bytes())?;
+    Ok(())
+}
+
+/// Generates the main mod.rs file with language-specific modules and price constants
+fn generate_main_file<'a>(
+    dir: &Path,
+    now: &str,
+    pricing: BTreeMap<String, PriceType>,
+    voices: &'a HashSet<VoiceData>,
+) -> Result<BTreeMap<String, Vec<&'a VoiceData>>, Box<dyn Error>> {
+    // Group voices by language code
+    let lang_groups: BTreeMap<String, Vec<&VoiceData>> =
+        voices.iter().fold(BTreeMap::new(), |mut map, voice| {
+            map.entry(voice.language_code.clone())
+                .or_default()
+                .push(voice);
+            map
+        });
+
+    let mut main_file = String::new();
+    writeln!(
+        main_file,
+        "// Auto-generated at: {now}\n// Source: {TWILIO_DOC_URL}"
+    )?;
+    writeln!(main_file, "#![allow(non_local_definitions)]\n")?;
+    writeln!(main_file, "use crate::PriceType;\n")?;
+
+    // Voice types from cheapest to most expensive
+    let mut tiers = pricing.iter().collect::<Vec<_>>();
+    tiers.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+    let tiers = tiers
+        .into_iter()
+        .map(|(voice_type, _)| voice_type.clone())
+        .collect::<Vec<_>>();
+
+    // Generate price constants for each voice type (Standard, Neural, Generative)
+    for (voice_type, price_per_100_chars) in pricing {
+        writeln!(
+            main_file,
+            "/// Current price of {voice_type} voices per 100 chars as of {now} UTC"
+        )?;
+        writeln!(
+            main_file,
+            "pub const {}_VOICE_PRICE: f64 = {price_per_100_chars};",
+            voice_type.to_case(Case::Constant)
+        )?;
+    }
+
+    writeln!(main_file)?;
+
+    // Generate module imports for each language with feature flags
+    let mut lang_codes: Vec<_> = lang_groups.keys().collect();
+    lang_codes.sort();
+
+    for lang_code in &lang_codes {
+        let module_name = lang_code.to_case(Case::Snake);
+        let feature_name = lang_code.to_case(Case::Kebab);
+        writeln!(main_file, "#[cfg(feature = \"{feature_name}\")]")?;
+        writeln!(main_file, "pub mod {module_name};")?;
+    }
+    writeln!(main_file, "\nuse serde::{{Serialize, Deserialize}};\n")?;
+
+    // Define the VoicePrice trait for pricing calculations
+    writeln!(
+        main_file,
+        r#"
+        pub trait VoicePrice {{
+            /// Cost of the voice per 100 characters (rounded down per call)
+            fn price(&self) -> Option<PriceType>;
+        }}
+    "#
+    )?;
+
+    let mut genders = voices
+        .iter()
+        .map(|v| v.gender.clone())
+        .collect::<HashSet<_>>()
+        .iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    genders.sort();
+    let genders = genders.join(", ");
+
+    // Define the VoiceGender trait to provide the gender of the voice
+    writeln!(
+        main_file,
+        r#"
+        {ENUM_DERIVE_DISPLAY}
+        #[non_exhaustive]
+        #[strum(serialize_all = "kebab-case")]
+        #[serde(rename = "kebab-case")]
+        pub enum Gender {{
+            {genders}
+        }}
+
+        pub trait VoiceGender {{
+            /// Gender of the voice
+            fn gender(&self) -> Gender;
+        }}
+    "#
+    )?;
+
+    // Create the top-level Language enum
+    writeln!(main_file, "{ENUM_DERIVE_DISPLAY}")?;
+    writeln!(main_file, "#[non_exhaustive]")?;
+    writeln!(main_file, "pub enum Language {{")?;
+    for lang_code in &lang_codes {
+        let module_name = lang_code.to_case(Case::Snake);
+        let variant_name = module_name.to_case(Case::Pascal);
+        let feature_name = lang_code.to_case(Case::Kebab);
+        writeln!(main_file, "    #[cfg(feature = \"{feature_name}\")]")?;
+        writeln!(main_file, "    #[strum(to_string = \"{lang_code}\")]")?;
+        writeln!(main_file, "    #[serde(rename = \"{lang_code}\")]")?;
+        writeln!(main_file, "    {variant_name},")?;
+    }
+    writeln!(main_file, "}}\n")?;
+
+    // List the compiled-in languages
+    writeln!(main_file, "impl Language {{")?;
+    writeln!(main_file, "    /// Every compiled-in language")?;
+    writeln!(main_file, "    pub const ALL: &[Language] = &[")?;
+    for lang_code in &lang_codes {
+        let feature_name = lang_code.to_case(Case::Kebab);
+        writeln!(main_file, "        #[cfg(feature = \"{feature_name}\")]")?;
+        writeln!(
+            main_file,
+            "        Language::{},",
+            lang_code.to_case(Case::Pascal)
+        )?;
+    }
+    writeln!(main_file, "    ];")?;
+    writeln!(main_file, "}}\n")?;
+
+    // Create the Provider and VoiceTier enums and the VoiceInfo catalog entry
+    let mut providers = voices
+        .iter()
+        .map(|v| v.provider.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    providers.sort();
+    let providers = providers.join(", ");
+    let tier_arms = tiers
+        .iter()
+        .map(|tier| {
+            (
+                None,
+                format!("VoiceTier::{tier}"),
+                format!(
+                    "crate::price_type_from_f64_ok({}_VOICE_PRICE)",
+                    tier.to_case(Case::Constant)
+                ),
+            )
+        })
+        .collect::<Vec<_>>();
+    let tiers = tiers.join(", ");
+    writeln!(
+        main_file,
+        r#"
+        /// Text-to-speech engine behind a voice
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum Provider {{
+            {providers}
+        }}
+
+        /// Pricing tier of a voice, from cheapest to most expensive
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum VoiceTier {{
+            {tiers}
+        }}
+
+        /// Catalog entry describing a voice, see [`Voice::all`] and [`Voice::info`]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct VoiceInfo {{
+            pub voice: Voice,
+            /// Value of the `voice` attribute, e.g. `Polly.Joanna-Neural`
+            pub id: &'static str,
+            pub language: Language,
+            pub gender: Gender,
+            pub provider: Provider,
+            pub tier: VoiceTier,
+        }}
+
+        impl VoicePrice for VoiceInfo {{
+            fn price(&self) -> Option<PriceType> {{
+                self.tier.price()
+            }}
+        }}
+
+        impl VoiceGender for VoiceInfo {{
+            fn gender(&self) -> Gender {{
+                self.gender
+            }}
+        }}
+    "#
+    )?;
+    write_voice_price_impl(&mut main_file, "VoiceTier", None, Some(&tier_arms))?;
+
+    // Create the top-level Voice enum with variants for each language
+    writeln!(main_file, "{VOICE_ENUM_DERIVE}")?;
+    writeln!(main_file, "#[non_exhaustive]")?;
+    writeln!(main_file, "pub enum Voice {{")?;
+    writeln!(
+        main_file,
+        r#"#[serde(alias = "man")] Man, #[serde(alias = "woman")] Woman,"#
+    )?;
+    for lang_code in &lang_codes {
+        let module_name = lang_code.to_case(Case::Snake);
+        let variant_name = module_name.to_case(Case::Pascal);
+        let feature_name = lang_code.to_case(Case::Kebab);
+        writeln!(main_file, "    #[cfg(feature = \"{feature_name}\")]")?;
+        writeln!(main_file, "    #[serde(untagged)]")?;
+        writeln!(main_file, "    {variant_name}({module_name}::Voice),")?;
+    }
+    writeln!(
+        main_file,
+        r#"
+        /// A voice id that isn't in the catalog of the compiled-in languages, kept as-is
+        #[serde(untagged)]
+        Other(String),"#
+    )?;
+    writeln!(main_file, "}}\n")?;
+
+    // Implement FromStr for Voice:
+    writeln!(
+        main_file,
+        r#"
+        impl std::str::FromStr for Voice {{
+            type Err = serde_plain::Error;
+
+            /// Unknown voice ids become [`Voice::Other`], see [`Voice::from_str_strict`]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {{
+                serde_plain::from_str(s)
+            }}
+        }}
+
+        impl Voice {{
+            /// Like [`Voice::from_str`](std::str::FromStr::from_str), but fails for voice
+            /// ids that aren't in the catalog of the compiled-in languages.
+            pub fn from_str_strict(s: &str) -> Result<Self, serde_plain::Error> {{
+                match serde_plain::from_str(s)? {{
+                    Voice::Other(id) => Err(serde::de::Error::custom(format!("unknown voice: {{id}}"))),
+                    voice => Ok(voice),
+                }}
+            }}
+        }}
+    "#
+    )?;
+
+    // Implement Display for Voice:
+    writeln!(
+        main_file,
+        r#"
+        impl std::fmt::Display for Voice {{
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                serde_plain::to_string(self)
+                    .map_err(|_| std::fmt::Error)
+                    .and_then(|s| write!(f, "{{s}}"))
+            }}
+        }}
+    "#
+    )?;
+
+    // Implement the VoicePrice trait for the Voice enum
+    let mut price_arms = Vec::new();
+    let mut gender_arms = Vec::new();
+
+    for gender in ["Man", "Woman"] {
+        price_arms.push((
+            None,
+            format!("Voice::{gender}"),
+            "crate::price_type_from_f64_ok(0.)".to_string(),
+        ));
+        gender_arms.push((
+            None,
+            format!("Voice::{gender}"),
+            format!(
+                "Gender::{}",
+                if gender == "Woman" { "Female" } else { "Male" }
+            ),
+        ));
+    }
+
+    for lang_code in &lang_codes {
+        let variant_name = lang_code.to_case(Case::Pascal);
+        let lang_code_snake = lang_code.to_case(Case::Snake);
+        let feature_name = lang_code.to_case(Case::Kebab);
+        price_arms.push((
+            Some(feature_name.clone()),
+            format!("Voice::{variant_name}({lang_code_snake})"),
+            format!("{lang_code_snake}.price()"),
+        ));
+        gender_arms.push((
+            Some(feature_name),
+            format!("Voice::{variant_name}({lang_code_snake})"),
+            format!("{lang_code_snake}.gender()"),
+        ));
+    }
+
+    price_arms.push((None, "Voice::Other(_)".to_string(), "None".to_string()));
+    write_voice_price_impl(&mut main_file, "Voice", None, Some(&price_arms))?;
+
+    // The gender of an unknown voice isn't known, so Voice doesn't implement VoiceGender
+    writeln!(main_file, "impl Voice {{")?;
+    writeln!(
+        main_file,
+        "    /// Gender of the voice, `None` for [`Voice::Other`]"
+    )?;
+    writeln!(main_file, "    pub fn gender(&self) -> Option<Gender> {{")?;
+    writeln!(main_file, "        match self {{")?;
+    for (cfg_feature, pattern, result) in &gender_arms {
+        if let Some(feature_name) = cfg_feature {
+            writeln!(
+                main_file,
+                r#"            #[cfg(feature = "{feature_name}")]"#
+            )?;
+        }
+        writeln!(main_file, "            {pattern} => Some({result}),")?;
+    }
+    writeln!(main_file, "            Voice::Other(_) => None,")?;
+    writeln!(main_file, "        }}")?;
+    writeln!(main_file, "    }}")?;
+    writeln!(main_file, "}}\n")?;
+
+    // Catalog of the voices of every compiled-in language
+    writeln!(main_file, "const CATALOG: &[&[VoiceInfo]] = &[")?;
+    for lang_code in &lang_codes {
+        let feature_name = lang_code.to_case(Case::Kebab);
+        writeln!(main_file, "    #[cfg(feature = \"{feature_name}\")]")?;
+        writeln!(main_file, "    {}::VOICES,", lang_code.to_case(Case::Snake))?;
+    }
+    writeln!(main_file, "];\n")?;
+
+    let mut info_arms = String::new();
+    for lang_code in &lang_codes {
+        let feature_name = lang_code.to_case(Case::Kebab);
+        writeln!(info_arms, "#[cfg(feature = \"{feature_name}\")]")?;
+        writeln!(
+            info_arms,
+            "Voice::{}(_) => {}::VOICES.iter().find(|info| info.voice == *self),",
+            lang_code.to_case(Case::Pascal),
+            lang_code.to_case(Case::Snake)
+        )?;
+    }
+    writeln!(
+        main_file,
+        r#"
+        impl Voice {{
+            /// Every voice of the compiled-in languages, grouped by language.
+            ///
+            /// The basic `man` and `woman` voices aren't tied to a language and aren't included.
+            pub fn all() -> impl Iterator<Item = &'static VoiceInfo> {{
+                CATALOG.iter().flat_map(|voices| voices.iter())
+            }}
+
+            /// Catalog entry for this voice, `None` for the basic `man` and `woman` voices, for
+            /// deprecated voices Twilio no longer lists and for [`Voice::Other`].
+            pub fn info(&self) -> Option<&'static VoiceInfo> {{
+                match self {{
+                    Voice::Man | Voice::Woman | Voice::Other(_) => None,
+                    {info_arms}
+                }}
+            }}
+        }}
+    "#
+    )?;
+
+    // Write the file to disk
+    File::create(dir.join("mod.rs"))?.write_all(main_file.as_bytes())?;
+    Ok(lang_groups)
+}
+
+/// Creates a canonical shortened name from a full voice name (e.g., "Chirp3HdZephyr" from "en-US-Chirp3-HD-Zephyr")
+fn extract_short_name(voice_name: &str) -> String {
+    let parts = voice_name.split('-');
+    if parts.clone().count() < 3 {
+        return voice_name.to_case(Case::Pascal);
+    }
+
+    // Skip provider locale name parts, keep only the actual voice name
+    parts
+        .skip(2)
+        .collect::<Vec<&str>>()
+        .join("")
+        .to_case(Case::Pascal)
+}
+
+/// Groups voices by a key function and returns a BTreeMap of groups
+fn group_voices_by<F, K, V>(voices: &[V], key_fn: F) -> BTreeMap<K, Vec<V>>
+where
+    F: Fn(&V) -> K,
+    K: Ord + Clone,
+    V: Clone,
+{
+    let mut groups: BTreeMap<K, Vec<V>> = BTreeMap::new();
+    for voice in voices {
+        groups.entry(key_fn(voice)).or_default().push(voice.clone());
+    }
+    groups
+}
+
+/// Implements the VoicePrice trait for various voice types
+/// Handles both direct voice type pricing and complex match-based pricing
+fn write_voice_price_impl(
+    output: &mut String,
+    type_name: &str,
+    voice_type: Option<&str>,
+    match_arms: Option<&[(Option<String>, String, String)]>,
+) -> Result<(), Box<dyn Error>> {
+    writeln!(output, "    impl VoicePrice for {type_name} {{")?;
+    writeln!(output, "        fn price(&self) -> Option<PriceType> {{")?;
+
+    if let Some(arms) = match_arms {
+        writeln!(output, "            match self {{")?;
+        for (cfg_feature, pattern, result) in arms {
+            if let Some(feature_name) = cfg_feature {
+                writeln!(
+                    output,
+                    r#"                #[cfg(feature = "{feature_name}")]"#
+                )?;
+            }
+            writeln!(output, "                {pattern} => {result},")?;
+        }
+        writeln!(output, "            }}")?;
+    } else {
+        writeln!(
+            output,
+            "            crate::price_type_from_f64_ok({}_VOICE_PRICE)",
+            voice_type.unwrap().to_case(Case::Constant)
+        )?;
+    }
+
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}\n")?;
+
+    Ok(())
+}
+
+/// Implements the VoiceGender trait for various voice types
+fn write_voice_gender_impl(
+    output: &mut String,
+    type_name: &str,
+    gender: Option<&str>,
+    match_arms: Option<&[(Option<String>, String, String)]>,
+) -> Result<(), Box<dyn Error>> {
+    writeln!(output, "    impl VoiceGender for {type_name} {{")?;
+    writeln!(output, "        fn gender(&self) -> Gender {{")?;
+
+    if let Some(arms) = match_arms {
+        writeln!(output, "            match self {{")?;
+        for (cfg_feature, pattern, result) in arms {
+            if let Some(feature_name) = cfg_feature {
+                writeln!(
+                    output,
+                    r#"                #[cfg(feature = "{feature_name}")]"#
+                )?;
+            }
+            writeln!(output, "                {pattern} => {result},")?;
+        }
+        writeln!(output, "            }}")?;
+    } else {
+        writeln!(output, "            {}", gender.unwrap())?;
+    }
+
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}\n")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_from_snapshot() {
+        let snapshot = VoiceSnapshot::from_json(include_str!("../fixtures/voices.json")).unwrap();
+        let dir = std::env::temp_dir().join(format!("generate-voices-{}", std::process::id()));
+        generate_voice_module_structure(&dir, &snapshot).unwrap();
+
+        let main_file = std::fs::read_to_string(dir.join("mod.rs")).unwrap();
+        assert!(main_file.starts_with("// Auto-generated at: 2025-05-25 22:10\n"));
+        assert!(main_file.contains("pub const NEURAL_VOICE_PRICE: f64 = 0.0032;"));
+        assert!(main_file.contains("pub mod en_gb_wls;"));
+
+        let en_us = std::fs::read_to_string(dir.join("en_us.rs")).unwrap();
+        assert!(en_us.contains(r#"#[serde(rename = "Polly.Kevin-Neural")]"#));
+        assert!(en_us.contains("pub enum MaleChild"));
+        assert!(en_us.contains(r#"id: "Google.en-US-Neural2-A","#));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,302 @@
+use std::collections::{BTreeMap, HashSet};
+
+use convert_case::{Case, Casing};
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+
+use crate::PriceType;
+
+/// Represents a single voice option with its metadata
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct VoiceData {
+    pub language_code: String,
+    pub voice_type: String,
+    pub gender: String,
+    pub provider: String,
+    pub voice_name: String,
+}
+
+impl VoiceData {
+    /// Value of the `voice` attribute, e.g. `Polly.Joanna-Neural`
+    pub fn id(&self) -> String {
+        format!("{}.{}", self.provider, self.voice_name)
+    }
+
+    /// Identifies the voice across snapshots, whatever its type or gender
+    pub fn key(&self) -> (String, String) {
+        (self.language_code.clone(), self.id())
+    }
+}
+
+/// Voice data and pricing as parsed from Twilio's documentation, saved as JSON so voice
+/// modules can be regenerated without fetching the page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoiceSnapshot {
+    /// When the data was fetched (UTC), written into the generated modules
+    pub generated_at: String,
+    /// Price per 100 characters by voice type
+    pub pricing: BTreeMap<String, PriceType>,
+    /// Sorted so snapshots diff cleanly
+    pub voices: Vec<VoiceData>,
+    /// Voices Twilio no longer lists that are still generated, as `#[deprecated]`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deprecated: Vec<VoiceData>,
+}
+
+impl VoiceSnapshot {
+    pub fn new(
+        generated_at: String,
+        pricing: BTreeMap<String, PriceType>,
+        voices: HashSet<VoiceData>,
+    ) -> Self {
+        let mut voices = voices.into_iter().collect::<Vec<_>>();
+        voices.sort();
+        Self {
+            generated_at,
+            pricing,
+            voices,
+            deprecated: vec![],
+        }
+    }
+
+    /// Keeps the deprecated voices of `previous` that aren't listed again and, with
+    /// `deprecate_removed`, deprecates the voices it listed that are gone now.
+    pub fn carry_deprecated(&mut self, previous: &VoiceSnapshot, deprecate_removed: bool) {
+        let listed = self
+            .voices
+            .iter()
+            .map(VoiceData::key)
+            .collect::<HashSet<_>>();
+        let mut deprecated = previous.deprecated.clone();
+        if deprecate_removed {
+            deprecated.extend(previous.voices.iter().cloned());
+        }
+        deprecated.retain(|voice| !listed.contains(&voice.key()));
+        deprecated.sort();
+        deprecated.dedup();
+        self.deprecated = deprecated;
+    }
+
+    /// Parses a saved copy of Twilio's documentation page
+    pub fn from_html(generated_at: String, html: String) -> Self {
+        let (pricing, voices) = parse_html_into_voices(html);
+        Self::new(generated_at, pricing, voices)
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Extracts voice data and pricing information from Twilio's documentation HTML
+pub fn parse_html_into_voices(html: String) -> (BTreeMap<String, PriceType>, HashSet<VoiceData>) {
+    let document = Html::parse_document(&html);
+    let row_selector = Selector::parse("table tbody tr").unwrap();
+    let cell_selector = Selector::parse("td").unwrap();
+
+    // Extract pricing information from the HTML
+    let mut pricing = BTreeMap::new();
+
+    // Process pricing tables
+    let table_selector = Selector::parse("table").unwrap();
+    let header_selector = Selector::parse("th").unwrap();
+
+    // First extract pricing information from tables containing price data
+    for table in document.select(&table_selector) {
+        // Check if this is a pricing table by examining headers
+        let headers: Vec<_> = table.select(&header_selector).collect();
+        if headers.len() < 3 {
+            continue;
+        }
+        let header_texts: Vec<String> = headers
+            .iter()
+            .map(|h| h.text().collect::<String>().trim().to_string())
+            .collect();
+
+        if !header_texts.contains(&"Price per 100 characters".to_string()) {
+            // not a pricing table
+            continue;
+        }
+
+        // extract the base pricing tier (starting from 0 characters)
+        for row in table.select(&row_selector) {
+            let cells: Vec<_> = row.select(&cell_selector).collect();
+            if cells.len() < 3 {
+                continue;
+            }
+
+            let min_chars = cells[0].text().collect::<String>().trim().to_string();
+
+            if min_chars == "0" {
+                let price_text = cells[2].text().collect::<String>().trim().to_string();
+                let price = price_text
+                    .strip_prefix('$')
+                    .expect("no text in expected price column")
+                    .parse::<PriceType>()
+                    .expect("failed to parse expected price");
+
+                // Navigate up to find the section ID to determine voice type (Standard/Neural/Generative)
+                let mut parent = table.parent();
+                while let Some(node) = parent {
+                    if let Some(element) = node.value().as_element()
+                        && element.name() == "section"
+                    {
+                        if let Some(id) = element.id() {
+                            if id.contains("standard-voices") {
+                                pricing.insert("Standard".to_string(), price);
+                            } else if id.contains("neural-voices") {
+                                pricing.insert("Neural".to_string(), price);
+                            } else if id.contains("generative-voices") {
+                                pricing.insert("Generative".to_string(), price);
+                            }
+                        }
+                        break;
+                    }
+                    parent = node.parent();
+                }
+            }
+        }
+    }
+
+    // Then extract voice data from voice tables
+    let mut all_voices = HashSet::new();
+    for row in document.select(&row_selector) {
+        let cells: Vec<_> = row.select(&cell_selector).collect();
+        if cells.len() < 6 {
+            continue;
+        }
+
+        let language_code = cells[1].text().collect::<String>().trim().to_string();
+        if language_code.is_empty() {
+            continue;
+        }
+
+        let voice_type = cells[2].text().collect::<String>().trim().to_string();
+        if voice_type == "Basic" || voice_type.is_empty() {
+            continue;
+        }
+
+        let gender = cells[3]
+            .text()
+            .collect::<String>()
+            .trim()
+            .replace(['(', ')'], " ")
+            .to_case(Case::Pascal);
+
+        let provider = cells[4].text().collect::<String>().trim().to_string();
+
+        let voice_name = cells[5]
+            .text()
+            .collect::<String>()
+            .replace('*', "")
+            .trim()
+            .to_string();
+        if voice_name.is_empty() {
+            continue;
+        }
+
+        all_voices.insert(VoiceData {
+            language_code,
+            voice_type,
+            gender,
+            provider,
+            voice_name,
+        });
+    }
+
+    (pricing, all_voices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = include_str!("../fixtures/voices.html");
+    const JSON: &str = include_str!("../fixtures/voices.json");
+
+    fn voice(
+        language_code: &str,
+        voice_type: &str,
+        gender: &str,
+        provider: &str,
+        voice_name: &str,
+    ) -> VoiceData {
+        VoiceData {
+            language_code: language_code.to_string(),
+            voice_type: voice_type.to_string(),
+            gender: gender.to_string(),
+            provider: provider.to_string(),
+            voice_name: voice_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_pricing() {
+        let (pricing, _) = parse_html_into_voices(HTML.to_string());
+        assert_eq!(
+            pricing,
+            BTreeMap::from([
+                ("Generative".to_string(), 0.013),
+                ("Neural".to_string(), 0.0032),
+                ("Standard".to_string(), 0.0008),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_voices() {
+        let (_, voices) = parse_html_into_voices(HTML.to_string());
+        assert_eq!(
+            voices,
+            HashSet::from([
+                voice("en-US", "Standard", "Female", "Polly", "Joanna"),
+                voice("en-US", "Neural", "Female", "Polly", "Joanna-Neural"),
+                voice("en-US", "Neural", "MaleChild", "Polly", "Kevin-Neural"),
+                voice("en-US", "Neural", "Male", "Google", "en-US-Neural2-A"),
+                voice("en-GB-WLS", "Standard", "Male", "Polly", "Geraint"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_carry_deprecated() {
+        let previous = VoiceSnapshot::from_json(JSON).unwrap();
+        let mut current = previous.clone();
+        current.voices.retain(|voice| voice.voice_name != "Geraint");
+
+        current.carry_deprecated(&previous, false);
+        assert!(current.deprecated.is_empty());
+
+        current.carry_deprecated(&previous, true);
+        assert_eq!(
+            current.deprecated,
+            [voice("en-GB-WLS", "Standard", "Male", "Polly", "Geraint")]
+        );
+
+        // Deprecated voices are kept until they're listed again
+        let mut next = current.clone();
+        next.carry_deprecated(&current, false);
+        assert_eq!(next.deprecated, current.deprecated);
+        next.voices = previous.voices.clone();
+        next.carry_deprecated(&current, false);
+        assert!(next.deprecated.is_empty());
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let snapshot = VoiceSnapshot::from_json(JSON).unwrap();
+        assert_eq!(
+            snapshot,
+            VoiceSnapshot::from_html("2025-05-25 22:10".to_string(), HTML.to_string())
+        );
+        assert!(snapshot.voices.is_sorted());
+        assert_eq!(
+            VoiceSnapshot::from_json(&snapshot.to_json().unwrap()).unwrap(),
+            snapshot
+        );
+    }
+}
@@ -0,0 +1,392 @@
+//! actix-web integration: a signature validating middleware and a [`FromRequest`] extractor
+//! for [`twilio::Request`](crate::twilio::Request). [`twiml::Response`](crate::twiml::Response)
+//! is a [`Responder`](actix_web::Responder).
+//!
+//! ```no_run
+//! use actix_web::{App, HttpServer, web};
+//! use twilio_voice::{actix::TwilioValidation, twilio, twiml};
+//!
+//! async fn voice(request: twilio::Request) -> twiml::Response {
+//!     twiml::Response::builder().build()
+//! }
+//!
+//! # async fn run() -> std::io::Result<()> {
+//! HttpServer::new(|| {
+//!     App::new()
+//!         .wrap(TwilioValidation::new("auth token"))
+//!         .route("/voice", web::post().to(voice))
+//! })
+//! .bind(("0.0.0.0", 8080))?
+//! .run()
+//! .await
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::future::{Ready, ready};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{Payload, Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use actix_web::error::ErrorBadRequest;
+use actix_web::web::Bytes;
+use actix_web::{FromRequest, HttpMessage, HttpRequest, HttpResponse};
+use futures_util::StreamExt;
+use futures_util::future::LocalBoxFuture;
+use http::Method;
+use tracing::warn;
+use url::form_urlencoded;
+
+use crate::twilio;
+use crate::twilio::{
+    AuthTokens, ForwardedOrigin, RequestValidatorOptions, TrustedProxies, TwilioRequest,
+    validate_incoming_request,
+};
+
+/// Largest body [`TwilioValidation`] reads by default
+pub const DEFAULT_MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// An actix-web request and its body, for [`validate_incoming_request`]
+#[derive(Debug, Clone)]
+pub struct ActixTwilioRequest {
+    method: Method,
+    protocol: String,
+    host: String,
+    path_and_query: String,
+    twilio_signature: Option<String>,
+    body: Bytes,
+}
+
+impl ActixTwilioRequest {
+    /// Honours the forwarding headers of `trusted_proxies` when the peer is one of them
+    pub fn new(request: &HttpRequest, body: Bytes, trusted_proxies: &TrustedProxies) -> Self {
+        let headers = request.headers();
+        let forwarded = request
+            .peer_addr()
+            .and_then(|peer| {
+                // actix-web uses http 0.2
+                let headers = headers
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        Some((
+                            http::HeaderName::from_bytes(name.as_str().as_bytes()).ok()?,
+                            http::HeaderValue::from_bytes(value.as_bytes()).ok()?,
+                        ))
+                    })
+                    .collect();
+                trusted_proxies.origin(peer.ip(), &headers)
+            })
+            .unwrap_or_default();
+        let ForwardedOrigin { proto, host } = forwarded;
+        let config = request.app_config();
+
+        Self {
+            method: Method::from_bytes(request.method().as_str().as_bytes()).unwrap_or_default(),
+            protocol: proto
+                .unwrap_or_else(|| if config.secure() { "https" } else { "http" }.to_string()),
+            host: host
+                .or_else(|| {
+                    headers
+                        .get("host")
+                        .and_then(|host| host.to_str().ok())
+                        .map(String::from)
+                })
+                .unwrap_or_else(|| config.host().to_string()),
+            path_and_query: request
+                .uri()
+                .path_and_query()
+                .map(|path_and_query| path_and_query.to_string())
+                .unwrap_or_else(|| "/".to_string()),
+            twilio_signature: headers
+                .get("x-twilio-signature")
+                .and_then(|signature| signature.to_str().ok())
+                .map(String::from),
+            body,
+        }
+    }
+}
+
+impl TwilioRequest for ActixTwilioRequest {
+    fn protocol(&self) -> String {
+        self.protocol.clone()
+    }
+
+    fn host(&self) -> String {
+        self.host.clone()
+    }
+
+    fn path_and_query(&self) -> String {
+        self.path_and_query.clone()
+    }
+
+    fn twilio_signature(&self) -> Option<String> {
+        self.twilio_signature.clone()
+    }
+
+    fn body(&self) -> BTreeMap<String, String> {
+        form_urlencoded::parse(&self.body).into_owned().collect()
+    }
+
+    fn raw_body(&self) -> Option<String> {
+        std::str::from_utf8(&self.body).ok().map(String::from)
+    }
+
+    fn method(&self) -> Method {
+        self.method.clone()
+    }
+}
+
+/// Middleware rejecting requests without a valid Twilio signature with `403 Forbidden`.
+///
+/// Handlers get the [`ValidationOutcome`](crate::twilio::ValidationOutcome) in the request
+/// extensions.
+#[derive(Clone)]
+pub struct TwilioValidation {
+    auth_tokens: Arc<dyn AuthTokens>,
+    options: RequestValidatorOptions,
+    trusted_proxies: TrustedProxies,
+    max_body_size: usize,
+}
+
+impl TwilioValidation {
+    pub fn new(auth_token: &str) -> Self {
+        Self::with_tokens(auth_token.to_string())
+    }
+
+    pub fn with_options(auth_token: &str, options: RequestValidatorOptions) -> Self {
+        Self::with_tokens_and_options(auth_token.to_string(), options)
+    }
+
+    /// `auth_tokens` is several tokens during a rotation, or a provider like
+    /// [`RotatingAuthTokens`](crate::twilio::RotatingAuthTokens)
+    pub fn with_tokens(auth_tokens: impl AuthTokens + 'static) -> Self {
+        Self::with_tokens_and_options(auth_tokens, Default::default())
+    }
+
+    pub fn with_tokens_and_options(
+        auth_tokens: impl AuthTokens + 'static,
+        options: RequestValidatorOptions,
+    ) -> Self {
+        Self {
+            auth_tokens: Arc::new(auth_tokens),
+            options,
+            trusted_proxies: Default::default(),
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+        }
+    }
+
+    /// Reconstructs the webhook URL from the forwarding headers of these proxies. The
+    /// `host` and `protocol` options still take precedence.
+    pub fn trusted_proxies(mut self, trusted_proxies: TrustedProxies) -> Self {
+        self.trusted_proxies = trusted_proxies;
+        self
+    }
+
+    /// Larger bodies are rejected with `413 Payload Too Large`
+    pub fn max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for TwilioValidation
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Transform = TwilioValidationMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(TwilioValidationMiddleware {
+            service: Rc::new(service),
+            validation: self.clone(),
+        }))
+    }
+}
+
+pub struct TwilioValidationMiddleware<S> {
+    service: Rc<S>,
+    validation: TwilioValidation,
+}
+
+impl<S, B> Service<ServiceRequest> for TwilioValidationMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut request: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let validation = self.validation.clone();
+
+        Box::pin(async move {
+            let reject = |request: ServiceRequest, response: HttpResponse| {
+                Ok(request.into_response(response).map_into_right_body())
+            };
+
+            // Collect the body, up to the limit
+            let mut payload = request.take_payload();
+            let mut body = Vec::new();
+            while let Some(chunk) = payload.next().await {
+                let chunk = match chunk {
+                    Ok(chunk) => chunk,
+                    Err(error) => {
+                        // The reason in debug builds only, like `default_rejection`
+                        let mut response = HttpResponse::BadRequest();
+                        let response = if cfg!(debug_assertions) {
+                            response.body(format!("Failed to read body: {error}"))
+                        } else {
+                            response.finish()
+                        };
+                        return reject(request, response);
+                    }
+                };
+                if body.len() + chunk.len() > validation.max_body_size {
+                    return reject(request, HttpResponse::PayloadTooLarge().finish());
+                }
+                body.extend_from_slice(&chunk);
+            }
+            let body = Bytes::from(body);
+
+            let twilio_request = ActixTwilioRequest::new(
+                request.request(),
+                body.clone(),
+                &validation.trusted_proxies,
+            );
+            let outcome = validate_incoming_request(
+                &twilio_request,
+                &*validation.auth_tokens,
+                validation.options.clone(),
+            );
+            if !outcome.is_allowed() {
+                warn!("Rejected Twilio request: {outcome:?}");
+                return reject(request, HttpResponse::Forbidden().finish());
+            }
+
+            // Put the body back for the handler
+            request.set_payload(Payload::from(body));
+            request.extensions_mut().insert(outcome);
+            let response = service.call(request).await?;
+            Ok(response.map_into_left_body())
+        })
+    }
+}
+
+impl FromRequest for twilio::Request {
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    /// The form body, or the query string of `GET` requests
+    fn from_request(request: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let request = request.clone();
+        let body = Bytes::from_request(&request, payload);
+        Box::pin(async move {
+            let params = if request.method() == actix_web::http::Method::GET {
+                Bytes::copy_from_slice(request.query_string().as_bytes())
+            } else {
+                body.await?
+            };
+            serde_urlencoded::from_bytes(&params).map_err(ErrorBadRequest)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::{App, test, web};
+    use futures::executor::block_on;
+
+    use crate::twilio::{CallStatus, TwilioRequestSigner};
+    use crate::twiml;
+
+    use super::*;
+
+    const TEST_AUTH_TOKEN: &str = "aaf98aa0a69a870c2e5a0e774af3f8b2";
+
+    async fn voice(request: twilio::Request) -> twiml::Response {
+        assert_eq!(request.call_sid, "CA123");
+        twiml::Response::builder().build()
+    }
+
+    /// A signed request as actix-web test request
+    fn signed(method: Method, url: &str) -> test::TestRequest {
+        let call = twilio::Request {
+            call_sid: "CA123".to_string(),
+            call_status: CallStatus::Ringing,
+            ..Default::default()
+        };
+        let request = TwilioRequestSigner::new(TEST_AUTH_TOKEN)
+            .request(method.clone(), url, &call)
+            .unwrap();
+        let mut test_request = test::TestRequest::default()
+            .method(actix_web::http::Method::from_bytes(method.as_str().as_bytes()).unwrap())
+            .uri(&request.uri().to_string())
+            .insert_header(("host", "example.com"))
+            .set_payload(request.body().clone());
+        for (name, value) in request.headers() {
+            test_request = test_request.insert_header((name.as_str(), value.to_str().unwrap()));
+        }
+        test_request
+    }
+
+    #[test]
+    fn test_middleware_and_extractor() {
+        let auth_token = TEST_AUTH_TOKEN.to_string();
+        block_on(async {
+            let app = test::init_service(
+                App::new()
+                    .wrap(TwilioValidation::new(&auth_token))
+                    .route("/voice", web::post().to(voice))
+                    .route("/voice", web::get().to(voice)),
+            )
+            .await;
+
+            let response = test::call_service(
+                &app,
+                signed(Method::POST, "https://example.com/voice").to_request(),
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers().get("content-type").unwrap(), "text/xml");
+            let body = test::read_body(response).await;
+            assert_eq!(body, twiml::Response::builder().build().to_xml());
+
+            let response = test::call_service(
+                &app,
+                signed(Method::GET, "https://example.com/voice").to_request(),
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let forged = signed(Method::POST, "https://example.com/voice")
+                .insert_header(("x-twilio-signature", "forged"))
+                .to_request();
+            let response = test::call_service(&app, forged).await;
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+            let app = test::init_service(
+                App::new()
+                    .wrap(TwilioValidation::new(TEST_AUTH_TOKEN).max_body_size(8))
+                    .route("/voice", web::post().to(voice)),
+            )
+            .await;
+            let response = test::call_service(
+                &app,
+                signed(Method::POST, "https://example.com/voice").to_request(),
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        });
+    }
+}
@@ -0,0 +1,225 @@
+//! axum integration: the [`TwilioWebhook`] extractor, used behind
+//! [`TwilioLayer`](crate::twilio::middleware::TwilioLayer).
+//! [`twiml::Response`](crate::twiml::Response) implements `IntoResponse`.
+//!
+//! ```no_run
+//! use axum::{Router, routing::post};
+//! use twilio_voice::{
+//!     axum::TwilioWebhook,
+//!     twilio::{self, middleware::TwilioLayer},
+//!     twiml,
+//! };
+//!
+//! async fn voice(TwilioWebhook(request): TwilioWebhook<twilio::Request>) -> twiml::Response {
+//!     twiml::Response::builder().hangup().build()
+//! }
+//!
+//! let app: Router = Router::new()
+//!     .route("/voice", post(voice).get(voice))
+//!     .layer(TwilioLayer::new("auth token"));
+//! ```
+
+use ::axum::body::Bytes;
+use ::axum::extract::{FromRequest, Request};
+use ::axum::http::{Method, StatusCode, header};
+use ::axum::response::{IntoResponse, Response};
+use serde::de::DeserializeOwned;
+
+use crate::twilio::ValidationOutcome;
+use crate::twilio::middleware::BufferedBody;
+use crate::twiml;
+
+/// A webhook payload validated by [`TwilioLayer`](crate::twilio::middleware::TwilioLayer)
+/// and decoded from the query string of `GET` requests, a JSON body, or a form body.
+///
+/// `T` is [`twilio::Request`](crate::twilio::Request), a status callback, or any other
+/// deserializable payload. The body buffered by the layer is reused rather than read again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwilioWebhook<T>(pub T);
+
+/// Why a [`TwilioWebhook`] couldn't be extracted
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum WebhookRejection {
+    /// The route isn't behind `TwilioLayer`, so nothing was validated
+    #[error("request wasn't validated, is TwilioLayer missing?")]
+    NotValidated,
+    /// The signature is invalid and enforced
+    #[error("invalid Twilio signature")]
+    Invalid(ValidationOutcome),
+    #[error("failed to read body: {0}")]
+    Body(String),
+    #[error("failed to decode webhook: {0}")]
+    Decode(String),
+}
+
+impl WebhookRejection {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            WebhookRejection::NotValidated => StatusCode::INTERNAL_SERVER_ERROR,
+            WebhookRejection::Invalid(_) => StatusCode::FORBIDDEN,
+            WebhookRejection::Body(_) | WebhookRejection::Decode(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    /// TwiML ending the call, for handlers taking `Result<TwilioWebhook<T>, WebhookRejection>`
+    /// that would rather hang up than have Twilio announce an application error
+    pub fn twiml(&self) -> twiml::Response {
+        twiml::Response::builder().hangup().build()
+    }
+}
+
+impl IntoResponse for WebhookRejection {
+    /// The status, with the reason as body in debug builds only
+    fn into_response(self) -> Response {
+        if cfg!(debug_assertions) {
+            (self.status(), self.to_string()).into_response()
+        } else {
+            self.status().into_response()
+        }
+    }
+}
+
+impl<S, T> FromRequest<S> for TwilioWebhook<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = WebhookRejection;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let outcome = request
+            .extensions()
+            .get::<ValidationOutcome>()
+            .ok_or(WebhookRejection::NotValidated)?;
+        if !outcome.is_allowed() {
+            return Err(WebhookRejection::Invalid(outcome.clone()));
+        }
+
+        if request.method() == Method::GET || request.method() == Method::HEAD {
+            let query = request.uri().query().unwrap_or_default();
+            return serde_urlencoded::from_str(query)
+                .map(TwilioWebhook)
+                .map_err(|error| WebhookRejection::Decode(error.to_string()));
+        }
+
+        let json = request
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .is_some_and(|content_type| content_type.starts_with("application/json"));
+        let body = match request.extensions().get::<BufferedBody>() {
+            Some(BufferedBody(body)) => body.clone(),
+            None => Bytes::from_request(request, state)
+                .await
+                .map_err(|error| WebhookRejection::Body(error.body_text()))?,
+        };
+        let payload = if json {
+            serde_json::from_slice(&body).map_err(|error| error.to_string())
+        } else {
+            serde_urlencoded::from_bytes(&body).map_err(|error| error.to_string())
+        };
+        payload.map(TwilioWebhook).map_err(WebhookRejection::Decode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ::axum::Router;
+    use ::axum::routing::post;
+    use futures::executor::block_on;
+    use http_body_util::BodyExt;
+    use serde::Deserialize;
+    use tower::Service;
+
+    use crate::twilio::middleware::TwilioLayer;
+    use crate::twilio::{self, CallStatus, TwilioRequestSigner};
+
+    use super::*;
+
+    const TEST_AUTH_TOKEN: &str = "aaf98aa0a69a870c2e5a0e774af3f8b2";
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Event {
+        call_sid: String,
+    }
+
+    async fn voice(TwilioWebhook(request): TwilioWebhook<twilio::Request>) -> String {
+        format!("{} {}", request.call_sid, request.call_status)
+    }
+
+    async fn event(TwilioWebhook(event): TwilioWebhook<Event>) -> String {
+        event.call_sid
+    }
+
+    fn call(router: &mut Router, request: http::Request<String>) -> (StatusCode, String) {
+        let response = block_on(router.call(request.map(::axum::body::Body::from))).unwrap();
+        let status = response.status();
+        let body = block_on(response.into_body().collect()).unwrap().to_bytes();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[test]
+    fn test_webhook() {
+        let signer = TwilioRequestSigner::new(TEST_AUTH_TOKEN);
+        let mut router = Router::new()
+            .route("/voice", post(voice).get(voice))
+            .route("/event", post(event))
+            .layer(TwilioLayer::new(TEST_AUTH_TOKEN));
+        let request = twilio::Request {
+            call_sid: "CA123".to_string(),
+            call_status: CallStatus::InProgress,
+            ..Default::default()
+        };
+
+        let post = signer
+            .request(Method::POST, "https://example.com/voice", &request)
+            .unwrap();
+        assert_eq!(
+            call(&mut router, post),
+            (StatusCode::OK, "CA123 in-progress".to_string())
+        );
+
+        let get = signer
+            .form(
+                Method::GET,
+                "https://example.com/voice",
+                &request.to_map().unwrap(),
+            )
+            .unwrap();
+        assert_eq!(call(&mut router, get).1, "CA123 in-progress");
+
+        let json = signer
+            .json("https://example.com/event", r#"{"CallSid":"CA456"}"#)
+            .unwrap();
+        assert_eq!(
+            call(&mut router, json),
+            (StatusCode::OK, "CA456".to_string())
+        );
+
+        let bad = signer
+            .form(Method::POST, "https://example.com/voice", &BTreeMap::new())
+            .unwrap();
+        assert_eq!(call(&mut router, bad).0, StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_without_layer() {
+        let mut router = Router::new().route("/voice", post(voice));
+        let request = TwilioRequestSigner::new(TEST_AUTH_TOKEN)
+            .form(Method::POST, "https://example.com/voice", &BTreeMap::new())
+            .unwrap();
+        assert_eq!(
+            call(&mut router, request).0,
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+        assert!(
+            WebhookRejection::NotValidated
+                .twiml()
+                .to_xml()
+                .contains("<Hangup")
+        );
+    }
+}
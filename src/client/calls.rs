@@ -1,6 +1,8 @@
 use http::Method;
 use serde::Deserialize;
 
+use crate::DtmfScript;
+use crate::errors::DtmfError;
 use crate::twilio::CallStatus;
 
 use super::{TwilioClient, TwilioClientResult};
@@ -12,6 +14,24 @@ pub struct OutboundCall {
     pub url: String,
 }
 
+impl OutboundCall {
+    /// Form parameters of the create request, with `SendDigits` when there are digits
+    fn params(
+        &self,
+        digits: Option<&DtmfScript>,
+    ) -> Result<Vec<(&'static str, String)>, DtmfError> {
+        let mut params = vec![
+            ("To", self.to.clone()),
+            ("From", self.from.clone()),
+            ("Url", self.url.clone()),
+        ];
+        if let Some(digits) = digits {
+            params.push(digits.send_digits()?);
+        }
+        Ok(params)
+    }
+}
+
 #[derive(Debug)]
 pub struct Calls {
     client: TwilioClient,
@@ -19,12 +39,27 @@ pub struct Calls {
 
 impl Calls {
     pub async fn create(&self, call: &OutboundCall) -> TwilioClientResult<Call> {
-        let opts = &[
-            ("To", &*call.to),
-            ("From", &*call.from),
-            ("Url", &*call.url),
-        ];
-        self.client.send_request(Method::POST, "Calls", opts).await
+        self.send(call.params(None)?).await
+    }
+
+    /// Creates the call and plays `digits` once it's answered. The digits are checked against
+    /// Twilio's limits before anything is sent.
+    pub async fn create_with_digits(
+        &self,
+        call: &OutboundCall,
+        digits: &DtmfScript,
+    ) -> TwilioClientResult<Call> {
+        self.send(call.params(Some(digits))?).await
+    }
+
+    async fn send(&self, params: Vec<(&'static str, String)>) -> TwilioClientResult<Call> {
+        let params = params
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect::<Vec<_>>();
+        self.client
+            .send_request(Method::POST, "Calls", &params)
+            .await
     }
 }
 
@@ -43,3 +78,39 @@ impl TwilioClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Digit;
+
+    use super::*;
+
+    #[test]
+    fn test_params() {
+        let call = OutboundCall {
+            from: "+15005550006".to_string(),
+            to: "+14155551212".to_string(),
+            url: "https://example.com/voice".to_string(),
+        };
+        assert_eq!(
+            call.params(None).unwrap(),
+            [
+                ("To", "+14155551212".to_string()),
+                ("From", "+15005550006".to_string()),
+                ("Url", "https://example.com/voice".to_string()),
+            ]
+        );
+
+        let digits = DtmfScript::new().half_second_pauses(2).key(Digit::One);
+        assert_eq!(
+            call.params(Some(&digits)).unwrap().last(),
+            Some(&("SendDigits", "ww1".to_string()))
+        );
+
+        let digits = DtmfScript::new().keys([Digit::One; 33]);
+        assert_eq!(
+            call.params(Some(&digits)),
+            Err(DtmfError::TooLong { len: 33, max: 32 })
+        );
+    }
+}
@@ -3,10 +3,67 @@ use std::sync::Arc;
 use http::{Method, StatusCode, header::CONTENT_TYPE};
 use reqwest::Body;
 
+/// What the client authenticates with
+#[derive(Clone, PartialEq, Eq)]
+pub enum Credentials {
+    /// The account's SID and auth token, which also work for its subaccounts
+    AuthToken {
+        account_sid: Arc<str>,
+        auth_token: Arc<str>,
+    },
+    /// A Standard or Restricted API key, preferred as it can be revoked on its own.
+    /// https://www.twilio.com/docs/iam/api-keys
+    ApiKey { sid: Arc<str>, secret: Arc<str> },
+}
+
+impl Credentials {
+    pub fn auth_token(account_sid: &str, auth_token: &str) -> Self {
+        Credentials::AuthToken {
+            account_sid: Arc::from(account_sid),
+            auth_token: Arc::from(auth_token),
+        }
+    }
+
+    pub fn api_key(sid: &str, secret: &str) -> Self {
+        Credentials::ApiKey {
+            sid: Arc::from(sid),
+            secret: Arc::from(secret),
+        }
+    }
+
+    /// Basic auth username and password
+    fn basic_auth(&self) -> (&str, &str) {
+        match self {
+            Credentials::AuthToken {
+                account_sid,
+                auth_token,
+            } => (account_sid, auth_token),
+            Credentials::ApiKey { sid, secret } => (sid, secret),
+        }
+    }
+}
+
+impl std::fmt::Debug for Credentials {
+    /// Without the token or secret
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Credentials::AuthToken { account_sid, .. } => f
+                .debug_struct("AuthToken")
+                .field("account_sid", account_sid)
+                .finish_non_exhaustive(),
+            Credentials::ApiKey { sid, .. } => f
+                .debug_struct("ApiKey")
+                .field("sid", sid)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TwilioClient {
+    /// The account requests act on
     account_sid: Arc<str>,
-    auth_token: Arc<str>,
+    credentials: Credentials,
     http_client: reqwest::Client,
 }
 
@@ -33,33 +90,80 @@ pub enum TwilioClientError {
     AuthError,
     #[error("bad request")]
     BadRequest,
+    #[error("SendDigits: {0}")]
+    SendDigits(#[from] crate::errors::DtmfError),
+}
+
+fn default_http_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .http1_only()
+        .https_only(true)
+        .build()
+        .expect("Client::new()")
 }
 
 impl TwilioClient {
     pub fn new(account_sid: &str, auth_token: &str) -> TwilioClient {
-        TwilioClient::new_with_client(
-            account_sid,
-            auth_token,
-            reqwest::Client::builder()
-                .http1_only()
-                .https_only(true)
-                .build()
-                .expect("Client::new()"),
-        )
+        TwilioClient::new_with_client(account_sid, auth_token, default_http_client())
     }
 
     pub fn new_with_client(
         account_sid: &str,
         auth_token: &str,
         http_client: reqwest::Client,
+    ) -> TwilioClient {
+        TwilioClient::with_credentials(
+            account_sid,
+            Credentials::auth_token(account_sid, auth_token),
+            http_client,
+        )
+    }
+
+    /// Authenticates with an API key created in `account_sid`
+    pub fn with_api_key(
+        account_sid: &str,
+        api_key_sid: &str,
+        api_key_secret: &str,
+    ) -> TwilioClient {
+        TwilioClient::with_credentials(
+            account_sid,
+            Credentials::api_key(api_key_sid, api_key_secret),
+            default_http_client(),
+        )
+    }
+
+    pub fn with_credentials(
+        account_sid: &str,
+        credentials: Credentials,
+        http_client: reqwest::Client,
     ) -> TwilioClient {
         TwilioClient {
             account_sid: Arc::from(account_sid),
-            auth_token: Arc::from(auth_token),
+            credentials,
             http_client,
         }
     }
 
+    /// A client acting on the subaccount `account_sid` with the same credentials and
+    /// connection pool, e.g. the parent account's API key
+    pub fn account(&self, account_sid: &str) -> TwilioClient {
+        TwilioClient {
+            account_sid: Arc::from(account_sid),
+            ..self.clone()
+        }
+    }
+
+    pub fn account_sid(&self) -> &str {
+        &self.account_sid
+    }
+
+    fn url(&self, endpoint: &str) -> String {
+        format!(
+            "https://api.twilio.com/2010-04-01/Accounts/{}/{}.json",
+            self.account_sid, endpoint
+        )
+    }
+
     pub async fn send_request<T>(
         &self,
         method: Method,
@@ -69,15 +173,11 @@ impl TwilioClient {
     where
         T: serde::de::DeserializeOwned,
     {
-        let url = format!(
-            "https://api.twilio.com/2010-04-01/Accounts/{}/{}.json",
-            self.account_sid, endpoint
-        );
-
+        let (username, password) = self.credentials.basic_auth();
         let resp = self
             .http_client
-            .request(method, url)
-            .basic_auth(&self.account_sid, Some(&self.auth_token))
+            .request(method, self.url(endpoint))
+            .basic_auth(username, Some(password))
             .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
             .body(Body::from(url_encode(params)))
             .send()
@@ -91,3 +191,31 @@ impl TwilioClient {
         Ok(resp.json().await?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credentials() {
+        let client = TwilioClient::new("AC123", "auth-token");
+        assert_eq!(client.credentials.basic_auth(), ("AC123", "auth-token"));
+        assert_eq!(
+            client.url("Calls"),
+            "https://api.twilio.com/2010-04-01/Accounts/AC123/Calls.json"
+        );
+
+        let client = TwilioClient::with_api_key("AC123", "SK456", "key-secret");
+        assert_eq!(client.credentials.basic_auth(), ("SK456", "key-secret"));
+        assert_eq!(client.account_sid(), "AC123");
+
+        let subaccount = client.account("AC789");
+        assert_eq!(subaccount.credentials.basic_auth(), ("SK456", "key-secret"));
+        assert_eq!(
+            subaccount.url("Calls"),
+            "https://api.twilio.com/2010-04-01/Accounts/AC789/Calls.json"
+        );
+        assert!(!format!("{subaccount:?}").contains("key-secret"));
+        assert!(!format!("{:?}", TwilioClient::new("AC123", "auth-token")).contains("auth-token"));
+    }
+}
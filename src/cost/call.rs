@@ -0,0 +1,394 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+use crate::twilio::Direction;
+use crate::twiml::{Response, Voice};
+use crate::{PriceType, TwilioError};
+
+use super::{CostReport, GeneratedPrices, PriceTable};
+
+/// Rates for estimating whole calls, e.g. from Twilio's pricing page for a country.
+///
+/// ```json
+/// {
+///     "currency": "USD",
+///     "minutes": { "inbound": 0.0085, "outbound-api": 0.014 },
+///     "recording_per_minute": 0.0025,
+///     "transcription_per_minute": 0.05,
+///     "amd_per_call": 0.0075,
+///     "tts": { "Polly.Joanna-Neural": 0.0032 }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RateCard {
+    /// ISO 4217 code the rates are in
+    #[serde(default = "default_currency")]
+    pub currency: String,
+    /// Connectivity per started minute, by call direction
+    #[serde(default)]
+    pub minutes: HashMap<Direction, PriceType>,
+    /// Call recording per started minute
+    #[serde(default)]
+    pub recording_per_minute: Option<PriceType>,
+    /// Transcription per started minute
+    #[serde(default)]
+    pub transcription_per_minute: Option<PriceType>,
+    /// Answering machine detection per call
+    #[serde(default)]
+    pub amd_per_call: Option<PriceType>,
+    /// Text-to-speech per 100 characters by voice id. Voices not listed use the generated
+    /// prices, see [`GeneratedPrices`].
+    #[serde(default)]
+    pub tts: HashMap<String, PriceType>,
+}
+
+/// TOML floats are binary, so turn them back into the decimal they were written as before
+/// they become `BigDecimal`s.
+#[cfg(all(feature = "toml", feature = "bigdecimal"))]
+fn decimal_strings(value: toml::Value) -> toml::Value {
+    match value {
+        toml::Value::Float(f) => toml::Value::String(f.to_string()),
+        toml::Value::Table(table) => toml::Value::Table(
+            table
+                .into_iter()
+                .map(|(k, v)| (k, decimal_strings(v)))
+                .collect(),
+        ),
+        toml::Value::Array(array) => {
+            toml::Value::Array(array.into_iter().map(decimal_strings).collect())
+        }
+        value => value,
+    }
+}
+
+fn default_currency() -> String {
+    "USD".to_string()
+}
+
+impl Default for RateCard {
+    fn default() -> Self {
+        Self {
+            currency: default_currency(),
+            minutes: HashMap::new(),
+            recording_per_minute: None,
+            transcription_per_minute: None,
+            amd_per_call: None,
+            tts: HashMap::new(),
+        }
+    }
+}
+
+impl RateCard {
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        serde_json::from_str(json).map_err(|e| TwilioError::RateCard(e.to_string()))
+    }
+
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> crate::Result<Self> {
+        let value: toml::Value =
+            toml::from_str(toml).map_err(|e| TwilioError::RateCard(e.to_string()))?;
+        #[cfg(feature = "bigdecimal")]
+        let value = decimal_strings(value);
+        value
+            .try_into()
+            .map_err(|e: toml::de::Error| TwilioError::RateCard(e.to_string()))
+    }
+
+    /// Load a rate card from a `.json` or (with the `toml` feature) `.toml` file.
+    pub fn from_path(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&contents),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&contents),
+            _ => Err(TwilioError::RateCard(format!(
+                "unsupported file type: {}",
+                path.display()
+            ))),
+        }
+    }
+}
+
+impl PriceTable for RateCard {
+    fn tts_price(&self, voice: &Voice) -> Option<PriceType> {
+        self.tts
+            .get(&voice.to_string())
+            .cloned()
+            .or_else(|| GeneratedPrices.tts_price(voice))
+    }
+}
+
+/// Billable extras enabled on a call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CallFeatures {
+    pub recording: bool,
+    pub answering_machine_detection: bool,
+    pub transcription: bool,
+}
+
+/// What a call is expected to look like, for [`CallPlan::estimate`].
+#[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
+pub struct CallPlan {
+    /// TwiML served during the call, in order
+    #[builder(default)]
+    pub responses: Vec<Response>,
+    /// Expected duration of the connected call
+    pub duration: Duration,
+    #[builder(default)]
+    pub direction: Direction,
+    #[builder(default)]
+    pub features: CallFeatures,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CallCostKind {
+    Connectivity(Direction),
+    TextToSpeech,
+    Recording,
+    Transcription,
+    AnsweringMachineDetection,
+}
+
+impl Display for CallCostKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallCostKind::Connectivity(direction) => write!(f, "connectivity ({direction})"),
+            CallCostKind::TextToSpeech => write!(f, "text-to-speech"),
+            CallCostKind::Recording => write!(f, "recording"),
+            CallCostKind::Transcription => write!(f, "transcription"),
+            CallCostKind::AnsweringMachineDetection => write!(f, "answering machine detection"),
+        }
+    }
+}
+
+/// One line of a [`CallCost`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallCostLine {
+    pub kind: CallCostKind,
+    /// Minutes, calls, or billed text-to-speech blocks
+    pub quantity: u64,
+    pub unit_price: Option<PriceType>,
+    /// `None` when the rate card has no rate for it
+    pub cost: Option<PriceType>,
+}
+
+/// Cost breakdown of a [`CallPlan`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallCost {
+    pub currency: String,
+    pub lines: Vec<CallCostLine>,
+    /// Itemised text-to-speech, one report per response
+    pub tts: Vec<CostReport>,
+}
+
+impl CallCost {
+    /// Total cost, or `None` if any line's cost is unknown.
+    pub fn total(&self) -> Option<PriceType> {
+        super::sum(self.lines.iter().map(|line| line.cost.as_ref()))
+    }
+
+    /// Sum of the lines whose cost is known.
+    pub fn known_total(&self) -> PriceType {
+        super::sum_known(self.lines.iter().map(|line| line.cost.as_ref()))
+    }
+}
+
+impl Display for CallCost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let currency = &self.currency;
+        for line in &self.lines {
+            match &line.cost {
+                Some(cost) => writeln!(f, "{} x{}: {cost} {currency}", line.kind, line.quantity)?,
+                None => writeln!(f, "{} x{}: unknown", line.kind, line.quantity)?,
+            }
+        }
+        match self.total() {
+            Some(total) => write!(f, "total: {total} {currency}"),
+            None => write!(f, "total: at least {} {currency}", self.known_total()),
+        }
+    }
+}
+
+impl CallPlan {
+    /// Estimate the call's cost. Minutes are billed per started minute.
+    pub fn estimate(&self, rates: &RateCard) -> CallCost {
+        let minutes = self.duration.as_secs().div_ceil(60);
+        let line = |kind, quantity, rate: Option<&PriceType>| CallCostLine {
+            kind,
+            quantity,
+            unit_price: rate.cloned(),
+            cost: rate.and_then(|rate| super::times(rate, quantity)),
+        };
+
+        let mut lines = vec![line(
+            CallCostKind::Connectivity(self.direction),
+            minutes,
+            rates.minutes.get(&self.direction),
+        )];
+
+        let tts = self
+            .responses
+            .iter()
+            .map(|response| response.cost_report(rates))
+            .collect::<Vec<_>>();
+        let blocks = tts
+            .iter()
+            .flat_map(|report| &report.items)
+            .filter_map(|item| match item.kind {
+                super::CostItemKind::Say { blocks, .. } => {
+                    Some(blocks as u64 * item.repetitions.unwrap_or(1) as u64)
+                }
+                _ => None,
+            })
+            .sum();
+        let tts_totals = tts.iter().map(CostReport::total).collect::<Vec<_>>();
+        lines.push(CallCostLine {
+            kind: CallCostKind::TextToSpeech,
+            quantity: blocks,
+            unit_price: None,
+            cost: super::sum(tts_totals.iter().map(Option::as_ref)),
+        });
+
+        if self.features.recording {
+            lines.push(line(
+                CallCostKind::Recording,
+                minutes,
+                rates.recording_per_minute.as_ref(),
+            ));
+        }
+        if self.features.transcription {
+            lines.push(line(
+                CallCostKind::Transcription,
+                minutes,
+                rates.transcription_per_minute.as_ref(),
+            ));
+        }
+        if self.features.answering_machine_detection {
+            lines.push(line(
+                CallCostKind::AnsweringMachineDetection,
+                1,
+                rates.amd_per_call.as_ref(),
+            ));
+        }
+
+        CallCost {
+            currency: rates.currency.clone(),
+            lines,
+            tts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::twiml::voices::NEURAL_VOICE_PRICE;
+
+    use super::*;
+
+    /// Rates as parsed from a rate card, exact with the `bigdecimal` feature
+    fn price(value: &str) -> PriceType {
+        value.parse().unwrap()
+    }
+
+    const RATES_JSON: &str = r#"{
+        "minutes": { "inbound": 0.0085, "outbound-api": 0.014 },
+        "recording_per_minute": 0.0025,
+        "amd_per_call": 0.0075,
+        "tts": { "Polly.Joanna": 0.001 }
+    }"#;
+
+    #[test]
+    fn test_estimate() {
+        let rates = RateCard::from_json(RATES_JSON).unwrap();
+        assert_eq!(rates.currency, "USD");
+
+        let response = Response::from_str(
+            r#"<Response>
+                <Say voice="Polly.Joanna" loop="2">Hello</Say>
+                <Say voice="Polly.Joanna-Neural">Goodbye</Say>
+            </Response>"#,
+        )
+        .unwrap();
+        let plan = CallPlan::builder()
+            .responses(vec![response])
+            .duration(Duration::from_secs(61))
+            .direction(Direction::OutboundApi)
+            .features(CallFeatures {
+                recording: true,
+                answering_machine_detection: true,
+                transcription: true,
+            })
+            .build();
+        let cost = plan.estimate(&rates);
+
+        let lines = cost
+            .lines
+            .iter()
+            .map(|line| (line.kind, line.quantity, line.cost.as_ref().cloned()))
+            .collect::<Vec<_>>();
+        let tts = price("0.001") * price("2")
+            + crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE).unwrap();
+        assert_eq!(
+            lines,
+            [
+                (
+                    CallCostKind::Connectivity(Direction::OutboundApi),
+                    2,
+                    Some(price("0.014") * price("2"))
+                ),
+                (CallCostKind::TextToSpeech, 3, Some(tts)),
+                (
+                    CallCostKind::Recording,
+                    2,
+                    Some(price("0.0025") * price("2"))
+                ),
+                (CallCostKind::Transcription, 2, None),
+                (
+                    CallCostKind::AnsweringMachineDetection,
+                    1,
+                    Some(price("0.0075"))
+                ),
+            ]
+        );
+        assert_eq!(cost.total(), None);
+    }
+
+    #[test]
+    fn test_missing_direction_rate() {
+        let rates = RateCard::from_json(RATES_JSON).unwrap();
+        let plan = CallPlan::builder()
+            .duration(Duration::from_secs(30))
+            .direction(Direction::OutboundDial)
+            .build();
+        let cost = plan.estimate(&rates);
+        assert_eq!(cost.lines[0].cost, None);
+        assert_eq!(cost.known_total(), PriceType::default());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_rate_card_toml() {
+        let toml = r#"
+            currency = "EUR"
+            amd_per_call = 0.0075
+
+            [minutes]
+            inbound = 0.0085
+            outbound-api = 0.014
+        "#;
+        let rates = RateCard::from_toml(toml).unwrap();
+        assert_eq!(rates.currency, "EUR");
+        assert_eq!(
+            rates.minutes.get(&Direction::OutboundApi),
+            Some(&price("0.014"))
+        );
+    }
+}
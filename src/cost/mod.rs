@@ -0,0 +1,101 @@
+mod call;
+pub use call::*;
+
+mod reconcile;
+pub use reconcile::*;
+
+mod tts;
+pub use tts::*;
+
+use std::collections::HashMap;
+
+use crate::PriceType;
+use crate::twiml::{Voice, VoicePrice};
+
+/// Prices used for cost estimates.
+///
+/// Twilio changes its rates independently of this crate, so estimates take a price table
+/// instead of relying on the generated constants alone.
+pub trait PriceTable {
+    /// Price per 100 characters of text-to-speech with `voice`, if known.
+    fn tts_price(&self, voice: &Voice) -> Option<PriceType>;
+}
+
+/// The prices generated from Twilio's documentation, see [`VoicePrice`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GeneratedPrices;
+
+impl PriceTable for GeneratedPrices {
+    fn tts_price(&self, voice: &Voice) -> Option<PriceType> {
+        voice.price()
+    }
+}
+
+impl<F> PriceTable for F
+where
+    F: Fn(&Voice) -> Option<PriceType>,
+{
+    fn tts_price(&self, voice: &Voice) -> Option<PriceType> {
+        self(voice)
+    }
+}
+
+/// Per-voice prices on top of another price table.
+///
+/// ```ignore
+/// let prices = PriceOverrides::new(GeneratedPrices).voice("Polly.Joanna", 0.001);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PriceOverrides<T = GeneratedPrices> {
+    /// Price per 100 characters, keyed by voice id, e.g. `Polly.Joanna-Neural`
+    pub voices: HashMap<String, PriceType>,
+    pub fallback: T,
+}
+
+impl<T: PriceTable> PriceOverrides<T> {
+    pub fn new(fallback: T) -> Self {
+        Self {
+            voices: HashMap::new(),
+            fallback,
+        }
+    }
+
+    /// Override the price per 100 characters of a voice.
+    pub fn voice(mut self, voice: impl ToString, price: PriceType) -> Self {
+        self.voices.insert(voice.to_string(), price);
+        self
+    }
+}
+
+impl<T: PriceTable> PriceTable for PriceOverrides<T> {
+    fn tts_price(&self, voice: &Voice) -> Option<PriceType> {
+        self.voices
+            .get(&voice.to_string())
+            .cloned()
+            .or_else(|| self.fallback.tts_price(voice))
+    }
+}
+
+/// `price * quantity`, `None` if the quantity can't be represented as a [`PriceType`].
+pub(crate) fn times(price: &PriceType, quantity: u64) -> Option<PriceType> {
+    crate::price_type_from_f64_ok(quantity as f64).map(|qty| price * qty)
+}
+
+/// Sum of `amounts`, `None` if any is unknown.
+///
+/// Starts from zero, whereas `Sum for f64` starts from `-0.0` and would print as `-0`.
+pub(crate) fn sum<'a>(
+    amounts: impl IntoIterator<Item = Option<&'a PriceType>>,
+) -> Option<PriceType> {
+    amounts
+        .into_iter()
+        .try_fold(PriceType::default(), |sum, amount| Some(sum + amount?))
+}
+
+/// Sum of the known `amounts`.
+pub(crate) fn sum_known<'a>(amounts: impl IntoIterator<Item = Option<&'a PriceType>>) -> PriceType {
+    amounts
+        .into_iter()
+        .flatten()
+        .fold(PriceType::default(), |sum, amount| sum + amount)
+}
@@ -0,0 +1,284 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::PriceType;
+use crate::twilio::CallResource;
+use crate::twiml::Response;
+
+use super::RateCard;
+
+/// The TwiML served for each call, keyed by CallSid, for reconciling with billed calls.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServedResponses {
+    calls: BTreeMap<String, Vec<Response>>,
+}
+
+impl ServedResponses {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a response served to the call `call_sid`.
+    pub fn record(&mut self, call_sid: impl Into<String>, response: Response) {
+        self.calls
+            .entry(call_sid.into())
+            .or_default()
+            .push(response);
+    }
+
+    pub fn get(&self, call_sid: &str) -> Option<&[Response]> {
+        self.calls.get(call_sid).map(Vec::as_slice)
+    }
+
+    /// Compare estimates for the recorded responses against fetched calls.
+    ///
+    /// `CallResource::price` only covers connectivity, so it's compared against the
+    /// connectivity estimate from `rates`; text-to-speech is reported as estimated only.
+    pub fn reconcile(&self, calls: &[CallResource], rates: &RateCard) -> ReconciliationReport {
+        let mut report = ReconciliationReport::default();
+
+        for call in calls {
+            let responses = self.get(&call.sid).unwrap_or_default();
+            let currency = call.price_unit.as_ref().map(|unit| unit.to_uppercase());
+            let same_currency = currency
+                .as_ref()
+                .is_none_or(|c| c.eq_ignore_ascii_case(&rates.currency));
+
+            let tts = responses
+                .iter()
+                .map(|response| response.cost_report(rates).total())
+                .collect::<Vec<_>>();
+            let estimated_tts =
+                super::sum(tts.iter().map(Option::as_ref)).filter(|_| same_currency);
+            let estimated_connectivity = call
+                .duration
+                .zip(rates.minutes.get(&call.direction))
+                .and_then(|(seconds, rate)| super::times(rate, seconds.div_ceil(60) as u64))
+                .filter(|_| same_currency);
+            // Twilio reports charges as negative amounts
+            let billed = call.price.as_ref().map(|price| price.abs());
+            let difference = match (&billed, &estimated_connectivity) {
+                (Some(billed), Some(estimated)) => Some(billed - estimated),
+                _ => None,
+            };
+
+            report.calls.push(CallReconciliation {
+                call_sid: call.sid.clone(),
+                currency,
+                duration: call.duration,
+                responses: responses.len(),
+                estimated_tts,
+                estimated_connectivity,
+                billed,
+                difference,
+            });
+        }
+
+        report.unmatched = self
+            .calls
+            .keys()
+            .filter(|sid| !calls.iter().any(|call| &call.sid == *sid))
+            .cloned()
+            .collect();
+
+        report
+    }
+}
+
+/// Estimated vs billed amounts for one call.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CallReconciliation {
+    pub call_sid: String,
+    /// From `CallResource::price_unit`, upper-cased
+    pub currency: Option<String>,
+    /// Seconds
+    pub duration: Option<usize>,
+    /// Responses recorded for the call
+    pub responses: usize,
+    /// `None` if a voice has no known price or the rate card is in another currency
+    pub estimated_tts: Option<PriceType>,
+    /// `None` if the rate card has no rate for the call's direction or is in another currency
+    pub estimated_connectivity: Option<PriceType>,
+    /// `None` until Twilio has priced the call
+    pub billed: Option<PriceType>,
+    /// `billed - estimated_connectivity`
+    pub difference: Option<PriceType>,
+}
+
+/// Totals for the calls billed in one currency. Unknown amounts are left out.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CurrencyTotals {
+    pub calls: usize,
+    pub estimated_tts: PriceType,
+    pub estimated_connectivity: PriceType,
+    pub billed: PriceType,
+}
+
+/// Result of [`ServedResponses::reconcile`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ReconciliationReport {
+    pub calls: Vec<CallReconciliation>,
+    /// CallSids with recorded responses but no fetched call
+    pub unmatched: Vec<String>,
+}
+
+impl ReconciliationReport {
+    /// Totals per currency. Calls without a `price_unit` are grouped under an empty string.
+    pub fn totals(&self) -> BTreeMap<String, CurrencyTotals> {
+        let mut totals = BTreeMap::<String, CurrencyTotals>::new();
+        for call in &self.calls {
+            let entry = totals
+                .entry(call.currency.clone().unwrap_or_default())
+                .or_default();
+            entry.calls += 1;
+            if let Some(amount) = &call.estimated_tts {
+                entry.estimated_tts += amount;
+            }
+            if let Some(amount) = &call.estimated_connectivity {
+                entry.estimated_connectivity += amount;
+            }
+            if let Some(amount) = &call.billed {
+                entry.billed += amount;
+            }
+        }
+        totals
+    }
+
+    /// One row per call, with a header row.
+    pub fn to_csv(&self) -> String {
+        fn field(value: Option<impl ToString>) -> String {
+            let value = value.map(|v| v.to_string()).unwrap_or_default();
+            if value.contains([',', '"', '\n']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value
+            }
+        }
+
+        let mut csv = String::from(
+            "call_sid,currency,duration,responses,estimated_tts,estimated_connectivity,billed,difference\n",
+        );
+        for call in &self.calls {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{}",
+                field(Some(&call.call_sid)),
+                field(call.currency.as_ref()),
+                field(call.duration),
+                call.responses,
+                field(call.estimated_tts.as_ref()),
+                field(call.estimated_connectivity.as_ref()),
+                field(call.billed.as_ref()),
+                field(call.difference.as_ref()),
+            );
+        }
+        csv
+    }
+
+    /// The calls, unmatched CallSids and per-currency totals.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        #[derive(Serialize)]
+        struct Export<'a> {
+            calls: &'a [CallReconciliation],
+            unmatched: &'a [String],
+            totals: BTreeMap<String, CurrencyTotals>,
+        }
+
+        serde_json::to_string_pretty(&Export {
+            calls: &self.calls,
+            unmatched: &self.unmatched,
+            totals: self.totals(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::twilio::Direction;
+
+    use super::*;
+
+    fn price(value: &str) -> PriceType {
+        value.parse().unwrap()
+    }
+
+    fn call(sid: &str, duration: usize, billed: &str) -> CallResource {
+        CallResource {
+            sid: sid.to_string(),
+            duration: Some(duration),
+            price: Some(price(billed)),
+            price_unit: Some("usd".to_string()),
+            direction: Direction::OutboundApi,
+            ..Default::default()
+        }
+    }
+
+    fn rates() -> RateCard {
+        RateCard::from_json(
+            r#"{ "minutes": { "outbound-api": 0.014 }, "tts": { "Polly.Joanna": 0.001 } }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reconcile() {
+        let response =
+            Response::from_str(r#"<Response><Say voice="Polly.Joanna">Hi</Say></Response>"#)
+                .unwrap();
+        let mut served = ServedResponses::new();
+        served.record("CA1", response.clone());
+        served.record("CA1", response);
+        served.record("CA3", Response::builder().build());
+
+        let report = served.reconcile(
+            &[call("CA1", 61, "-0.030"), call("CA2", 5, "-0.014")],
+            &rates(),
+        );
+
+        let first = &report.calls[0];
+        assert_eq!(first.currency.as_deref(), Some("USD"));
+        assert_eq!(first.responses, 2);
+        assert_eq!(first.estimated_tts, Some(price("0.002")));
+        assert_eq!(first.estimated_connectivity, Some(price("0.028")));
+        assert_eq!(first.billed, Some(price("0.030")));
+        assert_eq!(first.difference, Some(price("0.030") - price("0.028")));
+
+        assert_eq!(report.calls[1].estimated_tts, Some(PriceType::default()));
+        assert_eq!(report.unmatched, ["CA3"]);
+
+        let totals = report.totals();
+        assert_eq!(totals["USD"].calls, 2);
+        assert_eq!(totals["USD"].billed, price("0.030") + price("0.014"));
+    }
+
+    #[test]
+    fn test_other_currency_is_not_estimated() {
+        let mut eur = call("CA1", 30, "-0.01");
+        eur.price_unit = Some("EUR".to_string());
+        let report = ServedResponses::new().reconcile(&[eur], &rates());
+        assert_eq!(report.calls[0].estimated_connectivity, None);
+        assert_eq!(report.calls[0].billed, Some(price("0.01")));
+    }
+
+    #[test]
+    fn test_export() {
+        let mut unpriced = call("CA2", 0, "0");
+        unpriced.price = None;
+        let report =
+            ServedResponses::new().reconcile(&[call("CA1", 60, "-0.014"), unpriced], &rates());
+
+        let csv = report.to_csv();
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("CA1,USD,60,0,0,"));
+        assert!(lines[2].starts_with("CA2,USD,0,0,0,0,,"));
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["calls"][0]["call_sid"], "CA1");
+        assert_eq!(json["totals"]["USD"]["calls"], 2);
+    }
+}
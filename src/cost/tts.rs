@@ -0,0 +1,320 @@
+use std::fmt::Display;
+use std::sync::LazyLock;
+
+use quick_xml::escape::unescape;
+use regex::Regex;
+
+use crate::PriceType;
+use crate::twiml::{Gather, GatherVerb, Response, ResponseVerb, Say, Voice};
+
+use super::{GeneratedPrices, PriceTable};
+
+/// Twilio bills text-to-speech per started block of this many characters.
+pub const TTS_BLOCK_CHARS: usize = 100;
+
+static TAG_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<[^>]*>").unwrap());
+
+/// Characters Twilio bills for a `<Say>`'s content.
+///
+/// SSML tags aren't billed, entities count as the character they stand for, and characters
+/// are counted rather than UTF-8 bytes.
+pub fn billable_characters(ssml: &str) -> usize {
+    let without_cdata = ssml.replace("<![CDATA[", "").replace("]]>", "");
+    let text = TAG_REGEX.replace_all(&without_cdata, "");
+    match unescape(&text) {
+        Ok(unescaped) => unescaped.chars().count(),
+        Err(_) => text.chars().count(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CostItemKind {
+    /// Text-to-speech, billed per started block of [`TTS_BLOCK_CHARS`] characters each time
+    /// it's spoken
+    Say {
+        voice: Option<Voice>,
+        characters: usize,
+        /// Blocks billed per repetition
+        blocks: usize,
+    },
+    /// Audio file or DTMF tones, not billed
+    Play,
+    /// Silence, not billed
+    Pause { seconds: u8 },
+}
+
+/// The estimated cost of a single verb.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CostItem {
+    /// XPath-like location of the verb, e.g. `/Response/Gather[1]/Say[2]`
+    pub path: String,
+    pub kind: CostItemKind,
+    /// How often the verb runs; `None` when it loops until the call hangs up
+    pub repetitions: Option<u32>,
+    /// Price per block of [`TTS_BLOCK_CHARS`] characters, for `<Say>`
+    pub unit_price: Option<PriceType>,
+    /// `None` when the voice has no known price or the verb loops forever
+    pub cost: Option<PriceType>,
+}
+
+impl CostItem {
+    fn say(path: String, say: &Say, prices: &impl PriceTable) -> Self {
+        let characters = billable_characters(&say.ssml.raw());
+        let blocks = characters.div_ceil(TTS_BLOCK_CHARS);
+        let repetitions = (say.loop_count > 0).then_some(say.loop_count);
+        let unit_price = say.voice.as_ref().and_then(|v| prices.tts_price(v));
+
+        let cost = match (&unit_price, repetitions) {
+            (_, _) if blocks == 0 => Some(PriceType::default()),
+            (Some(unit_price), Some(repetitions)) => {
+                super::times(unit_price, blocks as u64 * repetitions as u64)
+            }
+            _ => None,
+        };
+
+        Self {
+            path,
+            kind: CostItemKind::Say {
+                voice: say.voice.clone(),
+                characters,
+                blocks,
+            },
+            repetitions,
+            unit_price,
+            cost,
+        }
+    }
+
+    fn free(path: String, kind: CostItemKind, loop_count: u32) -> Self {
+        Self {
+            path,
+            kind,
+            repetitions: (loop_count > 0).then_some(loop_count),
+            unit_price: None,
+            cost: Some(PriceType::default()),
+        }
+    }
+
+    fn gather_verb(path: String, verb: &GatherVerb, prices: &impl PriceTable) -> Self {
+        match verb {
+            GatherVerb::Say(say) => Self::say(path, say, prices),
+            GatherVerb::Play(play) => Self::free(path, CostItemKind::Play, play.loop_count),
+            GatherVerb::Pause(pause) => Self::free(
+                path,
+                CostItemKind::Pause {
+                    seconds: pause.length,
+                },
+                1,
+            ),
+        }
+    }
+}
+
+/// An itemised estimate of what the text-to-speech in a TwiML response costs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CostReport {
+    /// One item per `<Say>`, `<Play>` and `<Pause>`, in document order
+    pub items: Vec<CostItem>,
+}
+
+impl CostReport {
+    pub fn for_response(response: &Response, prices: &impl PriceTable) -> Self {
+        let mut items = vec![];
+        let paths = crate::twiml::sibling_paths(
+            "/Response",
+            response.verbs.iter().map(crate::twiml::verb_name),
+        );
+        for (verb, path) in response.verbs.iter().zip(paths) {
+            match verb {
+                ResponseVerb::Say(say) => items.push(CostItem::say(path, say, prices)),
+                ResponseVerb::Play(play) => {
+                    items.push(CostItem::free(path, CostItemKind::Play, play.loop_count))
+                }
+                ResponseVerb::Pause(pause) => items.push(CostItem::free(
+                    path,
+                    CostItemKind::Pause {
+                        seconds: pause.length,
+                    },
+                    1,
+                )),
+                ResponseVerb::Gather(gather) => {
+                    items.extend(Self::for_gather_at(&path, gather, prices).items)
+                }
+                ResponseVerb::Redirect(_) | ResponseVerb::Hangup => {}
+            }
+        }
+        Self { items }
+    }
+
+    pub fn for_gather(gather: &Gather, prices: &impl PriceTable) -> Self {
+        Self::for_gather_at("/Gather", gather, prices)
+    }
+
+    fn for_gather_at(parent: &str, gather: &Gather, prices: &impl PriceTable) -> Self {
+        let paths = crate::twiml::sibling_paths(
+            parent,
+            gather.verbs.iter().map(crate::twiml::gather_verb_name),
+        );
+        let items = gather
+            .verbs
+            .iter()
+            .zip(paths)
+            .map(|(verb, path)| CostItem::gather_verb(path, verb, prices))
+            .collect();
+        Self { items }
+    }
+
+    /// Total cost, or `None` if any item's cost is unknown.
+    pub fn total(&self) -> Option<PriceType> {
+        super::sum(self.items.iter().map(|item| item.cost.as_ref()))
+    }
+
+    /// Sum of the items whose cost is known.
+    pub fn known_total(&self) -> PriceType {
+        super::sum_known(self.items.iter().map(|item| item.cost.as_ref()))
+    }
+
+    /// Items whose cost couldn't be estimated.
+    pub fn unpriced(&self) -> impl Iterator<Item = &CostItem> {
+        self.items.iter().filter(|item| item.cost.is_none())
+    }
+}
+
+impl Display for CostReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for item in &self.items {
+            let repetitions = match item.repetitions {
+                Some(n) => format!("x{n}"),
+                None => "forever".to_string(),
+            };
+            let description = match &item.kind {
+                CostItemKind::Say {
+                    voice,
+                    characters,
+                    blocks,
+                } => {
+                    let voice = voice
+                        .as_ref()
+                        .map(|v| v.to_string())
+                        .unwrap_or("default voice".into());
+                    format!("{voice}, {characters} chars, {blocks} block(s) {repetitions}")
+                }
+                CostItemKind::Play => format!("play {repetitions}"),
+                CostItemKind::Pause { seconds } => format!("pause {seconds}s"),
+            };
+            let cost = match &item.cost {
+                Some(cost) => format!("${cost}"),
+                None => "unknown".to_string(),
+            };
+            writeln!(f, "{}: {description}: {cost}", item.path)?;
+        }
+        match self.total() {
+            Some(total) => write!(f, "total: ${total}"),
+            None => write!(f, "total: at least ${}", self.known_total()),
+        }
+    }
+}
+
+impl Response {
+    /// Itemised text-to-speech cost estimate using `prices`.
+    pub fn cost_report(&self, prices: &impl PriceTable) -> CostReport {
+        CostReport::for_response(self, prices)
+    }
+}
+
+pub(crate) fn say_cost(say: &Say) -> Option<PriceType> {
+    CostItem::say(String::new(), say, &GeneratedPrices).cost
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::cost::PriceOverrides;
+    use crate::twiml::voices::{NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE};
+
+    use super::*;
+
+    fn price(value: f64) -> PriceType {
+        crate::price_type_from_f64_ok(value).unwrap()
+    }
+
+    #[test]
+    fn test_billable_characters() {
+        assert_eq!(billable_characters("Hello"), 5);
+        assert_eq!(billable_characters("Grüße"), 5);
+        assert_eq!(
+            billable_characters(r#"<speak>Hi <break time="1s"/>there &amp; bye</speak>"#),
+            "Hi there & bye".len()
+        );
+        assert_eq!(
+            billable_characters("<![CDATA[<message>Hello & Welcome!</message>]]>"),
+            "Hello & Welcome!".len()
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let text = "é".repeat(60);
+        let xml = format!(
+            r#"<Response>
+                <Say voice="Polly.Joanna" loop="3">{text}</Say>
+                <Gather numDigits="1">
+                    <Say voice="Polly.Joanna-Neural"><prosody rate="slow">{text}</prosody></Say>
+                    <Pause length="2"/>
+                </Gather>
+                <Play>https://example.com/a.mp3</Play>
+            </Response>"#
+        );
+        let report = Response::from_str(&xml)
+            .unwrap()
+            .cost_report(&GeneratedPrices);
+
+        assert_eq!(
+            report
+                .items
+                .iter()
+                .map(|i| i.path.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "/Response/Say[1]",
+                "/Response/Gather[1]/Say[1]",
+                "/Response/Gather[1]/Pause[1]",
+                "/Response/Play[1]",
+            ]
+        );
+        // 60 characters (120 bytes) is a single block, spoken three times
+        assert_eq!(
+            report.items[0].cost,
+            Some(price(STANDARD_VOICE_PRICE) * price(3.))
+        );
+        assert_eq!(report.items[1].cost, Some(price(NEURAL_VOICE_PRICE)));
+        assert_eq!(report.items[2].cost, Some(PriceType::default()));
+        assert_eq!(
+            report.total(),
+            Some(price(STANDARD_VOICE_PRICE) * price(3.) + price(NEURAL_VOICE_PRICE))
+        );
+    }
+
+    #[test]
+    fn test_unknown_costs() {
+        let xml =
+            r#"<Response><Say loop="0" voice="Polly.Joanna">Hold on</Say><Say>Hi</Say></Response>"#;
+        let report = Response::from_str(xml)
+            .unwrap()
+            .cost_report(&GeneratedPrices);
+        assert_eq!(report.items[0].repetitions, None);
+        assert_eq!(report.unpriced().count(), 2);
+        assert_eq!(report.total(), None);
+        assert_eq!(report.known_total(), PriceType::default());
+    }
+
+    #[test]
+    fn test_price_overrides() {
+        let xml = r#"<Response><Say voice="Polly.Joanna">Hi</Say><Say voice="Polly.Matthew">Hi</Say></Response>"#;
+        let prices = PriceOverrides::new(GeneratedPrices).voice("Polly.Joanna", price(0.5));
+        let report = Response::from_str(xml).unwrap().cost_report(&prices);
+        assert_eq!(report.items[0].cost, Some(price(0.5)));
+        assert_eq!(report.items[1].cost, Some(price(STANDARD_VOICE_PRICE)));
+    }
+}
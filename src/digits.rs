@@ -1,4 +1,7 @@
-use crate::{errors::DigitsError, twiml::GatherDigit};
+use crate::{
+    errors::DigitsError,
+    twiml::{GatherDigit, Language, ReadBack, ReadOut, Say},
+};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
@@ -8,6 +11,12 @@ use std::{
     sync::LazyLock,
 };
 
+mod dtmf;
+pub use dtmf::*;
+
+mod pattern;
+pub use pattern::*;
+
 static STAR_POUND: LazyLock<Regex> = LazyLock::new(|| Regex::new("(?i)star|pound").unwrap());
 
 #[derive(Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -73,6 +82,17 @@ impl Digits {
         self.0.iter().map(Digit::word).collect()
     }
 
+    /// The words for the digits in a language, see [`ReadOut`] for the whole read-out.
+    pub fn words_in(&self, language: Language) -> Vec<String> {
+        let read_out = ReadOut::text(language);
+        self.0.iter().map(|digit| read_out.word(*digit)).collect()
+    }
+
+    /// A `<Say>` reading the digits back to the caller, see [`ReadBack`].
+    pub fn read_back(&self, read_back: &ReadBack) -> Say {
+        read_back.say(self)
+    }
+
     /// Return the integer value of the leading numeric digits if all non-numeric digits appear after all numeric digits; otherwise return None.
     pub fn to_int(&self) -> std::result::Result<usize, DigitsError> {
         if self.is_empty() {
@@ -196,8 +216,7 @@ pub enum Digit {
 }
 
 impl Digit {
-    /// TODO: Translations
-    /// The (for now, English) word for the digit.
+    /// The English word for the digit, see [`Digit::word_in`] for other languages.
     pub fn word(&self) -> String {
         match self {
             Digit::Zero => "zero".to_string(),
@@ -218,6 +237,11 @@ impl Digit {
         }
     }
 
+    /// The word for the digit in a language, see [`ReadOut::word`].
+    pub fn word_in(&self, language: Language) -> String {
+        ReadOut::text(language).word(*self)
+    }
+
     pub fn is_alpha(&self) -> bool {
         matches!(self, Digit::A | Digit::B | Digit::C | Digit::D)
     }
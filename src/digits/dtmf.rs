@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use crate::errors::DtmfError;
+use crate::twiml::Play;
+
+use super::{Digit, Digits};
+
+/// Most characters Twilio accepts in `SendDigits`, pauses included
+pub const MAX_DTMF_LEN: usize = 32;
+
+/// Assumed length of one DTMF tone and the gap after it, for [`DtmfScript::duration`].
+/// Twilio doesn't document it.
+pub const DTMF_KEY_DURATION: Duration = Duration::from_millis(250);
+
+const HALF_SECOND: Duration = Duration::from_millis(500);
+
+/// Composes DTMF tones for navigating a remote IVR, e.g. wait for the greeting, dial an
+/// extension, confirm with `#`.
+///
+/// The script is sent with [`<Play digits>`](Play) or as `SendDigits` when creating a call,
+/// both limited to [`MAX_DTMF_LEN`] characters.
+///
+/// ```
+/// use std::time::Duration;
+/// use twilio_voice::{Digit, digits::DtmfScript};
+///
+/// let script = DtmfScript::new()
+///     .wait(Duration::from_secs(2))
+///     .keys([Digit::One, Digit::Two, Digit::Three, Digit::Four])
+///     .half_second_pauses(1)
+///     .key(Digit::Pound);
+/// assert_eq!(script.to_string(), "WW1234w#");
+/// assert_eq!(script.wait_duration(), Duration::from_millis(2500));
+/// let play = script.play().unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DtmfScript(Vec<Digit>);
+
+impl DtmfScript {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a key; `w` and `W` are appended as pauses
+    pub fn key(mut self, digit: Digit) -> Self {
+        self.0.push(digit);
+        self
+    }
+
+    pub fn keys(mut self, digits: impl IntoIterator<Item = Digit>) -> Self {
+        self.0.extend(digits);
+        self
+    }
+
+    /// `n` half-second pauses (`w`)
+    pub fn half_second_pauses(mut self, n: usize) -> Self {
+        self.0.extend(std::iter::repeat_n(Digit::W, n));
+        self
+    }
+
+    /// `n` one-second pauses (`W`)
+    pub fn second_pauses(mut self, n: usize) -> Self {
+        self.0.extend(std::iter::repeat_n(Digit::WW, n));
+        self
+    }
+
+    /// Pauses for at least `duration`, rounded up to half a second, using as few
+    /// characters as possible
+    pub fn wait(self, duration: Duration) -> Self {
+        let halves = duration.as_millis().div_ceil(HALF_SECOND.as_millis()) as usize;
+        self.second_pauses(halves / 2)
+            .half_second_pauses(halves % 2)
+    }
+
+    /// Total of the pauses
+    pub fn wait_duration(&self) -> Duration {
+        self.0
+            .iter()
+            .map(|digit| match digit {
+                Digit::W => HALF_SECOND,
+                Digit::WW => HALF_SECOND * 2,
+                _ => Duration::ZERO,
+            })
+            .sum()
+    }
+
+    /// Estimated time to play the script: the pauses plus [`DTMF_KEY_DURATION`] per key
+    pub fn duration(&self) -> Duration {
+        let keys = self.0.iter().filter(|digit| !digit.is_pause()).count();
+        self.wait_duration() + DTMF_KEY_DURATION * keys as u32
+    }
+
+    /// The script if Twilio accepts it: at least one key, at most [`MAX_DTMF_LEN`]
+    /// characters
+    pub fn check(&self) -> Result<Digits, DtmfError> {
+        if self.0.iter().all(Digit::is_pause) {
+            return Err(DtmfError::NoKeys);
+        }
+        if self.0.len() > MAX_DTMF_LEN {
+            return Err(DtmfError::TooLong {
+                len: self.0.len(),
+                max: MAX_DTMF_LEN,
+            });
+        }
+        Ok(Digits::from(self.0.as_slice()))
+    }
+
+    /// `<Play digits="...">`
+    pub fn play(&self) -> Result<Play, DtmfError> {
+        Ok(Play::builder().digits(self.check()?).build())
+    }
+
+    /// The `SendDigits` parameter for creating a call
+    pub fn send_digits(&self) -> Result<(&'static str, String), DtmfError> {
+        Ok(("SendDigits", self.check()?.to_string()))
+    }
+}
+
+impl std::fmt::Display for DtmfScript {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0
+            .iter()
+            .try_for_each(|digit| write!(f, "{}", digit.as_ref()))
+    }
+}
+
+impl From<Digits> for DtmfScript {
+    fn from(digits: Digits) -> Self {
+        DtmfScript(digits.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script() {
+        let script = DtmfScript::new()
+            .wait(Duration::from_millis(1200))
+            .key(Digit::Two)
+            .second_pauses(1)
+            .keys([Digit::Four, Digit::Two])
+            .key(Digit::Pound);
+        assert_eq!(script.to_string(), "Ww2W42#");
+        assert_eq!(script.wait_duration(), Duration::from_millis(2500));
+        assert_eq!(script.duration(), Duration::from_millis(3500));
+
+        let play = script.play().unwrap();
+        assert_eq!(play.digits.unwrap().to_string(), "Ww2W42#");
+        assert_eq!(
+            script.send_digits().unwrap(),
+            ("SendDigits", "Ww2W42#".to_string())
+        );
+    }
+
+    #[test]
+    fn test_limits() {
+        assert_eq!(DtmfScript::new().check(), Err(DtmfError::NoKeys));
+        assert_eq!(
+            DtmfScript::new().second_pauses(3).check(),
+            Err(DtmfError::NoKeys)
+        );
+        assert_eq!(DtmfScript::new().wait(Duration::ZERO).to_string(), "");
+
+        let full = DtmfScript::new().keys(std::iter::repeat_n(Digit::One, MAX_DTMF_LEN));
+        assert!(full.check().is_ok());
+        assert_eq!(
+            full.key(Digit::Pound).play(),
+            Err(DtmfError::TooLong { len: 33, max: 32 })
+        );
+    }
+}
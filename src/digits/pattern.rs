@@ -0,0 +1,310 @@
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+
+use strum::IntoEnumIterator;
+
+use crate::errors::PatternError;
+
+use super::{Digit, Digits};
+
+const NUMERIC: [Digit; 10] = [
+    Digit::Zero,
+    Digit::One,
+    Digit::Two,
+    Digit::Three,
+    Digit::Four,
+    Digit::Five,
+    Digit::Six,
+    Digit::Seven,
+    Digit::Eight,
+    Digit::Nine,
+];
+
+/// What gathered [`Digits`] must look like: a length range, the keys allowed, an optional
+/// terminator and an optional Luhn check. Pauses are ignored.
+///
+/// [`DigitsPattern::check`] returns the entry without its terminator, or the first reason
+/// it doesn't match, so the caller can be re-prompted with something specific.
+///
+/// ```
+/// use twilio_voice::{Digit, Digits, digits::DigitsPattern, errors::PatternError};
+///
+/// let pin = DigitsPattern::pin(4);
+/// assert!(pin.matches(&"1234".parse::<Digits>().unwrap()));
+/// assert_eq!(
+///     pin.check(&"12".parse::<Digits>().unwrap()),
+///     Err(PatternError::TooShort { min: 4, len: 2 })
+/// );
+///
+/// // One of 1 to 3, then #
+/// let menu = DigitsPattern::new()
+///     .exactly(1)
+///     .only(Digit::One..=Digit::Three)
+///     .terminated_by(Digit::Pound);
+/// assert_eq!(menu.check(&"2#".parse::<Digits>().unwrap()).unwrap().to_string(), "2");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitsPattern {
+    min_len: usize,
+    max_len: Option<usize>,
+    allowed: Vec<Digit>,
+    terminator: Option<Digit>,
+    luhn: bool,
+}
+
+impl Default for DigitsPattern {
+    fn default() -> Self {
+        Self {
+            min_len: 1,
+            max_len: None,
+            allowed: NUMERIC.to_vec(),
+            terminator: None,
+            luhn: false,
+        }
+    }
+}
+
+impl DigitsPattern {
+    /// One or more of `0`–`9`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exactly `len` of `0`–`9`
+    pub fn pin(len: usize) -> Self {
+        Self::new().exactly(len)
+    }
+
+    /// 12 to 19 of `0`–`9` passing the Luhn check, the format of payment card numbers
+    pub fn card_number() -> Self {
+        Self::new().length(12..=19).luhn()
+    }
+
+    /// Number of keys, not counting the terminator
+    pub fn length(mut self, range: impl RangeBounds<usize>) -> Self {
+        self.min_len = match range.start_bound() {
+            Bound::Included(min) => *min,
+            Bound::Excluded(min) => min + 1,
+            Bound::Unbounded => 0,
+        };
+        self.max_len = match range.end_bound() {
+            Bound::Included(max) => Some(*max),
+            Bound::Excluded(max) => Some(max.saturating_sub(1)),
+            Bound::Unbounded => None,
+        };
+        self
+    }
+
+    pub fn exactly(self, len: usize) -> Self {
+        self.length(len..=len)
+    }
+
+    /// Replaces the allowed keys, e.g. `Digit::One..=Digit::Three`
+    pub fn only(mut self, digits: impl Into<DigitSet>) -> Self {
+        self.allowed = digits.into().0;
+        self
+    }
+
+    /// Adds to the allowed keys, e.g. `[Digit::Star]`
+    pub fn allow(mut self, digits: impl Into<DigitSet>) -> Self {
+        for digit in digits.into().0 {
+            if !self.allowed.contains(&digit) {
+                self.allowed.push(digit);
+            }
+        }
+        self
+    }
+
+    /// Requires the entry to end with `digit`, which isn't part of the matched digits
+    pub fn terminated_by(mut self, digit: Digit) -> Self {
+        self.terminator = Some(digit);
+        self
+    }
+
+    /// Requires the digits to pass the Luhn (mod 10) check used by card numbers
+    pub fn luhn(mut self) -> Self {
+        self.luhn = true;
+        self
+    }
+
+    /// The matched digits without the terminator, or why they don't match.
+    ///
+    /// Failures are checked in order: nothing entered, missing terminator, a key that
+    /// isn't allowed, length, then the Luhn check.
+    pub fn check(&self, digits: &[Digit]) -> Result<Digits, PatternError> {
+        let mut keys = digits
+            .iter()
+            .filter(|digit| !digit.is_pause())
+            .copied()
+            .collect::<Vec<_>>();
+        if keys.is_empty() {
+            return Err(PatternError::Empty);
+        }
+        if let Some(terminator) = self.terminator {
+            if keys.last() != Some(&terminator) {
+                return Err(PatternError::MissingTerminator(terminator));
+            }
+            keys.pop();
+        }
+
+        if let Some((position, digit)) = keys
+            .iter()
+            .enumerate()
+            .find(|(_, digit)| !self.allowed.contains(digit))
+        {
+            return Err(PatternError::NotAllowed {
+                digit: *digit,
+                position,
+            });
+        }
+        let len = keys.len();
+        if len < self.min_len {
+            return Err(PatternError::TooShort {
+                min: self.min_len,
+                len,
+            });
+        }
+        if let Some(max) = self.max_len.filter(|max| len > *max) {
+            return Err(PatternError::TooLong { max, len });
+        }
+        if self.luhn && !luhn(&keys) {
+            return Err(PatternError::Luhn);
+        }
+
+        Ok(Digits::from(keys.as_slice()))
+    }
+
+    pub fn matches(&self, digits: &[Digit]) -> bool {
+        self.check(digits).is_ok()
+    }
+}
+
+/// Keys for [`DigitsPattern::only`] and [`DigitsPattern::allow`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitSet(Vec<Digit>);
+
+impl From<RangeInclusive<Digit>> for DigitSet {
+    /// In declaration order: `0`–`9`, `*`, `#`, `A`–`D`
+    fn from(range: RangeInclusive<Digit>) -> Self {
+        DigitSet(
+            Digit::iter()
+                .filter(|digit| range.contains(digit))
+                .collect(),
+        )
+    }
+}
+
+impl<const N: usize> From<[Digit; N]> for DigitSet {
+    fn from(digits: [Digit; N]) -> Self {
+        DigitSet(digits.to_vec())
+    }
+}
+
+impl From<Vec<Digit>> for DigitSet {
+    fn from(digits: Vec<Digit>) -> Self {
+        DigitSet(digits)
+    }
+}
+
+impl From<Digit> for DigitSet {
+    fn from(digit: Digit) -> Self {
+        DigitSet(vec![digit])
+    }
+}
+
+/// Luhn check; false if any key isn't numeric
+fn luhn(digits: &[Digit]) -> bool {
+    let mut sum = 0;
+    for (i, digit) in digits.iter().rev().enumerate() {
+        let Some(n) = digit.to_int() else {
+            return false;
+        };
+        sum += match (i % 2, n * 2) {
+            (1, doubled) if doubled > 9 => doubled - 9,
+            (1, doubled) => doubled,
+            _ => n,
+        };
+    }
+    sum % 10 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn digits(s: &str) -> Digits {
+        Digits::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_length_and_keys() {
+        let pin = DigitsPattern::pin(4);
+        assert_eq!(pin.check(&digits("1234")), Ok(digits("1234")));
+        assert_eq!(pin.check(&digits("12w34")), Ok(digits("1234")));
+        assert_eq!(pin.check(&Digits::empty()), Err(PatternError::Empty));
+        assert_eq!(pin.check(&digits("wW")), Err(PatternError::Empty));
+        assert_eq!(
+            pin.check(&digits("12345")),
+            Err(PatternError::TooLong { max: 4, len: 5 })
+        );
+        assert_eq!(
+            pin.check(&digits("12*4")),
+            Err(PatternError::NotAllowed {
+                digit: Digit::Star,
+                position: 2
+            })
+        );
+
+        let extension = DigitsPattern::new()
+            .length(2..5)
+            .allow([Digit::Star, Digit::Pound]);
+        assert!(extension.matches(&digits("*12")));
+        assert_eq!(
+            extension.check(&digits("1")),
+            Err(PatternError::TooShort { min: 2, len: 1 })
+        );
+        assert!(!extension.matches(&digits("12345")));
+    }
+
+    #[test]
+    fn test_terminator() {
+        let menu = DigitsPattern::new()
+            .length(1..=3)
+            .only(Digit::One..=Digit::Three)
+            .terminated_by(Digit::Pound);
+        assert_eq!(menu.check(&digits("12#")), Ok(digits("12")));
+        assert_eq!(
+            menu.check(&digits("12")),
+            Err(PatternError::MissingTerminator(Digit::Pound))
+        );
+        assert_eq!(
+            menu.check(&digits("14#")),
+            Err(PatternError::NotAllowed {
+                digit: Digit::Four,
+                position: 1
+            })
+        );
+        assert_eq!(
+            menu.check(&digits("#")),
+            Err(PatternError::TooShort { min: 1, len: 0 })
+        );
+        assert_eq!(
+            PatternError::MissingTerminator(Digit::Pound).to_string(),
+            "not terminated by `#`"
+        );
+    }
+
+    #[test]
+    fn test_luhn() {
+        let card = DigitsPattern::card_number();
+        assert!(card.matches(&digits("4111111111111111")));
+        assert!(!card.matches(&digits("79927398713")));
+        assert_eq!(
+            card.check(&digits("4111111111111112")),
+            Err(PatternError::Luhn)
+        );
+        assert!(luhn(&digits("79927398713")));
+        assert!(!luhn(&digits("7992739871A")));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::Digit;
+
 pub type Result<T> = std::result::Result<T, TwilioError>;
 
 #[derive(Debug, thiserror::Error)]
@@ -8,14 +10,26 @@ pub enum TwilioError {
     Io(#[from] std::io::Error),
     #[error("Parse URL: {0}")]
     Url(#[from] url::ParseError),
+    #[error("HTTP: {0}")]
+    Http(#[from] http::Error),
+    #[error("invalid CIDR: {0}")]
+    Cidr(String),
     #[error("Digits: {0}")]
     Digits(#[from] DigitsError),
+    #[error("Digits pattern: {0}")]
+    Pattern(#[from] PatternError),
+    #[error("DTMF: {0}")]
+    Dtmf(#[from] DtmfError),
     #[error("Response deserialization error: {0}, raw xml: {1}")]
     ResponseDeser(String, String),
     #[error("serde_urlencoded serialization error: {0}")]
     SerdeUrlEncode(#[from] serde_urlencoded::ser::Error),
     #[error("serde_urlencoded deserialization error: {0}")]
     SerdeUrlDecode(#[from] serde_urlencoded::de::Error),
+    #[error("rate card: {0}")]
+    RateCard(String),
+    #[error("unsupported language: {0}")]
+    Language(String),
 }
 
 #[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq, Hash)]
@@ -37,3 +51,29 @@ pub enum DigitsError {
     #[error("negative number: {0}")]
     NegativeNumber(i128),
 }
+
+/// Why [`Digits`](crate::Digits) don't match a [`DigitsPattern`](crate::digits::DigitsPattern)
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PatternError {
+    #[error("nothing was entered")]
+    Empty,
+    #[error("expected at least {min} digits, got {len}")]
+    TooShort { min: usize, len: usize },
+    #[error("expected at most {max} digits, got {len}")]
+    TooLong { max: usize, len: usize },
+    #[error("`{digit}` is not allowed at position {position}")]
+    NotAllowed { digit: Digit, position: usize },
+    #[error("not terminated by `{0}`")]
+    MissingTerminator(Digit),
+    #[error("fails the Luhn check")]
+    Luhn,
+}
+
+/// Why a [`DtmfScript`](crate::digits::DtmfScript) can't be sent
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DtmfError {
+    #[error("has no keys to send")]
+    NoKeys,
+    #[error("is {len} characters long, Twilio allows at most {max}")]
+    TooLong { len: usize, max: usize },
+}
@@ -1,10 +1,17 @@
+#[cfg(feature = "actix")]
+pub mod actix;
+#[cfg(feature = "axum")]
+pub mod axum;
+
 pub mod client;
 
+pub mod cost;
+
 pub mod errors;
 pub use errors::{Result, TwilioError};
 
 pub mod digits;
-pub use digits::{Digit, Digits};
+pub use digits::{Digit, Digits, DigitsPattern, DtmfScript};
 
 pub mod twilio;
 pub mod twiml;
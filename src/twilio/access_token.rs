@@ -0,0 +1,275 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use hmac::{Hmac, KeyInit, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use typed_builder::TypedBuilder;
+
+/// Longest TTL Twilio accepts for an access token
+pub const MAX_ACCESS_TOKEN_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// What a Voice SDK client may do with an [`AccessToken`]
+/// https://www.twilio.com/docs/iam/access-tokens#voice-grant
+#[derive(Debug, Clone, Default, PartialEq, Eq, TypedBuilder)]
+pub struct VoiceGrant {
+    /// Accept incoming calls to the token's identity
+    #[builder(default)]
+    pub incoming_allow: bool,
+    /// TwiML App handling the client's outgoing calls
+    #[builder(default, setter(strip_option, into))]
+    pub outgoing_application_sid: Option<String>,
+    /// Parameters sent to the TwiML App with outgoing calls
+    #[builder(default)]
+    pub outgoing_application_params: BTreeMap<String, String>,
+    /// Push credential for incoming call notifications on mobile
+    #[builder(default, setter(strip_option, into))]
+    pub push_credential_sid: Option<String>,
+}
+
+/// An access token for the Twilio Voice client SDKs, signed with an API key as an HS256 JWT.
+/// https://www.twilio.com/docs/iam/access-tokens
+///
+/// ```
+/// use twilio_voice::twilio::{AccessToken, VoiceGrant};
+///
+/// let token = AccessToken::builder()
+///     .account_sid("ACxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx")
+///     .api_key_sid("SKxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx")
+///     .api_key_secret("secret")
+///     .identity("alice")
+///     .voice_grant(
+///         VoiceGrant::builder()
+///             .incoming_allow(true)
+///             .outgoing_application_sid("APxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx")
+///             .build(),
+///     )
+///     .build();
+/// let jwt = token.to_jwt();
+/// assert_eq!(jwt.split('.').count(), 3);
+/// ```
+#[derive(Clone, PartialEq, Eq, TypedBuilder)]
+pub struct AccessToken {
+    #[builder(setter(into))]
+    pub account_sid: String,
+    #[builder(setter(into))]
+    pub api_key_sid: String,
+    #[builder(setter(into))]
+    pub api_key_secret: String,
+    /// Client name the token is for, required to receive calls
+    #[builder(default, setter(strip_option, into))]
+    pub identity: Option<String>,
+    /// Validity from the time of issue, at most [`MAX_ACCESS_TOKEN_TTL`]
+    #[builder(default = Duration::from_secs(60 * 60))]
+    pub ttl: Duration,
+    /// Not valid before this time
+    #[builder(default, setter(strip_option))]
+    pub not_before: Option<SystemTime>,
+    /// Time of issue, now when unset
+    #[builder(default, setter(strip_option))]
+    pub issued_at: Option<SystemTime>,
+    #[builder(default, setter(strip_option))]
+    pub voice_grant: Option<VoiceGrant>,
+}
+
+#[derive(Serialize)]
+struct Header {
+    alg: &'static str,
+    typ: &'static str,
+    cty: &'static str,
+}
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    jti: String,
+    iss: &'a str,
+    sub: &'a str,
+    iat: u64,
+    exp: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nbf: Option<u64>,
+    grants: Grants<'a>,
+}
+
+#[derive(Serialize)]
+struct Grants<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    identity: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    voice: Option<VoiceClaims<'a>>,
+}
+
+#[derive(Serialize)]
+struct VoiceClaims<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    incoming: Option<Incoming>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outgoing: Option<Outgoing<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    push_credential_sid: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct Incoming {
+    allow: bool,
+}
+
+#[derive(Serialize)]
+struct Outgoing<'a> {
+    application_sid: &'a str,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    params: &'a BTreeMap<String, String>,
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl AccessToken {
+    /// The signed JWT to hand to the client SDK
+    pub fn to_jwt(&self) -> String {
+        let iat = unix_seconds(self.issued_at.unwrap_or_else(SystemTime::now));
+        let voice = self.voice_grant.as_ref().map(|grant| VoiceClaims {
+            incoming: grant.incoming_allow.then_some(Incoming { allow: true }),
+            outgoing: grant
+                .outgoing_application_sid
+                .as_deref()
+                .map(|application_sid| Outgoing {
+                    application_sid,
+                    params: &grant.outgoing_application_params,
+                }),
+            push_credential_sid: grant.push_credential_sid.as_deref(),
+        });
+        let claims = Claims {
+            jti: format!("{}-{iat}", self.api_key_sid),
+            iss: &self.api_key_sid,
+            sub: &self.account_sid,
+            iat,
+            exp: iat + self.ttl.min(MAX_ACCESS_TOKEN_TTL).as_secs(),
+            nbf: self.not_before.map(unix_seconds),
+            grants: Grants {
+                identity: self.identity.as_deref(),
+                voice,
+            },
+        };
+        let header = Header {
+            alg: "HS256",
+            typ: "JWT",
+            cty: "twilio-fpa;v=1",
+        };
+
+        let encode = |json: Vec<u8>| URL_SAFE_NO_PAD.encode(json);
+        let message = format!(
+            "{}.{}",
+            encode(serde_json::to_vec(&header).expect("header serializes")),
+            encode(serde_json::to_vec(&claims).expect("claims serialize"))
+        );
+        let signature = sign_hs256(&message, &self.api_key_secret);
+        format!("{message}.{signature}")
+    }
+}
+
+impl std::fmt::Debug for AccessToken {
+    /// Without the API key secret
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccessToken")
+            .field("account_sid", &self.account_sid)
+            .field("api_key_sid", &self.api_key_sid)
+            .field("identity", &self.identity)
+            .field("ttl", &self.ttl)
+            .field("not_before", &self.not_before)
+            .field("issued_at", &self.issued_at)
+            .field("voice_grant", &self.voice_grant)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Base64url HMAC-SHA256 of `message`
+fn sign_hs256(message: &str, secret: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(message.as_bytes());
+    URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(part: &str) -> serde_json::Value {
+        serde_json::from_slice(&URL_SAFE_NO_PAD.decode(part).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_sign_hs256() {
+        // https://jwt.io example
+        let message = concat!(
+            "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.",
+            "eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiaWF0IjoxNTE2MjM5MDIyfQ"
+        );
+        assert_eq!(
+            sign_hs256(message, "your-256-bit-secret"),
+            "SflKxwRJSMeKKF2QT4fwpMeJf36POk6yJV_adQssw5c"
+        );
+    }
+
+    #[test]
+    fn test_access_token() {
+        let issued_at = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let token = AccessToken::builder()
+            .account_sid("AC123")
+            .api_key_sid("SK456")
+            .api_key_secret("secret")
+            .identity("alice")
+            .ttl(Duration::from_secs(600))
+            .issued_at(issued_at)
+            .not_before(issued_at)
+            .voice_grant(
+                VoiceGrant::builder()
+                    .incoming_allow(true)
+                    .outgoing_application_sid("AP789")
+                    .outgoing_application_params(BTreeMap::from([(
+                        "team".to_string(),
+                        "support".to_string(),
+                    )]))
+                    .push_credential_sid("CR000")
+                    .build(),
+            )
+            .build();
+        let jwt = token.to_jwt();
+        assert_eq!(
+            jwt,
+            concat!(
+                "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCIsImN0eSI6InR3aWxpby1mcGE7dj0xIn0.",
+                "eyJqdGkiOiJTSzQ1Ni0xNzAwMDAwMDAwIiwiaXNzIjoiU0s0NTYiLCJzdWIiOiJBQzEyMyIsImlhdCI6",
+                "MTcwMDAwMDAwMCwiZXhwIjoxNzAwMDAwNjAwLCJuYmYiOjE3MDAwMDAwMDAsImdyYW50cyI6eyJpZGVu",
+                "dGl0eSI6ImFsaWNlIiwidm9pY2UiOnsiaW5jb21pbmciOnsiYWxsb3ciOnRydWV9LCJvdXRnb2luZyI6",
+                "eyJhcHBsaWNhdGlvbl9zaWQiOiJBUDc4OSIsInBhcmFtcyI6eyJ0ZWFtIjoic3VwcG9ydCJ9fSwicHVz",
+                "aF9jcmVkZW50aWFsX3NpZCI6IkNSMDAwIn19fQ.",
+                "LnbiTaBw0nVXozziN6dJJoCT67_zLf6LcDdTrDmfco8"
+            )
+        );
+    }
+
+    #[test]
+    fn test_defaults() {
+        let token = AccessToken::builder()
+            .account_sid("AC123")
+            .api_key_sid("SK456")
+            .api_key_secret("secret")
+            .ttl(Duration::from_secs(48 * 60 * 60))
+            .build();
+        let jwt = token.to_jwt();
+        let claims = decode(jwt.split('.').nth(1).unwrap());
+        assert_eq!(
+            claims["exp"].as_u64().unwrap() - claims["iat"].as_u64().unwrap(),
+            MAX_ACCESS_TOKEN_TTL.as_secs()
+        );
+        assert_eq!(claims["grants"], serde_json::json!({}));
+        assert!(claims.get("nbf").is_none());
+        assert!(!format!("{token:?}").contains("secret\""));
+    }
+}
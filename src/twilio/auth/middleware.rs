@@ -1,35 +1,151 @@
-use axum::{body::Body, extract::Request, http::StatusCode, response::Response};
 use bytes::Bytes;
 use futures_util::future::BoxFuture;
-use http::{Method, Uri, request::Parts};
-use http_body_util::BodyExt;
+use http::{Method, Request, Response, StatusCode, Uri, request::Parts};
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
 use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
 use std::task::{Context, Poll};
-use tower::{Layer, Service};
+use tower::{BoxError, Layer, Service};
+use tracing::warn;
 use url::form_urlencoded;
 
-use super::TwilioRequest;
+use super::{
+    AuthTokens, ForwardedOrigin, ReplayAction, ReplayGuard, Replayed, TrustedProxies,
+    TwilioRequest, ValidationOutcome,
+};
 
+/// The request body as read by [`TwilioLayer`], in the request extensions so extractors
+/// don't collect it again
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferedBody(pub Bytes);
+
+/// Largest body [`TwilioLayer`] reads by default
+pub const DEFAULT_MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// Why [`TwilioMiddleware`] responded without calling the inner service
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    /// The signature is invalid and enforced
+    Invalid(ValidationOutcome),
+    /// The webhook was already received, see [`TwilioLayer::replay_guard`]
+    Replayed(Replayed),
+    /// The body is larger than [`TwilioLayer::max_body_size`]
+    BodyTooLarge { limit: usize },
+    /// Reading the body failed
+    Body(String),
+}
+
+impl Rejection {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Rejection::Invalid(_) => StatusCode::FORBIDDEN,
+            Rejection::Replayed(_) => StatusCode::CONFLICT,
+            Rejection::BodyTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            Rejection::Body(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+/// The rejection status, with the reason as body in debug builds only
+pub fn default_rejection(rejection: &Rejection) -> Response<Bytes> {
+    let body = if cfg!(debug_assertions) {
+        Bytes::from(match rejection {
+            Rejection::Invalid(_) => "Invalid Twilio signature".to_string(),
+            Rejection::Replayed(_) => "Replayed Twilio request".to_string(),
+            Rejection::BodyTooLarge { limit } => format!("Body larger than {limit} bytes"),
+            Rejection::Body(error) => format!("Failed to read body: {error}"),
+        })
+    } else {
+        Bytes::new()
+    };
+    let mut response = Response::new(body);
+    *response.status_mut() = rejection.status();
+    response
+}
+
+type RejectionFn = dyn Fn(&Rejection) -> Response<Bytes> + Send + Sync;
+
+/// Validates Twilio webhooks in any tower stack taking [`http::Request`]s, e.g. axum,
+/// hyper or tonic.
+///
+/// The inner service gets the request with its body collected into a [`Full`], and the
+/// [`ValidationOutcome`] and a [`BufferedBody`] in its extensions. Its response body must be buildable from
+/// [`Bytes`] for rejections, and `Send`, as must its errors.
 #[derive(Clone)]
 pub struct TwilioLayer {
-    auth_token: String,
+    auth_tokens: Arc<dyn AuthTokens>,
     options: super::RequestValidatorOptions,
+    trusted_proxies: TrustedProxies,
+    replay_guard: Option<ReplayGuard>,
+    max_body_size: usize,
+    rejection: Arc<RejectionFn>,
 }
 
 impl TwilioLayer {
     pub fn new(auth_token: &str) -> Self {
-        Self {
-            auth_token: auth_token.to_string(),
-            options: Default::default(),
-        }
+        Self::with_tokens(auth_token.to_string())
     }
 
     pub fn with_options(auth_token: &str, options: super::RequestValidatorOptions) -> Self {
+        Self::with_tokens_and_options(auth_token.to_string(), options)
+    }
+
+    /// `auth_tokens` is several tokens during a rotation, or a provider like
+    /// [`RotatingAuthTokens`](super::RotatingAuthTokens)
+    pub fn with_tokens(auth_tokens: impl AuthTokens + 'static) -> Self {
+        Self::with_tokens_and_options(auth_tokens, Default::default())
+    }
+
+    pub fn with_tokens_and_options(
+        auth_tokens: impl AuthTokens + 'static,
+        options: super::RequestValidatorOptions,
+    ) -> Self {
         Self {
-            auth_token: auth_token.to_string(),
+            auth_tokens: Arc::new(auth_tokens),
             options,
+            trusted_proxies: Default::default(),
+            replay_guard: None,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            rejection: Arc::new(default_rejection),
         }
     }
+
+    /// Reconstructs the webhook URL from the forwarding headers of these proxies.
+    ///
+    /// The peer address is read from a [`SocketAddr`] request extension or, with the
+    /// `axum` feature, from axum's `ConnectInfo<SocketAddr>`, so serve with
+    /// `into_make_service_with_connect_info::<SocketAddr>()`. The
+    /// [`host`](super::RequestValidatorOptions::host) and
+    /// [`protocol`](super::RequestValidatorOptions::protocol) options still take precedence,
+    /// set `protocol` to `None` to use the forwarded one.
+    pub fn trusted_proxies(mut self, trusted_proxies: TrustedProxies) -> Self {
+        self.trusted_proxies = trusted_proxies;
+        self
+    }
+
+    /// Rejects or flags webhooks that were already handled or are being handled. Only
+    /// requests with a valid signature that the inner service answered with a success status
+    /// are recorded.
+    pub fn replay_guard(mut self, replay_guard: ReplayGuard) -> Self {
+        self.replay_guard = Some(replay_guard);
+        self
+    }
+
+    /// Larger bodies are rejected, [`DEFAULT_MAX_BODY_SIZE`] by default
+    pub fn max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// Builds the responses to rejected requests, [`default_rejection`] by default
+    pub fn rejection(
+        mut self,
+        rejection: impl Fn(&Rejection) -> Response<Bytes> + Send + Sync + 'static,
+    ) -> Self {
+        self.rejection = Arc::new(rejection);
+        self
+    }
 }
 
 impl<S> Layer<S> for TwilioLayer {
@@ -38,8 +154,7 @@ impl<S> Layer<S> for TwilioLayer {
     fn layer(&self, inner: S) -> Self::Service {
         TwilioMiddleware {
             inner,
-            auth_token: self.auth_token.clone(),
-            options: self.options.clone(),
+            layer: self.clone(),
         }
     }
 }
@@ -47,14 +162,18 @@ impl<S> Layer<S> for TwilioLayer {
 #[derive(Clone)]
 pub struct TwilioMiddleware<S> {
     inner: S,
-    auth_token: String,
-    options: super::RequestValidatorOptions,
+    layer: TwilioLayer,
 }
 
-impl<S> Service<Request> for TwilioMiddleware<S>
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for TwilioMiddleware<S>
 where
-    S: Service<Request, Response = Response> + Send + Clone + 'static,
+    S: Service<Request<Full<Bytes>>, Response = Response<ResBody>> + Send + Clone + 'static,
     S::Future: Send + 'static,
+    S::Error: Send,
+    ReqBody: http_body::Body + Send + 'static,
+    ReqBody::Data: Send,
+    ReqBody::Error: Into<BoxError>,
+    ResBody: From<Bytes> + Send,
 {
     type Response = S::Response;
     type Error = S::Error;
@@ -64,64 +183,131 @@ where
         self.inner.poll_ready(cx)
     }
 
-    fn call(&mut self, request: Request) -> Self::Future {
-        let auth_token = self.auth_token.clone();
-        let options = self.options.clone();
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let layer = self.layer.clone();
         let mut inner = self.inner.clone();
 
         Box::pin(async move {
+            let reject =
+                |rejection: Rejection| Ok((layer.rejection)(&rejection).map(ResBody::from));
+
             // Extract request parts and original URI
             let (parts, body) = request.into_parts();
             let original_uri = parts.uri.clone();
 
-            // Collect the body
-            let body_bytes = body
-                .collect()
-                .await
-                .map_err(|_| ())
-                .unwrap_or_default()
-                .to_bytes();
+            // Collect the body, up to the limit
+            let body_bytes = match Limited::new(body, layer.max_body_size).collect().await {
+                Ok(collected) => collected.to_bytes(),
+                Err(error) if error.is::<LengthLimitError>() => {
+                    return reject(Rejection::BodyTooLarge {
+                        limit: layer.max_body_size,
+                    });
+                }
+                Err(error) => return reject(Rejection::Body(error.to_string())),
+            };
+
+            // Where the request came from before the trusted proxies
+            let forwarded = peer_addr(&parts.extensions)
+                .and_then(|peer| layer.trusted_proxies.origin(peer.ip(), &parts.headers))
+                .unwrap_or_default();
 
             // Create a TwilioRequest implementation
             let twilio_request = TwilioRequestImpl {
                 original_uri,
+                forwarded,
                 parts: parts.clone(),
                 body_bytes: body_bytes.clone(),
             };
 
             // Validate the request
 
-            let is_valid = super::validate_incoming_request(&twilio_request, &auth_token, options);
-
-            if !is_valid {
-                return Ok(Response::builder()
-                    .status(StatusCode::FORBIDDEN)
-                    .body(if cfg!(debug_assertions) {
-                        Body::from("Invalid Twilio signature")
-                    } else {
-                        Body::empty()
-                    })
-                    .unwrap());
+            let outcome = super::validate_incoming_request(
+                &twilio_request,
+                &*layer.auth_tokens,
+                layer.options.clone(),
+            );
+
+            if !outcome.is_allowed() {
+                return reject(Rejection::Invalid(outcome));
             }
 
-            // Create a new request with the original body
-            let restored_request = Request::from_parts(parts, Body::from(body_bytes));
+            // A request with a valid signature can't change its key
+            let replay_key = layer.replay_guard.as_ref().and_then(|_| {
+                Some(super::replay_key(
+                    outcome.matched_url()?,
+                    &twilio_request.twilio_signature()?,
+                ))
+            });
+            // Claimed before calling the inner service, so only one of concurrent
+            // duplicates is handled
+            let (claimed, replayed) = match (&layer.replay_guard, &replay_key) {
+                (Some(replay_guard), Some(key)) => match replay_guard.claim(key).await {
+                    Some(replayed) => (false, Some(replayed)),
+                    None => (true, None),
+                },
+                _ => (false, None),
+            };
+            if let Some(replayed) = &replayed {
+                warn!("Replayed Twilio request: {}", replayed.key);
+                if layer.replay_guard.as_ref().map(ReplayGuard::replay_action)
+                    == Some(ReplayAction::Reject)
+                {
+                    return reject(Rejection::Replayed(replayed.clone()));
+                }
+            }
+
+            // Create a new request with the original body, handlers can read the outcome
+            // from its extensions
+            let mut restored_request = Request::from_parts(parts, Full::new(body_bytes.clone()));
+            restored_request.extensions_mut().insert(outcome);
+            restored_request
+                .extensions_mut()
+                .insert(BufferedBody(body_bytes));
+            if let Some(replayed) = replayed {
+                restored_request.extensions_mut().insert(replayed);
+            }
 
-            let future = inner.call(restored_request);
-            future.await
+            let result = inner.call(restored_request).await;
+
+            // Recorded once handled, released otherwise so that Twilio's retries of failed
+            // webhooks get through
+            let handled = matches!(&result, Ok(response) if response.status().is_success());
+            if let (Some(replay_guard), Some(key), true) =
+                (&layer.replay_guard, replay_key, claimed)
+            {
+                if handled {
+                    replay_guard.record(&key).await;
+                } else {
+                    replay_guard.release(&key).await;
+                }
+            }
+            result
         })
     }
 }
 
+/// Address of the client or proxy that sent the request
+fn peer_addr(extensions: &http::Extensions) -> Option<SocketAddr> {
+    #[cfg(feature = "axum")]
+    if let Some(axum::extract::ConnectInfo(peer)) = extensions.get() {
+        return Some(*peer);
+    }
+    extensions.get::<SocketAddr>().copied()
+}
+
 // Implementation of TwilioRequest trait using OriginalUri
 struct TwilioRequestImpl {
     original_uri: Uri,
+    forwarded: ForwardedOrigin,
     parts: Parts,
     body_bytes: Bytes,
 }
 
 impl TwilioRequest for TwilioRequestImpl {
     fn protocol(&self) -> String {
+        if let Some(proto) = &self.forwarded.proto {
+            return proto.clone();
+        }
         self.original_uri
             .scheme_str()
             .unwrap_or("http") // Default to http if scheme is missing
@@ -129,6 +315,9 @@ impl TwilioRequest for TwilioRequestImpl {
     }
 
     fn host(&self) -> String {
+        if let Some(host) = &self.forwarded.host {
+            return host.clone();
+        }
         self.original_uri
             .authority()
             .map(|auth| auth.to_string())
@@ -177,3 +366,268 @@ impl TwilioRequest for TwilioRequestImpl {
         self.parts.method.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use futures::executor::block_on;
+
+    use crate::twilio::{RotatingAuthTokens, TwilioRequestSigner};
+
+    use super::*;
+
+    const TEST_AUTH_TOKEN: &str = "aaf98aa0a69a870c2e5a0e774af3f8b2";
+
+    /// Responds with the length of the body it got
+    #[derive(Clone)]
+    struct Echo;
+
+    impl Service<Request<Full<Bytes>>> for Echo {
+        type Response = Response<Bytes>;
+        type Error = Infallible;
+        type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: Request<Full<Bytes>>) -> Self::Future {
+            Box::pin(async move {
+                let replayed = request.extensions().get::<Replayed>().is_some();
+                let body = request.into_body().collect().await?.to_bytes();
+                Ok(Response::new(format!("{} {replayed}", body.len()).into()))
+            })
+        }
+    }
+
+    /// Fails with `500 Internal Server Error` until `failures` runs out
+    #[derive(Clone)]
+    struct Flaky {
+        failures: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl Service<Request<Full<Bytes>>> for Flaky {
+        type Response = Response<Bytes>;
+        type Error = Infallible;
+        type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: Request<Full<Bytes>>) -> Self::Future {
+            use std::sync::atomic::Ordering;
+            let failing = self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+            let mut response = Response::new(Bytes::new());
+            if failing {
+                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+            }
+            Box::pin(std::future::ready(Ok(response)))
+        }
+    }
+
+    /// Counts its calls, and yields once before responding
+    #[derive(Clone)]
+    struct Slow {
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl Service<Request<Full<Bytes>>> for Slow {
+        type Response = Response<Bytes>;
+        type Error = Infallible;
+        type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: Request<Full<Bytes>>) -> Self::Future {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let mut yielded = false;
+            Box::pin(async move {
+                std::future::poll_fn(|cx| {
+                    if yielded {
+                        return Poll::Ready(());
+                    }
+                    yielded = true;
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                })
+                .await;
+                Ok(Response::new(Bytes::new()))
+            })
+        }
+    }
+
+    fn call(layer: &TwilioLayer, request: Request<String>) -> Response<Bytes> {
+        let request = request.map(|body| Full::new(Bytes::from(body)));
+        block_on(layer.layer(Echo).call(request)).unwrap()
+    }
+
+    #[test]
+    fn test_layer() {
+        let signer = TwilioRequestSigner::new(TEST_AUTH_TOKEN);
+        let params = BTreeMap::from([("CallSid".to_string(), "CA123".to_string())]);
+        let signed = || {
+            signer
+                .form(Method::POST, "https://example.com/voice", &params)
+                .unwrap()
+        };
+        // A borrowed token, e.g. from configuration
+        let auth_token = TEST_AUTH_TOKEN.to_string();
+        let layer = TwilioLayer::new(&auth_token);
+
+        let response = call(&layer, signed());
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body(), "13 false");
+
+        let forged = TwilioRequestSigner::new("wrong")
+            .form(Method::POST, "https://example.com/voice", &params)
+            .unwrap();
+        assert_eq!(call(&layer, forged).status(), StatusCode::FORBIDDEN);
+
+        let rotating = TwilioLayer::with_tokens(RotatingAuthTokens::new(["old", TEST_AUTH_TOKEN]));
+        assert_eq!(call(&rotating, signed()).status(), StatusCode::OK);
+
+        let small = layer.clone().max_body_size(8);
+        assert_eq!(
+            call(&small, signed()).status(),
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
+
+        let teapot = layer.clone().rejection(|rejection| {
+            let mut response = default_rejection(rejection);
+            if matches!(rejection, Rejection::Invalid(_)) {
+                *response.status_mut() = StatusCode::IM_A_TEAPOT;
+            }
+            response
+        });
+        let unsigned = Request::post("https://example.com/voice")
+            .body(String::new())
+            .unwrap();
+        assert_eq!(call(&teapot, unsigned).status(), StatusCode::IM_A_TEAPOT);
+    }
+
+    #[test]
+    fn test_replay_guard() {
+        let signer = TwilioRequestSigner::new(TEST_AUTH_TOKEN);
+        let params = BTreeMap::from([
+            ("CallSid".to_string(), "CA123".to_string()),
+            ("SequenceNumber".to_string(), "0".to_string()),
+        ]);
+        let signed = || {
+            signer
+                .form(Method::POST, "https://example.com/status", &params)
+                .unwrap()
+        };
+
+        let layer = TwilioLayer::new(TEST_AUTH_TOKEN).replay_guard(ReplayGuard::default());
+        assert_eq!(call(&layer, signed()).status(), StatusCode::OK);
+        assert_eq!(call(&layer, signed()).status(), StatusCode::CONFLICT);
+
+        let layer = TwilioLayer::new(TEST_AUTH_TOKEN)
+            .replay_guard(ReplayGuard::default().action(ReplayAction::Flag));
+        assert!(call(&layer, signed()).body().ends_with(b"false"));
+        assert!(call(&layer, signed()).body().ends_with(b"true"));
+
+        // A GET webhook is identified by its query string
+        let get = || {
+            signer
+                .form(Method::GET, "https://example.com/status", &params)
+                .unwrap()
+        };
+        let layer = TwilioLayer::new(TEST_AUTH_TOKEN).replay_guard(ReplayGuard::default());
+        assert_eq!(call(&layer, get()).status(), StatusCode::OK);
+        assert_eq!(call(&layer, get()).status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn test_replay_guard_retry_after_failure() {
+        let signer = TwilioRequestSigner::new(TEST_AUTH_TOKEN);
+        let params = BTreeMap::from([("CallSid".to_string(), "CA123".to_string())]);
+        let retry = || {
+            signer
+                .form(Method::POST, "https://example.com/status", &params)
+                .unwrap()
+                .map(|body| Full::new(Bytes::from(body)))
+        };
+
+        let layer = TwilioLayer::new(TEST_AUTH_TOKEN).replay_guard(ReplayGuard::default());
+        let mut service = layer.layer(Flaky {
+            failures: Arc::new(1.into()),
+        });
+        let status = |service: &mut TwilioMiddleware<Flaky>| {
+            block_on(service.call(retry())).unwrap().status()
+        };
+        assert_eq!(status(&mut service), StatusCode::INTERNAL_SERVER_ERROR);
+        // Twilio's retry is handled, as the first attempt failed
+        assert_eq!(status(&mut service), StatusCode::OK);
+        assert_eq!(status(&mut service), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn test_replay_guard_unsigned_headers() {
+        let signer = TwilioRequestSigner::new(TEST_AUTH_TOKEN);
+        let params = BTreeMap::from([("CallSid".to_string(), "CA123".to_string())]);
+        let layer = TwilioLayer::new(TEST_AUTH_TOKEN).replay_guard(ReplayGuard::default());
+        let request = || {
+            signer
+                .form(Method::POST, "https://example.com/status", &params)
+                .unwrap()
+        };
+        assert_eq!(call(&layer, request()).status(), StatusCode::OK);
+
+        // Headers that aren't signed don't make a replay look new
+        let mut replay = request();
+        replay
+            .headers_mut()
+            .insert("i-twilio-idempotency-token", "forged".parse().unwrap());
+        assert_eq!(call(&layer, replay).status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn test_replay_guard_concurrent() {
+        let signer = TwilioRequestSigner::new(TEST_AUTH_TOKEN);
+        let params = BTreeMap::from([("CallSid".to_string(), "CA123".to_string())]);
+        let request = || {
+            signer
+                .form(Method::POST, "https://example.com/status", &params)
+                .unwrap()
+                .map(|body| Full::new(Bytes::from(body)))
+        };
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut service = TwilioLayer::new(TEST_AUTH_TOKEN)
+            .replay_guard(ReplayGuard::default())
+            .layer(Slow {
+                calls: calls.clone(),
+            });
+
+        let (first, second) = block_on(futures::future::join(
+            service.call(request()),
+            service.call(request()),
+        ));
+        let mut statuses = [first.unwrap().status(), second.unwrap().status()];
+        statuses.sort();
+        assert_eq!(statuses, [StatusCode::OK, StatusCode::CONFLICT]);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[cfg(feature = "axum")]
+    #[test]
+    fn test_axum_router() {
+        let signer = TwilioRequestSigner::new(TEST_AUTH_TOKEN);
+        let mut router = axum::Router::new()
+            .route("/voice", axum::routing::post(|| async { "<Response/>" }))
+            .layer(TwilioLayer::new(TEST_AUTH_TOKEN));
+        let request = signer
+            .form(Method::POST, "https://example.com/voice", &BTreeMap::new())
+            .unwrap()
+            .map(axum::body::Body::from);
+        let response = block_on(router.call(request)).unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -1,6 +1,17 @@
-#[cfg(feature = "middleware")]
+#[cfg(feature = "tower")]
 pub mod middleware;
 
+mod outcome;
+pub use outcome::*;
+mod proxy;
+pub use proxy::*;
+mod replay;
+pub use replay::*;
+mod signer;
+pub use signer::*;
+mod tokens;
+pub use tokens::*;
+
 use base64::{Engine as _, engine::general_purpose};
 use hmac::{Hmac, KeyInit, Mac};
 use http::Method;
@@ -256,21 +267,30 @@ fn without_trailing_slash(url: &str) -> String {
 /// Validate that a request came from Twilio
 ///
 /// # Arguments
-/// * `auth_token` - The auth token, as seen in the Twilio portal
+/// * `auth_tokens` - The auth token as seen in the Twilio portal, or several during a rotation
 /// * `twilio_sig` - The value of the X-Twilio-Signature header from the request
 /// * `url` - The full URL (with query string) you configured to handle this request
 /// * `params` - The parameters sent with this request
 ///
 /// # Returns
-/// * `true` if the request is valid, `false` otherwise
+/// * The URL variant the signature matched, or why it didn't
 pub fn validate_request(
-    auth_token: &str,
+    auth_tokens: &(impl AuthTokens + ?Sized),
     twilio_sig: &str,
     url: &str,
     params: &BTreeMap<String, String>,
     options: &RequestValidatorOptions,
-) -> bool {
+) -> ValidationOutcome {
     debug!("Validating Twilio request from: {url}");
+    if twilio_sig.is_empty() {
+        warn!("Twilio request validation failed: no signature");
+        return ValidationOutcome::invalid(ValidationFailure::MissingSignature, options);
+    }
+    let auth_tokens = auth_tokens.tokens();
+    if auth_tokens.is_empty() {
+        warn!("Twilio request validation failed: no auth tokens");
+        return ValidationOutcome::invalid(ValidationFailure::NoAuthTokens, options);
+    }
 
     // Check signature of the url with and without the port number
     // and with and without the legacy querystring (special chars are encoded when using `new URL()`)
@@ -294,24 +314,38 @@ pub fn validate_request(
     .flat_map(|v| [without_trailing_slash(&v), v])
     .collect::<HashSet<_>>();
 
-    let mut tried = vec![];
-
     debug!("Trying variants: {variants:?}");
-    let valid = variants.iter().any(|variant_url| {
-        let valid = validate_signature_with_url(auth_token, twilio_sig, variant_url, params);
-        if !valid {
-            tried.push(variant_url.clone());
-            debug!("Twilio request failed to validate with: {variant_url}");
-        }
-        valid
-    });
-    if !valid {
+    let matched = auth_tokens
+        .iter()
+        .enumerate()
+        .find_map(|(token, auth_token)| {
+            let url = variants.iter().find(|variant_url| {
+                let valid =
+                    validate_signature_with_url(auth_token, twilio_sig, variant_url, params);
+                if !valid {
+                    debug!("Twilio request failed to validate with token {token}: {variant_url}");
+                }
+                valid
+            })?;
+            Some(ValidationOutcome::Valid {
+                url: url.clone(),
+                token,
+            })
+        });
+    matched.unwrap_or_else(|| {
+        let mut tried = variants.into_iter().collect::<Vec<_>>();
+        tried.sort();
+        let hints = auth_tokens
+            .iter()
+            .map(|auth_token| token_hint(auth_token))
+            .collect::<Vec<_>>();
+        debug!("Twilio request signature {twilio_sig} tried with tokens ending in: {hints:?}");
         warn!(
-            "Twilio request validation failed with token ending in: {}, sig: {twilio_sig}\ntried urls: {tried:?}",
-            &auth_token[auth_token.len() - 4..]
+            "Twilio request validation failed with {} token(s)\ntried urls: {tried:?}",
+            hints.len()
         );
-    }
-    valid || !options.enforce
+        ValidationOutcome::invalid(ValidationFailure::SignatureMismatch { tried }, options)
+    })
 }
 
 /// Validate the body of a request against a body hash
@@ -336,20 +370,20 @@ pub fn validate_body(body: &str, body_hash: &str) -> bool {
 /// Validate a request with a JSON body
 ///
 /// # Arguments
-/// * `auth_token` - The auth token, as seen in the Twilio portal
+/// * `auth_tokens` - The auth token as seen in the Twilio portal, or several during a rotation
 /// * `twilio_sig` - The value of the X-Twilio-Signature header from the request
 /// * `url` - The full URL (with query string) you configured to handle this request
 /// * `body` - The body of the request
 ///
 /// # Returns
-/// * `true` if the request is valid, `false` otherwise
+/// * The URL variant the signature matched, or why the signature or body didn't match
 pub fn validate_request_with_body(
-    auth_token: &str,
+    auth_tokens: &(impl AuthTokens + ?Sized),
     twilio_sig: &str,
     url: &str,
     body: &str,
     options: &RequestValidatorOptions,
-) -> bool {
+) -> ValidationOutcome {
     let empty_params = BTreeMap::new();
     let url_object = Url::parse(url).expect("Invalid URL");
 
@@ -359,8 +393,11 @@ pub fn validate_request_with_body(
         .map(|(_, value)| value.to_string())
         .unwrap_or_default();
 
-    validate_request(auth_token, twilio_sig, url, &empty_params, options)
-        && (validate_body(body, &body_hash) || !options.enforce)
+    let outcome = validate_request(auth_tokens, twilio_sig, url, &empty_params, options);
+    if outcome.is_valid() && !validate_body(body, &body_hash) {
+        return ValidationOutcome::invalid(ValidationFailure::BodyHashMismatch, options);
+    }
+    outcome
 }
 
 /// Implement on a [`Request`] object for validation.
@@ -378,16 +415,16 @@ pub trait TwilioRequest {
 ///
 /// # Arguments
 /// * `request` - A request object implementing the TwilioRequest trait
-/// * `auth_token` - The auth token, as seen in the Twilio portal
+/// * `auth_tokens` - The auth token as seen in the Twilio portal, or several during a rotation
 /// * `opts` - Options for request validation
 ///
 /// # Returns
-/// * `true` if the request is valid, `false` otherwise
+/// * The URL variant the signature matched, or why the request is invalid
 pub fn validate_incoming_request<T: TwilioRequest>(
     request: &T,
-    auth_token: &str,
+    auth_tokens: &(impl AuthTokens + ?Sized),
     options: RequestValidatorOptions,
-) -> bool {
+) -> ValidationOutcome {
     #[cfg(debug_assertions)]
     let validated_with: &str;
 
@@ -430,9 +467,9 @@ pub fn validate_incoming_request<T: TwilioRequest>(
         url
     };
 
-    let is_valid = if webhook_url.contains("bodySHA256") {
+    let outcome = if webhook_url.contains("bodySHA256") {
         validate_request_with_body(
-            auth_token,
+            auth_tokens,
             &request.twilio_signature().unwrap_or_default(),
             &webhook_url,
             &request.raw_body().unwrap_or_default(),
@@ -440,7 +477,7 @@ pub fn validate_incoming_request<T: TwilioRequest>(
         )
     } else {
         validate_request(
-            auth_token,
+            auth_tokens,
             &request.twilio_signature().unwrap_or_default(),
             &webhook_url,
             &request.body(),
@@ -451,14 +488,14 @@ pub fn validate_incoming_request<T: TwilioRequest>(
     #[cfg(debug_assertions)]
     {
         let method = request.method();
-        if !is_valid {
+        if !outcome.is_valid() {
             debug!("INVALID URL: {method} {webhook_url}, validated with {validated_with}");
         } else {
             debug!("Valid URL: {method} {webhook_url}");
         }
     }
 
-    is_valid || !options.enforce
+    outcome
 }
 
 #[cfg(test)]
@@ -558,7 +595,8 @@ mod tests {
                     url_with_slash,
                     &params,
                     &Default::default()
-                ),
+                )
+                .is_allowed(),
                 "trailing slash in Twilio console webhook URL, and trailing slash received on our backend"
             );
             assert!(
@@ -568,7 +606,8 @@ mod tests {
                     url_without_slash,
                     &params,
                     &Default::default()
-                ),
+                )
+                .is_allowed(),
                 "no trailing slash in Twilio console webhook URL, and no trailing slash received on our backend"
             );
             assert!(
@@ -578,7 +617,8 @@ mod tests {
                     url_with_slash,
                     &params,
                     &Default::default()
-                ),
+                )
+                .is_allowed(),
                 "no trailing slash in Twilio console webhook URL, but received trailing slash on our backend - redirect or root URL without path"
             );
             assert!(
@@ -588,7 +628,8 @@ mod tests {
                     url_without_slash,
                     &params,
                     &Default::default()
-                ),
+                )
+                .is_allowed(),
                 "should never be valid, because our backend should never strip an existing trailing slash from the request"
             );
         }
@@ -760,76 +801,97 @@ mod tests {
         let expected_signature = get_expected_twilio_signature(auth_token, url, &params);
 
         // Test success case
-        assert!(validate_request(
-            auth_token,
-            &expected_signature,
-            url,
-            &params,
-            &Default::default()
-        ));
+        assert!(
+            validate_request(
+                auth_token,
+                &expected_signature,
+                url,
+                &params,
+                &Default::default()
+            )
+            .is_allowed()
+        );
 
         // Test failure cases
 
         // 1. Wrong auth token
-        assert!(!validate_request(
-            "wrong_auth_token",
-            &expected_signature,
-            url,
-            &params,
-            &Default::default()
-        ));
+        assert!(
+            !validate_request(
+                "wrong_auth_token",
+                &expected_signature,
+                url,
+                &params,
+                &Default::default()
+            )
+            .is_allowed()
+        );
 
         // 2. Tampered signature - force a completely different signature
         let tampered_signature = "XYZ_COMPLETELY_DIFFERENT_SIGNATURE_ABC=";
-        assert!(!validate_request(
-            auth_token,
-            tampered_signature,
-            url,
-            &params,
-            &Default::default()
-        ));
+        assert!(
+            !validate_request(
+                auth_token,
+                tampered_signature,
+                url,
+                &params,
+                &Default::default()
+            )
+            .is_allowed()
+        );
 
         // 3. Modified URL
-        assert!(!validate_request(
-            auth_token,
-            &expected_signature,
-            "https://example.com/myapp.php?foo=1&bar=3", // changed query param
-            &params,
-            &Default::default()
-        ));
+        assert!(
+            !validate_request(
+                auth_token,
+                &expected_signature,
+                "https://example.com/myapp.php?foo=1&bar=3", // changed query param
+                &params,
+                &Default::default()
+            )
+            .is_allowed()
+        );
 
         // 4. Modified params
         let mut tampered_params = params.clone();
         tampered_params.insert("Digits".to_string(), "5678".to_string()); // changed value
-        assert!(!validate_request(
-            auth_token,
-            &expected_signature,
-            url,
-            &tampered_params,
-            &Default::default()
-        ));
+        assert!(
+            !validate_request(
+                auth_token,
+                &expected_signature,
+                url,
+                &tampered_params,
+                &Default::default()
+            )
+            .is_allowed()
+        );
 
         // 5. Additional param
         let mut extended_params = params.clone();
         extended_params.insert("Extra".to_string(), "Parameter".to_string()); // added param
-        assert!(!validate_request(
-            auth_token,
-            &expected_signature,
-            url,
-            &extended_params,
-            &Default::default()
-        ));
+        assert!(
+            !validate_request(
+                auth_token,
+                &expected_signature,
+                url,
+                &extended_params,
+                &Default::default()
+            )
+            .is_allowed()
+        );
 
         // 6. Missing param
         let mut reduced_params = params.clone();
         reduced_params.remove("Digits"); // removed param
-        assert!(!validate_request(
-            auth_token,
-            &expected_signature,
-            url,
-            &reduced_params,
-            &Default::default()
-        ));
+        assert!(
+            !validate_request(
+                auth_token,
+                &expected_signature,
+                url,
+                &reduced_params,
+                &Default::default()
+            )
+            .is_allowed()
+        );
     }
 
     #[test]
@@ -843,54 +905,170 @@ mod tests {
         let expected_signature = get_expected_twilio_signature(auth_token, &url, &BTreeMap::new());
 
         // Test success case
-        assert!(validate_request_with_body(
-            auth_token,
-            &expected_signature,
-            &url,
-            body,
-            &Default::default()
-        ));
+        assert!(
+            validate_request_with_body(
+                auth_token,
+                &expected_signature,
+                &url,
+                body,
+                &Default::default()
+            )
+            .is_allowed()
+        );
 
         // Test failure cases
 
         // 1. Wrong auth token
-        assert!(!validate_request_with_body(
-            "wrong_auth_token",
-            &expected_signature,
-            &url,
-            body,
-            &Default::default()
-        ));
+        assert!(
+            !validate_request_with_body(
+                "wrong_auth_token",
+                &expected_signature,
+                &url,
+                body,
+                &Default::default()
+            )
+            .is_allowed()
+        );
 
         // 2. Tampered signature - completely different signature
         let tampered_signature = "XYZ_COMPLETELY_DIFFERENT_SIGNATURE_ABC=";
-        assert!(!validate_request_with_body(
-            auth_token,
-            tampered_signature,
-            &url,
-            body,
-            &Default::default()
-        ));
+        assert!(
+            !validate_request_with_body(
+                auth_token,
+                tampered_signature,
+                &url,
+                body,
+                &Default::default()
+            )
+            .is_allowed()
+        );
 
         // 3. Modified body
         let modified_body = body.replace("12349013030", "12345678901");
-        assert!(!validate_request_with_body(
-            auth_token,
-            &expected_signature,
-            &url,
-            &modified_body,
-            &Default::default()
-        ));
+        assert!(
+            !validate_request_with_body(
+                auth_token,
+                &expected_signature,
+                &url,
+                &modified_body,
+                &Default::default()
+            )
+            .is_allowed()
+        );
 
         // 4. Modified URL (but keeping the same hash)
         let modified_url = url.replace("example.com", "attacker.com");
-        assert!(!validate_request_with_body(
+        assert!(
+            !validate_request_with_body(
+                auth_token,
+                &expected_signature,
+                &modified_url,
+                body,
+                &Default::default()
+            )
+            .is_allowed()
+        );
+    }
+
+    #[test]
+    fn test_validation_outcome() {
+        let auth_token = TEST_AUTH_TOKEN;
+        let url = "https://example.com/myapp.php?foo=1&bar=2";
+        let params = BTreeMap::from([("CallSid".to_string(), "CA123".to_string())]);
+        let signature = get_expected_twilio_signature(auth_token, url, &params);
+        let options = RequestValidatorOptions::default();
+
+        // Matched after adding the default port
+        let outcome = validate_request(
             auth_token,
-            &expected_signature,
-            &modified_url,
-            body,
-            &Default::default()
-        ));
+            &signature,
+            "https://example.com:443/myapp.php?foo=1&bar=2",
+            &params,
+            &options,
+        );
+        assert!(outcome.is_valid() && outcome.is_allowed() && !outcome.is_bypassed());
+        assert_eq!(outcome.matched_url(), Some(url));
+        assert_eq!(outcome.failure(), None);
+
+        let outcome = validate_request(auth_token, "", url, &params, &options);
+        assert_eq!(
+            outcome,
+            ValidationOutcome::Invalid {
+                failure: ValidationFailure::MissingSignature,
+                enforced: true
+            }
+        );
+
+        let outcome = validate_request(auth_token, "bad", url, &params, &options);
+        let Some(ValidationFailure::SignatureMismatch { tried }) = outcome.failure() else {
+            panic!("{outcome:?}");
+        };
+        assert!(tried.iter().any(|tried| tried == url));
+        assert!(!outcome.is_allowed());
+
+        // Not enforced: invalid, but let through
+        let lenient = RequestValidatorOptions {
+            enforce: false,
+            ..Default::default()
+        };
+        let outcome = validate_request(auth_token, "bad", url, &params, &lenient);
+        assert!(!outcome.is_valid() && outcome.is_allowed() && outcome.is_bypassed());
+
+        let body = "{\"CallSid\":\"CA123\"}";
+        let url = format!(
+            "https://example.com/myapp?bodySHA256={}",
+            get_expected_body_hash(body)
+        );
+        let signature = get_expected_twilio_signature(auth_token, &url, &BTreeMap::new());
+        assert_eq!(
+            validate_request_with_body(auth_token, &signature, &url, "{}", &options).failure(),
+            Some(&ValidationFailure::BodyHashMismatch)
+        );
+        assert_eq!(
+            validate_request_with_body(auth_token, &signature, &url, body, &options).matched_url(),
+            Some(url.as_str())
+        );
+    }
+
+    #[test]
+    fn test_token_rotation() {
+        let old_token = TEST_AUTH_TOKEN;
+        let new_token = "0123456789abcdef0123456789abcdef";
+        let url = "https://example.com/myapp.php?foo=1&bar=2";
+        let params = BTreeMap::new();
+        let options = RequestValidatorOptions::default();
+        let old_signature = get_expected_twilio_signature(old_token, url, &params);
+        let new_signature = get_expected_twilio_signature(new_token, url, &params);
+
+        let tokens = RotatingAuthTokens::new([old_token]);
+        let outcome = validate_request(&tokens, &new_signature, url, &params, &options);
+        assert!(!outcome.is_allowed());
+
+        // Rotation: both are accepted, the outcome tells which one matched
+        tokens.set([new_token, old_token]);
+        let outcome = validate_request(&tokens, &new_signature, url, &params, &options);
+        assert_eq!(outcome.matched_token(), Some(0));
+        let outcome = validate_request(&tokens, &old_signature, url, &params, &options);
+        assert_eq!(outcome.matched_token(), Some(1));
+        assert_eq!(
+            validate_request(
+                &[new_token, old_token],
+                &old_signature,
+                url,
+                &params,
+                &options
+            ),
+            outcome
+        );
+        assert!(!format!("{tokens:?}").contains(old_token));
+
+        tokens.set(Vec::<String>::new());
+        assert_eq!(
+            validate_request(&tokens, &old_signature, url, &params, &options).failure(),
+            Some(&ValidationFailure::NoAuthTokens)
+        );
+        assert_eq!(token_hint(old_token), "f8b2");
+        assert_eq!(token_hint("ab"), "ab");
     }
 
     #[test]
@@ -918,11 +1096,7 @@ mod tests {
         };
 
         // Test success case
-        assert!(validate_incoming_request(
-            &request,
-            auth_token,
-            Default::default()
-        ));
+        assert!(validate_incoming_request(&request, auth_token, Default::default()).is_allowed());
 
         // Test failure cases
 
@@ -936,11 +1110,10 @@ mod tests {
             raw_body: None,
             method: Method::POST,
         };
-        assert!(!validate_incoming_request(
-            &request_no_sig,
-            auth_token,
-            Default::default()
-        ));
+        assert!(
+            !validate_incoming_request(&request_no_sig, auth_token, Default::default())
+                .is_allowed()
+        );
 
         // 2. Wrong host
         let request_wrong_host = MockRequest {
@@ -952,11 +1125,10 @@ mod tests {
             raw_body: None,
             method: Method::POST,
         };
-        assert!(!validate_incoming_request(
-            &request_wrong_host,
-            auth_token,
-            Default::default()
-        ));
+        assert!(
+            !validate_incoming_request(&request_wrong_host, auth_token, Default::default())
+                .is_allowed()
+        );
 
         // 3. Wrong protocol
         let request_wrong_protocol = MockRequest {
@@ -968,14 +1140,17 @@ mod tests {
             raw_body: None,
             method: Method::POST,
         };
-        assert!(!validate_incoming_request(
-            &request_wrong_protocol,
-            auth_token,
-            RequestValidatorOptions {
-                protocol: None,
-                ..Default::default()
-            }
-        ));
+        assert!(
+            !validate_incoming_request(
+                &request_wrong_protocol,
+                auth_token,
+                RequestValidatorOptions {
+                    protocol: None,
+                    ..Default::default()
+                }
+            )
+            .is_allowed()
+        );
 
         // 4. Wrong URL
         let request_wrong_url = MockRequest {
@@ -987,18 +1162,16 @@ mod tests {
             raw_body: None,
             method: Method::POST,
         };
-        assert!(!validate_incoming_request(
-            &request_wrong_url,
-            auth_token,
-            Default::default()
-        ));
+        assert!(
+            !validate_incoming_request(&request_wrong_url, auth_token, Default::default())
+                .is_allowed()
+        );
 
         // 5. Wrong auth token
-        assert!(!validate_incoming_request(
-            &request,
-            "wrong_auth_token",
-            Default::default()
-        ));
+        assert!(
+            !validate_incoming_request(&request, "wrong_auth_token", Default::default())
+                .is_allowed()
+        );
     }
 
     #[test]
@@ -1009,13 +1182,10 @@ mod tests {
         // URL with special chars that might be encoded differently
         let url = "https://example.com/path?q=a+b&r=c%20d&s=e%2Bf";
         let signature = get_expected_twilio_signature(auth_token, url, &params);
-        assert!(validate_request(
-            auth_token,
-            &signature,
-            url,
-            &params,
-            &Default::default()
-        ));
+        assert!(
+            validate_request(auth_token, &signature, url, &params, &Default::default())
+                .is_allowed()
+        );
     }
 
     #[test]
@@ -1038,7 +1208,8 @@ mod tests {
                 &url_with_auth,
                 &params,
                 &Default::default()
-            ),
+            )
+            .is_allowed(),
             "Twilio always strips auth from the URL"
         );
 
@@ -1054,7 +1225,8 @@ mod tests {
                 &url_without_auth,
                 &params,
                 &Default::default()
-            ),
+            )
+            .is_allowed(),
             "our algorithm should strip auth as well"
         );
     }
@@ -1078,33 +1250,36 @@ mod tests {
         let params = BTreeMap::new();
 
         // Invalid URL should return false
-        assert!(!validate_request(
-            auth_token,
-            "",
-            "not-a-url",
-            &params,
-            &Default::default()
-        ));
+        assert!(
+            !validate_request(auth_token, "", "not-a-url", &params, &Default::default())
+                .is_allowed()
+        );
 
         // Extremely long auth token
         let long_token = "a".repeat(1000);
-        assert!(!validate_request(
-            &long_token,
-            "",
-            "https://example.com",
-            &params,
-            &Default::default()
-        ));
+        assert!(
+            !validate_request(
+                &long_token,
+                "",
+                "https://example.com",
+                &params,
+                &Default::default()
+            )
+            .is_allowed()
+        );
 
         // Null bytes in signature (security test)
         let null_byte_sig = "valid\0signature";
-        assert!(!validate_request(
-            auth_token,
-            null_byte_sig,
-            "https://example.com",
-            &params,
-            &Default::default()
-        ));
+        assert!(
+            !validate_request(
+                auth_token,
+                null_byte_sig,
+                "https://example.com",
+                &params,
+                &Default::default()
+            )
+            .is_allowed()
+        );
     }
 
     #[test]
@@ -1139,34 +1314,18 @@ mod tests {
         );
 
         // All signatures should validate the original URL
-        assert!(validate_request(
-            auth_token,
-            &sig1,
-            url,
-            &params,
-            &Default::default()
-        ));
-        assert!(validate_request(
-            auth_token,
-            &sig2,
-            url,
-            &params,
-            &Default::default()
-        ));
-        assert!(validate_request(
-            auth_token,
-            &sig3,
-            url,
-            &params,
-            &Default::default()
-        ));
-        assert!(validate_request(
-            auth_token,
-            &sig4,
-            url,
-            &params,
-            &Default::default()
-        ));
+        assert!(
+            validate_request(auth_token, &sig1, url, &params, &Default::default()).is_allowed()
+        );
+        assert!(
+            validate_request(auth_token, &sig2, url, &params, &Default::default()).is_allowed()
+        );
+        assert!(
+            validate_request(auth_token, &sig3, url, &params, &Default::default()).is_allowed()
+        );
+        assert!(
+            validate_request(auth_token, &sig4, url, &params, &Default::default()).is_allowed()
+        );
     }
 
     #[test]
@@ -1181,13 +1340,10 @@ mod tests {
 
         let url = "https://example.com";
         let signature = get_expected_twilio_signature(auth_token, url, &params);
-        assert!(validate_request(
-            auth_token,
-            &signature,
-            url,
-            &params,
-            &Default::default()
-        ));
+        assert!(
+            validate_request(auth_token, &signature, url, &params, &Default::default())
+                .is_allowed()
+        );
     }
 
     #[test]
@@ -1204,13 +1360,10 @@ mod tests {
         params.insert("键".to_string(), "值".to_string());
 
         let signature = get_expected_twilio_signature(auth_token, url, &params);
-        assert!(validate_request(
-            auth_token,
-            &signature,
-            url,
-            &params,
-            &Default::default()
-        ));
+        assert!(
+            validate_request(auth_token, &signature, url, &params, &Default::default())
+                .is_allowed()
+        );
     }
 
     #[test]
@@ -1221,13 +1374,10 @@ mod tests {
 
         let url = "https://example.com/unicode";
         let signature = get_expected_twilio_signature(auth_token, url, &params);
-        assert!(validate_request(
-            auth_token,
-            &signature,
-            url,
-            &params,
-            &Default::default()
-        ));
+        assert!(
+            validate_request(auth_token, &signature, url, &params, &Default::default())
+                .is_allowed()
+        );
     }
 
     #[test]
@@ -1252,11 +1402,7 @@ mod tests {
         };
 
         // Test success case
-        assert!(validate_incoming_request(
-            &request,
-            auth_token,
-            Default::default()
-        ));
+        assert!(validate_incoming_request(&request, auth_token, Default::default()).is_allowed());
 
         // Test failure cases
 
@@ -1270,11 +1416,10 @@ mod tests {
             raw_body: None, // No body
             method: Method::POST,
         };
-        assert!(!validate_incoming_request(
-            &request_no_body,
-            auth_token,
-            Default::default()
-        ));
+        assert!(
+            !validate_incoming_request(&request_no_body, auth_token, Default::default())
+                .is_allowed()
+        );
 
         // 2. Modified body
         let modified_body = body.replace("12349013030", "12345678901");
@@ -1287,11 +1432,10 @@ mod tests {
             raw_body: Some(modified_body), // Modified body
             method: Method::POST,
         };
-        assert!(!validate_incoming_request(
-            &request_mod_body,
-            auth_token,
-            Default::default()
-        ));
+        assert!(
+            !validate_incoming_request(&request_mod_body, auth_token, Default::default())
+                .is_allowed()
+        );
 
         // 3. Wrong signature - use a completely different signature instead of trying to modify
         let wrong_signature = "XYZ_COMPLETELY_DIFFERENT_SIGNATURE_ABC=";
@@ -1309,11 +1453,10 @@ mod tests {
             method: Method::POST,
         };
 
-        assert!(!validate_incoming_request(
-            &request_wrong_sig,
-            auth_token,
-            Default::default()
-        ));
+        assert!(
+            !validate_incoming_request(&request_wrong_sig, auth_token, Default::default())
+                .is_allowed()
+        );
     }
 
     #[test]
@@ -1356,11 +1499,7 @@ mod tests {
             test_both_protocols: false,
             ..Default::default()
         };
-        assert!(validate_incoming_request(
-            &request_with_sig,
-            auth_token,
-            url_option
-        ));
+        assert!(validate_incoming_request(&request_with_sig, auth_token, url_option).is_allowed());
 
         // 2. Validate with host/port options
         let host_port_option = RequestValidatorOptions {
@@ -1370,20 +1509,17 @@ mod tests {
             test_both_protocols: false,
             ..Default::default()
         };
-        assert!(validate_incoming_request(
-            &request_with_sig,
-            auth_token,
-            host_port_option
-        ));
+        assert!(
+            validate_incoming_request(&request_with_sig, auth_token, host_port_option).is_allowed()
+        );
 
         // Test failure cases
 
         // 1. No options provided (falls back to parsing request URL)
-        assert!(!validate_incoming_request(
-            &request_with_sig,
-            auth_token,
-            Default::default()
-        ));
+        assert!(
+            !validate_incoming_request(&request_with_sig, auth_token, Default::default())
+                .is_allowed()
+        );
 
         // 2. Wrong URL in options
         let wrong_options = RequestValidatorOptions {
@@ -1393,11 +1529,9 @@ mod tests {
             test_both_protocols: false,
             ..Default::default()
         };
-        assert!(!validate_incoming_request(
-            &request_with_sig,
-            auth_token,
-            wrong_options
-        ));
+        assert!(
+            !validate_incoming_request(&request_with_sig, auth_token, wrong_options).is_allowed()
+        );
     }
 
     #[test]
@@ -1480,37 +1614,49 @@ mod tests {
 
         // Signatures should be different for different URL formats
         // But validation should still pass due to our multi-form checking
-        assert!(validate_request(
-            auth_token,
-            &sig_without_port,
-            url_without_port,
-            &params,
-            &Default::default(),
-        ));
-        assert!(validate_request(
-            auth_token,
-            &sig_with_port,
-            url_with_port,
-            &params,
-            &Default::default(),
-        ));
+        assert!(
+            validate_request(
+                auth_token,
+                &sig_without_port,
+                url_without_port,
+                &params,
+                &Default::default(),
+            )
+            .is_allowed()
+        );
+        assert!(
+            validate_request(
+                auth_token,
+                &sig_with_port,
+                url_with_port,
+                &params,
+                &Default::default(),
+            )
+            .is_allowed()
+        );
 
         // Cross-validation tests - validation should pass with various combinations
         // due to the implementation trying different URL formats
-        assert!(validate_request(
-            auth_token,
-            &sig_with_port,
-            url_without_port,
-            &params,
-            &Default::default(),
-        ));
-        assert!(validate_request(
-            auth_token,
-            &sig_without_port,
-            url_with_port,
-            &params,
-            &Default::default(),
-        ));
+        assert!(
+            validate_request(
+                auth_token,
+                &sig_with_port,
+                url_without_port,
+                &params,
+                &Default::default(),
+            )
+            .is_allowed()
+        );
+        assert!(
+            validate_request(
+                auth_token,
+                &sig_without_port,
+                url_with_port,
+                &params,
+                &Default::default(),
+            )
+            .is_allowed()
+        );
     }
 
     #[test]
@@ -1534,36 +1680,48 @@ mod tests {
         // Generate signature using standard URL format
         let signature = get_expected_twilio_signature(auth_token, url_standard, &params);
 
-        assert!(validate_request(
-            auth_token,
-            &signature,
-            url_standard,
-            &params,
-            &Default::default(),
-        ));
-        assert!(validate_request(
-            auth_token,
-            &signature,
-            url_encoded,
-            &params,
-            &Default::default(),
-        ));
+        assert!(
+            validate_request(
+                auth_token,
+                &signature,
+                url_standard,
+                &params,
+                &Default::default(),
+            )
+            .is_allowed()
+        );
+        assert!(
+            validate_request(
+                auth_token,
+                &signature,
+                url_encoded,
+                &params,
+                &Default::default(),
+            )
+            .is_allowed()
+        );
 
         // And signature generated with the encoded URL should also validate against both URLs
         let signature_encoded = get_expected_twilio_signature(auth_token, url_encoded, &params);
-        assert!(validate_request(
-            auth_token,
-            &signature_encoded,
-            url_standard,
-            &params,
-            &Default::default(),
-        ));
-        assert!(validate_request(
-            auth_token,
-            &signature_encoded,
-            url_encoded,
-            &params,
-            &Default::default(),
-        ));
+        assert!(
+            validate_request(
+                auth_token,
+                &signature_encoded,
+                url_standard,
+                &params,
+                &Default::default(),
+            )
+            .is_allowed()
+        );
+        assert!(
+            validate_request(
+                auth_token,
+                &signature_encoded,
+                url_encoded,
+                &params,
+                &Default::default(),
+            )
+            .is_allowed()
+        );
     }
 }
@@ -0,0 +1,86 @@
+/// Why a request failed validation
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ValidationFailure {
+    /// No `X-Twilio-Signature` header, or an empty one
+    #[error("missing signature")]
+    MissingSignature,
+    /// The [`AuthTokens`](super::AuthTokens) provider returned no tokens
+    #[error("no auth tokens")]
+    NoAuthTokens,
+    /// The signature matched none of the webhook URL variants
+    #[error("signature mismatch, tried {} URL variants", tried.len())]
+    SignatureMismatch { tried: Vec<String> },
+    /// The signature matched, but the JSON body doesn't match its `bodySHA256` hash
+    #[error("body hash mismatch")]
+    BodyHashMismatch,
+}
+
+/// Result of validating a request from Twilio.
+///
+/// [`ValidationOutcome::is_allowed`] is what the validators used to return: valid, or
+/// invalid with [`RequestValidatorOptions::enforce`](super::RequestValidatorOptions) off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationOutcome {
+    /// The signature matched `url`, one of the variants of the webhook URL tried, with the
+    /// auth token at index `token` of [`AuthTokens::tokens`](super::AuthTokens::tokens)
+    Valid { url: String, token: usize },
+    /// `enforced` is false when the request is let through anyway
+    Invalid {
+        failure: ValidationFailure,
+        enforced: bool,
+    },
+}
+
+impl ValidationOutcome {
+    pub(crate) fn invalid(
+        failure: ValidationFailure,
+        options: &super::RequestValidatorOptions,
+    ) -> Self {
+        ValidationOutcome::Invalid {
+            failure,
+            enforced: options.enforce,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        matches!(self, ValidationOutcome::Valid { .. })
+    }
+
+    /// Valid, or invalid but not enforced
+    pub fn is_allowed(&self) -> bool {
+        !matches!(self, ValidationOutcome::Invalid { enforced: true, .. })
+    }
+
+    /// Invalid, but let through because enforcement is off
+    pub fn is_bypassed(&self) -> bool {
+        matches!(
+            self,
+            ValidationOutcome::Invalid {
+                enforced: false,
+                ..
+            }
+        )
+    }
+
+    pub fn matched_url(&self) -> Option<&str> {
+        match self {
+            ValidationOutcome::Valid { url, .. } => Some(url),
+            ValidationOutcome::Invalid { .. } => None,
+        }
+    }
+
+    /// Index of the matching token, 0 for the primary
+    pub fn matched_token(&self) -> Option<usize> {
+        match self {
+            ValidationOutcome::Valid { token, .. } => Some(*token),
+            ValidationOutcome::Invalid { .. } => None,
+        }
+    }
+
+    pub fn failure(&self) -> Option<&ValidationFailure> {
+        match self {
+            ValidationOutcome::Valid { .. } => None,
+            ValidationOutcome::Invalid { failure, .. } => Some(failure),
+        }
+    }
+}
@@ -0,0 +1,344 @@
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use http::HeaderMap;
+
+use crate::errors::TwilioError;
+
+/// An IP network like `10.0.0.0/8` or `fd00::/8`; a bare address is a single host
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IpCidr {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl IpCidr {
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.addr, ip.to_canonical()) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                mask(u32::from(net).into(), u32::from(ip).into(), self.prefix, 32)
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => mask(net.into(), ip.into(), self.prefix, 128),
+            _ => false,
+        }
+    }
+}
+
+fn mask(net: u128, ip: u128, prefix: u8, bits: u8) -> bool {
+    let shift = bits - prefix;
+    shift == bits || (net >> shift) == (ip >> shift)
+}
+
+impl FromStr for IpCidr {
+    type Err = TwilioError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || TwilioError::Cidr(s.to_string());
+        let (addr, prefix) = match s.split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix)),
+            None => (s, None),
+        };
+        let addr = IpAddr::from_str(addr.trim())
+            .map_err(|_| invalid())?
+            .to_canonical();
+        let bits = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(prefix) => prefix.trim().parse().map_err(|_| invalid())?,
+            None => bits,
+        };
+        if prefix > bits {
+            return Err(invalid());
+        }
+        Ok(IpCidr { addr, prefix })
+    }
+}
+
+impl std::fmt::Display for IpCidr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix)
+    }
+}
+
+/// Scheme and host of a request before it went through proxies
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ForwardedOrigin {
+    pub proto: Option<String>,
+    /// With the port, if one was forwarded
+    pub host: Option<String>,
+}
+
+/// Load balancers and proxies whose forwarding headers are trusted: RFC 7239 `Forwarded`,
+/// or `X-Forwarded-Proto`, `X-Forwarded-Host` and `X-Forwarded-Port`.
+///
+/// Headers are only read when the request comes from a trusted proxy, and values a client
+/// sent itself are skipped: the values added by the outermost trusted proxy are used, found
+/// with the `for=` addresses of `Forwarded` or with `X-Forwarded-For`.
+///
+/// ```
+/// use twilio_voice::twilio::TrustedProxies;
+///
+/// let proxies = TrustedProxies::parse(["10.0.0.0/8", "fd00::/8"]).unwrap();
+/// let mut headers = http::HeaderMap::new();
+/// headers.insert("x-forwarded-proto", "https".parse().unwrap());
+/// headers.insert("x-forwarded-host", "voice.example.com".parse().unwrap());
+///
+/// let origin = proxies.origin("10.1.2.3".parse().unwrap(), &headers).unwrap();
+/// assert_eq!(origin.host.as_deref(), Some("voice.example.com"));
+/// assert_eq!(proxies.origin("203.0.113.7".parse().unwrap(), &headers), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrustedProxies(Vec<IpCidr>);
+
+impl TrustedProxies {
+    pub fn new(cidrs: impl IntoIterator<Item = IpCidr>) -> Self {
+        TrustedProxies(cidrs.into_iter().collect())
+    }
+
+    pub fn parse(cidrs: impl IntoIterator<Item = impl AsRef<str>>) -> crate::errors::Result<Self> {
+        cidrs
+            .into_iter()
+            .map(|cidr| cidr.as_ref().parse())
+            .collect::<Result<_, _>>()
+            .map(TrustedProxies)
+    }
+
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        self.0.iter().any(|cidr| cidr.contains(ip))
+    }
+
+    /// The original scheme and host of a request received from `peer`, or `None` if `peer`
+    /// isn't trusted or didn't forward them
+    pub fn origin(&self, peer: IpAddr, headers: &HeaderMap) -> Option<ForwardedOrigin> {
+        if !self.contains(peer) {
+            return None;
+        }
+        let origin = if headers.contains_key(http::header::FORWARDED) {
+            self.forwarded(headers)
+        } else {
+            self.x_forwarded(headers)
+        };
+        (origin != ForwardedOrigin::default()).then_some(origin)
+    }
+
+    /// Index of the hop whose values to use in a list where each proxy appended one, given
+    /// the address each proxy received from
+    fn hop(&self, senders: &[Option<IpAddr>]) -> usize {
+        let mut hop = senders.len().saturating_sub(1);
+        while hop > 0 && senders[hop].is_some_and(|ip| self.contains(ip)) {
+            hop -= 1;
+        }
+        hop
+    }
+
+    fn forwarded(&self, headers: &HeaderMap) -> ForwardedOrigin {
+        let elements = header_list(headers, http::header::FORWARDED.as_str())
+            .iter()
+            .map(|element| {
+                element
+                    .split(';')
+                    .filter_map(|pair| pair.split_once('='))
+                    .map(|(key, value)| {
+                        (
+                            key.trim().to_ascii_lowercase(),
+                            value.trim().trim_matches('"').to_string(),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let value = |element: &[(String, String)], key: &str| {
+            element
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, value)| value.clone())
+        };
+        let senders = elements
+            .iter()
+            .map(|element| value(element, "for").and_then(|ip| parse_node(&ip)))
+            .collect::<Vec<_>>();
+        let Some(element) = elements.get(self.hop(&senders)) else {
+            return ForwardedOrigin::default();
+        };
+        ForwardedOrigin {
+            proto: value(element, "proto"),
+            host: value(element, "host"),
+        }
+    }
+
+    /// Proxies set these or append to them along with `X-Forwarded-For`, so the lists are
+    /// aligned from the right. Without `X-Forwarded-For`, the last value comes from the nearest
+    /// proxy, which is trusted.
+    fn x_forwarded(&self, headers: &HeaderMap) -> ForwardedOrigin {
+        let senders = header_list(headers, "x-forwarded-for")
+            .iter()
+            .map(|node| parse_node(node))
+            .collect::<Vec<_>>();
+        // Values appended after the outermost trusted proxy's
+        let after = senders.len().saturating_sub(self.hop(&senders) + 1);
+        let value = |name: &str| {
+            let mut values = header_list(headers, name);
+            let index = values.len().checked_sub(1)?.saturating_sub(after);
+            Some(values.swap_remove(index))
+        };
+        let host = value("x-forwarded-host").map(|host| match value("x-forwarded-port") {
+            Some(port) if !host.contains(':') || host.ends_with(']') => format!("{host}:{port}"),
+            _ => host,
+        });
+        ForwardedOrigin {
+            proto: value("x-forwarded-proto"),
+            host,
+        }
+    }
+}
+
+/// Comma separated values of every `name` header
+fn header_list(headers: &HeaderMap, name: &str) -> Vec<String> {
+    headers
+        .get_all(name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+/// The address in a `Forwarded` `for=` or `X-Forwarded-For`: `1.2.3.4`, `1.2.3.4:80`, `[::1]` or `[::1]:80`
+fn parse_node(node: &str) -> Option<IpAddr> {
+    let node = node.trim();
+    if let Some(rest) = node.strip_prefix('[') {
+        return rest.split(']').next()?.parse().ok();
+    }
+    node.parse()
+        .ok()
+        .or_else(|| node.rsplit_once(':')?.0.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_cidr() {
+        let cidr: IpCidr = "10.0.0.0/8".parse().unwrap();
+        assert!(cidr.contains(ip("10.255.0.1")));
+        assert!(cidr.contains(ip("::ffff:10.0.0.1")));
+        assert!(!cidr.contains(ip("11.0.0.1")));
+        assert!(!cidr.contains(ip("::1")));
+        assert!(
+            "0.0.0.0/0"
+                .parse::<IpCidr>()
+                .unwrap()
+                .contains(ip("8.8.8.8"))
+        );
+        assert!(
+            "fd00::/8"
+                .parse::<IpCidr>()
+                .unwrap()
+                .contains(ip("fd12::1"))
+        );
+        assert_eq!(
+            "127.0.0.1".parse::<IpCidr>().unwrap().to_string(),
+            "127.0.0.1/32"
+        );
+        assert!("10.0.0.0/33".parse::<IpCidr>().is_err());
+        assert!("example.com".parse::<IpCidr>().is_err());
+    }
+
+    #[test]
+    fn test_x_forwarded() {
+        let proxies = TrustedProxies::parse(["10.0.0.0/8"]).unwrap();
+        let forwarded = headers(&[
+            ("x-forwarded-proto", "https"),
+            ("x-forwarded-host", "voice.example.com"),
+            ("x-forwarded-port", "8443"),
+        ]);
+        assert_eq!(
+            proxies.origin(ip("10.0.0.1"), &forwarded),
+            Some(ForwardedOrigin {
+                proto: Some("https".to_string()),
+                host: Some("voice.example.com:8443".to_string()),
+            })
+        );
+        assert_eq!(proxies.origin(ip("192.0.2.1"), &forwarded), None);
+        assert_eq!(proxies.origin(ip("10.0.0.1"), &HeaderMap::new()), None);
+
+        // The client sent its own header, the edge proxy appended the real values
+        let spoofed = headers(&[
+            ("x-forwarded-host", "evil.example.com, voice.example.com"),
+            ("x-forwarded-proto", "http, https"),
+        ]);
+        let origin = proxies.origin(ip("10.0.0.1"), &spoofed).unwrap();
+        assert_eq!(origin.host.as_deref(), Some("voice.example.com"));
+        assert_eq!(origin.proto.as_deref(), Some("https"));
+
+        // A CDN in front of a load balancer, both trusted, each appending their values
+        let proxies = TrustedProxies::parse(["10.0.0.0/8", "198.51.100.0/24"]).unwrap();
+        let chained = headers(&[
+            ("x-forwarded-for", "203.0.113.9, 198.51.100.7"),
+            ("x-forwarded-host", "voice.example.com, internal.lb"),
+            ("x-forwarded-proto", "https, http"),
+            ("x-forwarded-port", "443, 8080"),
+        ]);
+        assert_eq!(
+            proxies.origin(ip("10.0.0.2"), &chained),
+            Some(ForwardedOrigin {
+                proto: Some("https".to_string()),
+                host: Some("voice.example.com:443".to_string()),
+            })
+        );
+        // The load balancer overwrites X-Forwarded-Proto instead of appending, so only its
+        // value is left
+        let overwritten = headers(&[
+            ("x-forwarded-for", "203.0.113.9, 198.51.100.7"),
+            (
+                "x-forwarded-host",
+                "evil.example.com, voice.example.com, internal.lb",
+            ),
+            ("x-forwarded-proto", "http"),
+        ]);
+        let origin = proxies.origin(ip("10.0.0.2"), &overwritten).unwrap();
+        assert_eq!(origin.host.as_deref(), Some("voice.example.com"));
+        assert_eq!(origin.proto.as_deref(), Some("http"));
+    }
+
+    #[test]
+    fn test_forwarded() {
+        let proxies = TrustedProxies::parse(["10.0.0.0/8", "fd00::/8"]).unwrap();
+        let forwarded = headers(&[(
+            "forwarded",
+            r#"proto=https;host=evil.example.com, for=203.0.113.9;proto=http;host=edge.example.com, for="[fd00::2]:4711";proto=https;host="internal""#,
+        )]);
+        assert_eq!(
+            proxies.origin(ip("fd00::1"), &forwarded),
+            Some(ForwardedOrigin {
+                proto: Some("http".to_string()),
+                host: Some("edge.example.com".to_string()),
+            })
+        );
+        // Forwarded takes precedence over X-Forwarded-*
+        let forwarded = headers(&[
+            (
+                "forwarded",
+                "for=192.0.2.60;proto=https;host=voice.example.com",
+            ),
+            ("x-forwarded-host", "other.example.com"),
+        ]);
+        let origin = proxies.origin(ip("10.0.0.1"), &forwarded).unwrap();
+        assert_eq!(origin.host.as_deref(), Some("voice.example.com"));
+        assert_eq!(parse_node("[::1]:80"), Some(ip("::1")));
+        assert_eq!(parse_node("unknown"), None);
+    }
+}
@@ -0,0 +1,261 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use futures::future::BoxFuture;
+
+/// Remembers the webhooks already handled, e.g. in memory or in Redis
+pub trait ReplayStore: Send + Sync {
+    /// Records `key` for `ttl` unless it's recorded and hasn't expired, in which case it
+    /// returns false. Must be atomic, like `SET key 1 NX PX ttl` in Redis, so that only one
+    /// of two concurrent requests gets through.
+    fn insert(&self, key: String, ttl: Duration) -> BoxFuture<'_, bool>;
+
+    /// Makes a recorded `key` expire `ttl` from now
+    fn extend(&self, key: &str, ttl: Duration) -> BoxFuture<'_, ()>;
+
+    /// Forgets `key`
+    fn remove(&self, key: &str) -> BoxFuture<'_, ()>;
+}
+
+#[derive(Debug, Default)]
+struct Expiries {
+    keys: HashMap<String, Instant>,
+    /// Soonest first, with stale entries for keys that were extended or removed
+    queue: BinaryHeap<Reverse<(Instant, String)>>,
+}
+
+/// In-memory [`ReplayStore`]
+#[derive(Debug, Default)]
+pub struct MemoryReplayStore {
+    expiries: Mutex<Expiries>,
+}
+
+impl MemoryReplayStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.unexpired().keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Locks the store after dropping the expired keys
+    fn unexpired(&self) -> MutexGuard<'_, Expiries> {
+        let now = Instant::now();
+        let mut expiries = self.expiries.lock().unwrap_or_else(|e| e.into_inner());
+        let Expiries { keys, queue } = &mut *expiries;
+        while let Some(Reverse((expiry, _))) = queue.peek()
+            && *expiry <= now
+        {
+            let Some(Reverse((_, key))) = queue.pop() else {
+                break;
+            };
+            if keys.get(&key).is_some_and(|expiry| *expiry <= now) {
+                keys.remove(&key);
+            }
+        }
+        expiries
+    }
+}
+
+impl ReplayStore for MemoryReplayStore {
+    fn insert(&self, key: String, ttl: Duration) -> BoxFuture<'_, bool> {
+        let expiry = Instant::now() + ttl;
+        let mut expiries = self.unexpired();
+        let new = !expiries.keys.contains_key(&key);
+        if new {
+            expiries.keys.insert(key.clone(), expiry);
+            expiries.queue.push(Reverse((expiry, key)));
+        }
+        Box::pin(std::future::ready(new))
+    }
+
+    fn extend(&self, key: &str, ttl: Duration) -> BoxFuture<'_, ()> {
+        let expiry = Instant::now() + ttl;
+        let mut expiries = self.unexpired();
+        if let Some(current) = expiries.keys.get_mut(key) {
+            *current = expiry;
+            expiries.queue.push(Reverse((expiry, key.to_string())));
+        }
+        Box::pin(std::future::ready(()))
+    }
+
+    fn remove(&self, key: &str) -> BoxFuture<'_, ()> {
+        self.unexpired().keys.remove(key);
+        Box::pin(std::future::ready(()))
+    }
+}
+
+/// What to do with a webhook that was already received
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReplayAction {
+    /// Respond `409 Conflict` without calling the handler
+    #[default]
+    Reject,
+    /// Call the handler with a [`Replayed`] request extension
+    Flag,
+}
+
+/// Request extension marking a webhook that was already received
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replayed {
+    pub key: String,
+}
+
+/// Detects captured webhooks being replayed, and Twilio retrying status callbacks that were
+/// already handled.
+///
+/// A webhook is identified by [`replay_key`], from its signature and the URL it was signed
+/// for, so the key can't be changed without invalidating the signature. Identical webhooks
+/// are replays too, e.g. a caller pressing the same digits twice for the same `<Gather>`
+/// action URL in a call.
+///
+/// A webhook is [claimed](Self::claim) while it's being handled, so a concurrent duplicate
+/// is a replay, and only [recorded](Self::record) once handled, so that Twilio's retries of
+/// failed ones get through.
+#[derive(Clone)]
+pub struct ReplayGuard {
+    store: Arc<dyn ReplayStore>,
+    ttl: Duration,
+    in_flight_ttl: Duration,
+    action: ReplayAction,
+}
+
+impl Default for ReplayGuard {
+    /// In memory, for a day, rejecting duplicates
+    fn default() -> Self {
+        Self::new(MemoryReplayStore::new())
+    }
+}
+
+impl ReplayGuard {
+    pub fn new(store: impl ReplayStore + 'static) -> Self {
+        Self {
+            store: Arc::new(store),
+            ttl: Duration::from_secs(24 * 60 * 60),
+            in_flight_ttl: Duration::from_secs(60),
+            action: ReplayAction::Reject,
+        }
+    }
+
+    /// How long webhooks are remembered
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// How long a webhook stays claimed if it's never [recorded](Self::record) or
+    /// [released](Self::release), e.g. because the handler was dropped. A minute by default.
+    pub fn in_flight_ttl(mut self, in_flight_ttl: Duration) -> Self {
+        self.in_flight_ttl = in_flight_ttl;
+        self
+    }
+
+    pub fn action(mut self, action: ReplayAction) -> Self {
+        self.action = action;
+        self
+    }
+
+    pub fn replay_action(&self) -> ReplayAction {
+        self.action
+    }
+
+    /// Claims the webhook identified by `key` while it's handled, `Some` if it was already
+    /// handled or is being handled
+    pub async fn claim(&self, key: &str) -> Option<Replayed> {
+        let claimed = self.store.insert(key.to_string(), self.in_flight_ttl).await;
+        (!claimed).then(|| Replayed {
+            key: key.to_string(),
+        })
+    }
+
+    /// Records the claimed webhook identified by `key` as handled
+    pub async fn record(&self, key: &str) {
+        self.store.extend(key, self.ttl).await;
+    }
+
+    /// Releases the claim on the webhook identified by `key`, which failed and may be retried
+    pub async fn release(&self, key: &str) {
+        self.store.remove(key).await;
+    }
+}
+
+impl std::fmt::Debug for ReplayGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReplayGuard")
+            .field("ttl", &self.ttl)
+            .field("in_flight_ttl", &self.in_flight_ttl)
+            .field("action", &self.action)
+            .finish_non_exhaustive()
+    }
+}
+
+/// What identifies a webhook: its `X-Twilio-Signature` and the URL it was signed for, see
+/// [`ValidationOutcome::matched_url`](super::ValidationOutcome::matched_url). The signature
+/// covers the parameters, in the query string or the body.
+pub fn replay_key(url: &str, signature: &str) -> String {
+    format!("{signature} {url}")
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+
+    use super::*;
+
+    #[test]
+    fn test_memory_store() {
+        let store = MemoryReplayStore::new();
+        let ttl = Duration::from_secs(60);
+        assert!(block_on(store.insert("a".to_string(), ttl)));
+        assert!(!block_on(store.insert("a".to_string(), ttl)));
+        assert!(block_on(store.insert("b".to_string(), ttl)));
+        assert_eq!(store.len(), 2);
+        block_on(store.remove("a"));
+        assert!(block_on(store.insert("a".to_string(), ttl)));
+
+        // Expired keys are dropped and can be seen again
+        let store = MemoryReplayStore::new();
+        assert!(block_on(store.insert("a".to_string(), Duration::ZERO)));
+        assert!(block_on(store.insert("a".to_string(), Duration::ZERO)));
+        assert!(block_on(store.insert("b".to_string(), Duration::ZERO)));
+        assert!(store.is_empty());
+
+        // Extending changes the expiry
+        assert!(block_on(store.insert("c".to_string(), ttl)));
+        block_on(store.extend("c", Duration::ZERO));
+        assert!(store.is_empty());
+        block_on(store.extend("d", ttl));
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_replay_guard() {
+        let guard = ReplayGuard::default().action(ReplayAction::Flag);
+        let key = replay_key("https://example.com/status", "sig=");
+        assert_eq!(key, "sig= https://example.com/status");
+
+        assert_eq!(block_on(guard.claim(&key)), None);
+        // Claimed while in flight
+        assert_eq!(
+            block_on(guard.claim(&key)),
+            Some(Replayed { key: key.clone() })
+        );
+        block_on(guard.release(&key));
+        assert_eq!(block_on(guard.claim(&key)), None);
+        block_on(guard.record(&key));
+        assert!(block_on(guard.claim(&key)).is_some());
+        assert_eq!(guard.replay_action(), ReplayAction::Flag);
+
+        // A claim that's never recorded or released runs out
+        let guard = ReplayGuard::default().in_flight_ttl(Duration::ZERO);
+        assert_eq!(block_on(guard.claim(&key)), None);
+        assert_eq!(block_on(guard.claim(&key)), None);
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+
+use http::{Method, header};
+use url::Url;
+
+use crate::errors::Result;
+use crate::twilio::Request;
+
+use super::{get_expected_body_hash, get_expected_twilio_signature};
+
+/// Signs webhook requests like Twilio does, to fire realistic requests at handlers in
+/// integration tests or from local tools.
+///
+/// ```
+/// use http::Method;
+/// use twilio_voice::twilio::{Request, TwilioRequestSigner, validate_request_with_body};
+///
+/// let signer = TwilioRequestSigner::new("aaf98aa0a69a870c2e5a0e774af3f8b2");
+/// let request = signer
+///     .request(Method::POST, "https://example.com/voice", &Request::default())
+///     .unwrap();
+/// assert!(request.headers().contains_key("x-twilio-signature"));
+///
+/// let request = signer
+///     .json("https://example.com/events", r#"{"CallSid":"CA123"}"#)
+///     .unwrap();
+/// let signature = request.headers()["x-twilio-signature"].to_str().unwrap();
+/// let outcome = validate_request_with_body(
+///     "aaf98aa0a69a870c2e5a0e774af3f8b2",
+///     signature,
+///     &request.uri().to_string(),
+///     request.body(),
+///     &Default::default(),
+/// );
+/// assert!(outcome.is_valid());
+/// ```
+#[derive(Clone)]
+pub struct TwilioRequestSigner {
+    auth_token: String,
+}
+
+impl TwilioRequestSigner {
+    pub fn new(auth_token: impl Into<String>) -> Self {
+        Self {
+            auth_token: auth_token.into(),
+        }
+    }
+
+    /// A webhook with `params`: in the query string for `GET`, form encoded in the body
+    /// otherwise
+    pub fn form(
+        &self,
+        method: Method,
+        url: &str,
+        params: &BTreeMap<String, String>,
+    ) -> Result<http::Request<String>> {
+        let mut url = Url::parse(url)?;
+        if method == Method::GET {
+            if !params.is_empty() {
+                url.query_pairs_mut().extend_pairs(params);
+            }
+            let signature =
+                get_expected_twilio_signature(&self.auth_token, url.as_str(), &BTreeMap::new());
+            return Ok(http::Request::builder()
+                .method(method)
+                .uri(url.as_str())
+                .header("X-Twilio-Signature", signature)
+                .body(String::new())?);
+        }
+
+        let signature = get_expected_twilio_signature(&self.auth_token, url.as_str(), params);
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(params)
+            .finish();
+        Ok(http::Request::builder()
+            .method(method)
+            .uri(url.as_str())
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .header("X-Twilio-Signature", signature)
+            .body(body)?)
+    }
+
+    /// A voice webhook with the parameters of `request`
+    pub fn request(
+        &self,
+        method: Method,
+        url: &str,
+        request: &Request,
+    ) -> Result<http::Request<String>> {
+        let params = serde_urlencoded::from_str(&serde_urlencoded::to_string(request)?)?;
+        self.form(method, url, &params)
+    }
+
+    /// A `POST` with a JSON body, signed through the `bodySHA256` query parameter
+    pub fn json(&self, url: &str, body: &str) -> Result<http::Request<String>> {
+        let mut url = Url::parse(url)?;
+        url.query_pairs_mut()
+            .append_pair("bodySHA256", &get_expected_body_hash(body));
+        let signature =
+            get_expected_twilio_signature(&self.auth_token, url.as_str(), &BTreeMap::new());
+        Ok(http::Request::builder()
+            .method(Method::POST)
+            .uri(url.as_str())
+            .header(header::CONTENT_TYPE, "application/json")
+            .header("X-Twilio-Signature", signature)
+            .body(body.to_string())?)
+    }
+}
+
+impl std::fmt::Debug for TwilioRequestSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TwilioRequestSigner")
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::twilio::{
+        CallStatus, ValidationFailure, validate_request, validate_request_with_body,
+    };
+
+    use super::*;
+
+    const TEST_AUTH_TOKEN: &str = "aaf98aa0a69a870c2e5a0e774af3f8b2";
+
+    fn signature(request: &http::Request<String>) -> &str {
+        request.headers()["x-twilio-signature"].to_str().unwrap()
+    }
+
+    #[test]
+    fn test_sign_form() {
+        let signer = TwilioRequestSigner::new(TEST_AUTH_TOKEN);
+        let call = Request {
+            call_sid: "CA123".to_string(),
+            from: "+15551230000".to_string(),
+            call_status: CallStatus::Ringing,
+            ..Default::default()
+        };
+        let request = signer
+            .request(Method::POST, "https://example.com/voice?x=1", &call)
+            .unwrap();
+        assert_eq!(
+            request.headers()[header::CONTENT_TYPE],
+            "application/x-www-form-urlencoded"
+        );
+        let params: BTreeMap<String, String> = serde_urlencoded::from_str(request.body()).unwrap();
+        assert_eq!(params["CallSid"], "CA123");
+        assert_eq!(
+            serde_urlencoded::from_str::<Request>(request.body()).unwrap(),
+            call
+        );
+        let outcome = validate_request(
+            TEST_AUTH_TOKEN,
+            signature(&request),
+            &request.uri().to_string(),
+            &params,
+            &Default::default(),
+        );
+        assert_eq!(outcome.matched_url(), Some("https://example.com/voice?x=1"));
+
+        let params = BTreeMap::from([("Digits".to_string(), "1 2#".to_string())]);
+        let request = signer
+            .form(Method::GET, "https://example.com/gather", &params)
+            .unwrap();
+        assert_eq!(request.uri(), "https://example.com/gather?Digits=1+2%23");
+        assert!(request.body().is_empty());
+        let outcome = validate_request(
+            TEST_AUTH_TOKEN,
+            signature(&request),
+            &request.uri().to_string(),
+            &BTreeMap::new(),
+            &Default::default(),
+        );
+        assert!(outcome.is_valid());
+        assert!(!format!("{signer:?}").contains(TEST_AUTH_TOKEN));
+    }
+
+    #[test]
+    fn test_sign_json() {
+        let signer = TwilioRequestSigner::new(TEST_AUTH_TOKEN);
+        let body = r#"{"CallSid":"CA123"}"#;
+        let request = signer.json("https://example.com/events?x=1", body).unwrap();
+        assert_eq!(request.method(), Method::POST);
+        assert_eq!(request.headers()[header::CONTENT_TYPE], "application/json");
+        assert_eq!(request.body(), body);
+        assert_eq!(
+            request.uri(),
+            "https://example.com/events?x=1&bodySHA256=484a2cc5aefecd5dd80287edd227e8e4667f74168eaa0907992ec30c14fe5eec"
+        );
+
+        let url = request.uri().to_string();
+        let outcome = validate_request_with_body(
+            TEST_AUTH_TOKEN,
+            signature(&request),
+            &url,
+            body,
+            &Default::default(),
+        );
+        assert_eq!(outcome.matched_url(), Some(url.as_str()));
+
+        let outcome = validate_request_with_body(
+            TEST_AUTH_TOKEN,
+            signature(&request),
+            &url,
+            r#"{"CallSid":"CA456"}"#,
+            &Default::default(),
+        );
+        assert_eq!(
+            outcome.failure(),
+            Some(&ValidationFailure::BodyHashMismatch)
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::{Arc, RwLock};
+
+/// The auth tokens a request may be signed with.
+///
+/// While a token is being rotated Twilio signs webhooks with either the primary or the
+/// secondary token, so both must be accepted. Implement this to read the tokens from a
+/// secret store at request time, or use [`RotatingAuthTokens`] and refresh it.
+pub trait AuthTokens: Send + Sync {
+    /// The active tokens, primary first
+    fn tokens(&self) -> Vec<String>;
+}
+
+impl AuthTokens for str {
+    fn tokens(&self) -> Vec<String> {
+        vec![self.to_string()]
+    }
+}
+
+impl AuthTokens for String {
+    fn tokens(&self) -> Vec<String> {
+        vec![self.clone()]
+    }
+}
+
+impl<S: AsRef<str> + Send + Sync> AuthTokens for [S] {
+    fn tokens(&self) -> Vec<String> {
+        self.iter()
+            .map(|token| token.as_ref().to_string())
+            .collect()
+    }
+}
+
+impl<S: AsRef<str> + Send + Sync, const N: usize> AuthTokens for [S; N] {
+    fn tokens(&self) -> Vec<String> {
+        self.as_slice().tokens()
+    }
+}
+
+impl<S: AsRef<str> + Send + Sync> AuthTokens for Vec<S> {
+    fn tokens(&self) -> Vec<String> {
+        self.as_slice().tokens()
+    }
+}
+
+impl<T: AuthTokens + ?Sized> AuthTokens for &T {
+    fn tokens(&self) -> Vec<String> {
+        (**self).tokens()
+    }
+}
+
+impl<T: AuthTokens + ?Sized> AuthTokens for Arc<T> {
+    fn tokens(&self) -> Vec<String> {
+        (**self).tokens()
+    }
+}
+
+/// Tokens that can be replaced at runtime, e.g. by a task polling a secret store.
+/// Clones share the tokens.
+///
+/// ```
+/// use twilio_voice::twilio::{AuthTokens, RotatingAuthTokens};
+///
+/// let tokens = RotatingAuthTokens::new(["primary"]);
+/// let shared = tokens.clone();
+/// // Rotation started: accept both until the old token is retired
+/// tokens.set(["secondary", "primary"]);
+/// assert_eq!(shared.tokens(), ["secondary", "primary"]);
+/// ```
+#[derive(Clone, Default)]
+pub struct RotatingAuthTokens(Arc<RwLock<Vec<String>>>);
+
+impl RotatingAuthTokens {
+    pub fn new(tokens: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let rotating = Self::default();
+        rotating.set(tokens);
+        rotating
+    }
+
+    /// Replaces the tokens, primary first
+    pub fn set(&self, tokens: impl IntoIterator<Item = impl Into<String>>) {
+        let tokens = tokens.into_iter().map(Into::into).collect();
+        *self
+            .0
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = tokens;
+    }
+}
+
+impl AuthTokens for RotatingAuthTokens {
+    fn tokens(&self) -> Vec<String> {
+        self.0
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+impl std::fmt::Debug for RotatingAuthTokens {
+    /// Only the number of tokens, never the tokens
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RotatingAuthTokens")
+            .field("tokens", &self.tokens().len())
+            .finish()
+    }
+}
+
+/// Last 4 characters of a token, for logs
+pub(crate) fn token_hint(token: &str) -> &str {
+    let start = token
+        .char_indices()
+        .rev()
+        .nth(3)
+        .map_or(0, |(start, _)| start);
+    &token[start..]
+}
@@ -1,5 +1,8 @@
 pub use crate::errors::{DigitsError, TwilioError};
 
+mod access_token;
+pub use access_token::*;
+
 mod auth;
 pub use auth::*;
 
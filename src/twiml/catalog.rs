@@ -0,0 +1,134 @@
+use super::voices::{Gender, Language, Provider, Voice, VoiceInfo, VoiceTier};
+
+/// Query over the voice catalog; unset criteria match every voice.
+///
+/// ```
+/// use twilio_voice::twiml::{Gender, Language, VoiceFilter, VoiceTier};
+///
+/// let voices = VoiceFilter::new()
+///     .language(Language::EnUs)
+///     .gender(Gender::Female)
+///     .tier(VoiceTier::Neural)
+///     .voices()
+///     .collect::<Vec<_>>();
+/// assert!(voices.iter().any(|info| info.id == "Polly.Joanna-Neural"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VoiceFilter {
+    pub language: Option<Language>,
+    pub gender: Option<Gender>,
+    pub provider: Option<Provider>,
+    pub tier: Option<VoiceTier>,
+}
+
+impl VoiceFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    pub fn gender(mut self, gender: Gender) -> Self {
+        self.gender = Some(gender);
+        self
+    }
+
+    pub fn provider(mut self, provider: Provider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    pub fn tier(mut self, tier: VoiceTier) -> Self {
+        self.tier = Some(tier);
+        self
+    }
+
+    pub fn matches(&self, info: &VoiceInfo) -> bool {
+        self.language
+            .is_none_or(|language| info.language == language)
+            && self.gender.is_none_or(|gender| info.gender == gender)
+            && self
+                .provider
+                .is_none_or(|provider| info.provider == provider)
+            && self.tier.is_none_or(|tier| info.tier == tier)
+    }
+
+    /// Matching voices, in catalog order.
+    pub fn voices(self) -> impl Iterator<Item = &'static VoiceInfo> {
+        Voice::all().filter(move |info| self.matches(info))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::twiml::VoicePrice;
+    use crate::twiml::voices::en_us;
+
+    use super::*;
+
+    #[test]
+    fn test_info() {
+        let voice: Voice = en_us::neural::polly::Female::JoannaNeural.into();
+        let info = voice.info().unwrap();
+        assert_eq!(info.id, "Polly.Joanna-Neural");
+        assert_eq!(info.id, voice.to_string());
+        assert_eq!(info.language, Language::EnUs);
+        assert_eq!(Some(info.gender), voice.gender());
+        assert_eq!(info.provider, Provider::Polly);
+        assert_eq!(info.tier, VoiceTier::Neural);
+        assert_eq!(info.price(), voice.price());
+        assert_eq!(Voice::Woman.info(), None);
+    }
+
+    #[test]
+    fn test_catalog_is_consistent() {
+        for info in Voice::all() {
+            assert_eq!(info.voice.to_string(), info.id);
+            assert_eq!(info.voice.info(), Some(info));
+            assert_eq!(info.voice.gender(), Some(info.gender));
+            assert_eq!(info.voice.price(), info.price());
+        }
+    }
+
+    #[test]
+    fn test_other_voice() {
+        let voice: Voice = "Polly.Nope-Generative".parse().unwrap();
+        assert_eq!(voice, Voice::Other("Polly.Nope-Generative".to_string()));
+        assert_eq!(voice.to_string(), "Polly.Nope-Generative");
+        assert_eq!(voice.price(), None);
+        assert_eq!(voice.gender(), None);
+        assert_eq!(voice.info(), None);
+        assert!(Voice::from_str_strict("Polly.Nope-Generative").is_err());
+
+        let known = Voice::from_str_strict("Polly.Joanna-Neural").unwrap();
+        assert_eq!(known, en_us::neural::polly::Female::JoannaNeural.into());
+        assert_eq!(Voice::from_str_strict("man").unwrap(), Voice::Man);
+    }
+
+    #[test]
+    fn test_filter() {
+        let filter = VoiceFilter::new()
+            .language(Language::EnUs)
+            .gender(Gender::Male)
+            .provider(Provider::Google);
+        let voices = filter.voices().collect::<Vec<_>>();
+        assert!(!voices.is_empty());
+        assert!(voices.iter().all(|info| filter.matches(info)));
+        assert!(
+            voices
+                .iter()
+                .any(|info| info.id == "Google.en-US-Neural2-D")
+        );
+        assert!(
+            voices
+                .iter()
+                .all(|info| info.language == Language::EnUs && info.gender == Gender::Male)
+        );
+
+        assert_eq!(VoiceFilter::new().voices().count(), Voice::all().count());
+        assert!(VoiceTier::Standard < VoiceTier::Generative);
+    }
+}
@@ -0,0 +1,339 @@
+use typed_builder::TypedBuilder;
+
+use super::Response;
+
+/// Options for [`Response::to_xml_pretty_with`].
+#[derive(Debug, Clone, PartialEq, Eq, TypedBuilder)]
+pub struct FormatOptions {
+    /// Spaces per nesting level
+    #[builder(default = 4)]
+    pub indent: usize,
+    /// Sort attributes by name instead of keeping declaration order
+    #[builder(default)]
+    pub sort_attributes: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl Response {
+    /// Indented XML, one verb per line, with the default [`FormatOptions`].
+    ///
+    /// `<Say>` content is kept verbatim so SSML isn't altered.
+    pub fn to_xml_pretty(&self) -> String {
+        self.to_xml_pretty_with(&FormatOptions::default())
+    }
+
+    /// Indented XML, one verb per line.
+    ///
+    /// `<Say>` content is kept verbatim so SSML isn't altered.
+    pub fn to_xml_pretty_with(&self, options: &FormatOptions) -> String {
+        let mut out = String::new();
+        for node in parse_nodes(&self.to_xml()) {
+            write_pretty(&mut out, &node, 0, options);
+        }
+        out
+    }
+
+    /// Compact XML with attributes sorted by name and whitespace outside `<Say>` normalized.
+    ///
+    /// Two responses that Twilio treats the same way produce the same canonical form, which
+    /// makes it suitable for snapshot tests and diffs.
+    pub fn to_xml_canonical(&self) -> String {
+        let mut out = String::new();
+        for node in parse_nodes(&self.to_xml()) {
+            write_canonical(&mut out, &node);
+        }
+        out
+    }
+}
+
+#[derive(Debug)]
+enum Node<'a> {
+    Element {
+        name: &'a str,
+        attributes: Vec<(&'a str, &'a str)>,
+        children: Vec<Node<'a>>,
+    },
+    /// Text outside `<Say>`, still escaped
+    Text(&'a str),
+    /// `<Say>` content, kept as-is
+    Ssml(&'a str),
+}
+
+/// Parses the output of [`Response::to_xml`] into nodes. `<Say>` content is opaque.
+fn parse_nodes(xml: &str) -> Vec<Node<'_>> {
+    let mut stack: Vec<Node> = vec![Node::Element {
+        name: "",
+        attributes: vec![],
+        children: vec![],
+    }];
+    let mut rest = xml;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push(&mut stack, Node::Text(rest));
+            break;
+        };
+        if start > 0 {
+            push(&mut stack, Node::Text(&rest[..start]));
+        }
+        rest = &rest[start..];
+
+        if rest.starts_with("</") {
+            let end = rest.find('>').map(|i| i + 1).unwrap_or(rest.len());
+            rest = &rest[end..];
+            if stack.len() > 1
+                && let Some(element) = stack.pop()
+            {
+                push(&mut stack, element);
+            }
+            continue;
+        }
+
+        let end = tag_end(rest);
+        let self_closing = rest[..end].ends_with("/>");
+        let inner = rest[1..end - if self_closing { 2 } else { 1 }].trim();
+        let (name, attributes) = parse_tag(inner);
+        rest = &rest[end..];
+
+        if self_closing {
+            push(
+                &mut stack,
+                Node::Element {
+                    name,
+                    attributes,
+                    children: vec![],
+                },
+            );
+        } else if name == "Say" {
+            let content_end = rest.find("</Say>").unwrap_or(rest.len());
+            let content = &rest[..content_end];
+            rest = rest.get(content_end + "</Say>".len()..).unwrap_or("");
+            let children = if content.is_empty() {
+                vec![]
+            } else {
+                vec![Node::Ssml(content)]
+            };
+            push(
+                &mut stack,
+                Node::Element {
+                    name,
+                    attributes,
+                    children,
+                },
+            );
+        } else {
+            stack.push(Node::Element {
+                name,
+                attributes,
+                children: vec![],
+            });
+        }
+    }
+
+    while stack.len() > 1 {
+        if let Some(element) = stack.pop() {
+            push(&mut stack, element);
+        }
+    }
+    match stack.pop() {
+        Some(Node::Element { children, .. }) => children,
+        _ => vec![],
+    }
+}
+
+/// Appends a node to the innermost open element.
+fn push<'a>(stack: &mut [Node<'a>], node: Node<'a>) {
+    if let Some(Node::Element { children, .. }) = stack.last_mut() {
+        children.push(node);
+    }
+}
+
+/// Length of the start tag at the beginning of `s`, ignoring `>` in quoted attribute values.
+fn tag_end(s: &str) -> usize {
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return i + 1,
+            _ => {}
+        }
+    }
+    s.len()
+}
+
+/// Splits `Name a="1" b="2"` into the name and its attributes, values still escaped.
+fn parse_tag(inner: &str) -> (&str, Vec<(&str, &str)>) {
+    let name_end = inner.find(char::is_whitespace).unwrap_or(inner.len());
+    let (name, mut rest) = inner.split_at(name_end);
+    let mut attributes = vec![];
+
+    while let Some(eq) = rest.find('=') {
+        let key = rest[..eq].trim();
+        let after = rest[eq + 1..].trim_start();
+        let Some(quote) = after.chars().next() else {
+            break;
+        };
+        let Some(close) = after[1..].find(quote) else {
+            break;
+        };
+        attributes.push((key, &after[1..1 + close]));
+        rest = &after[close + 2..];
+    }
+
+    (name, attributes)
+}
+
+fn write_start_tag(out: &mut String, name: &str, attributes: &[(&str, &str)], sort: bool) {
+    let mut attributes = attributes.to_vec();
+    if sort {
+        attributes.sort_by_key(|(key, _)| *key);
+    }
+    out.push('<');
+    out.push_str(name);
+    for (key, value) in attributes {
+        out.push_str(&format!(" {key}=\"{value}\""));
+    }
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn write_pretty(out: &mut String, node: &Node, depth: usize, options: &FormatOptions) {
+    let indent = " ".repeat(depth * options.indent);
+    match node {
+        Node::Element {
+            name,
+            attributes,
+            children,
+        } => {
+            out.push_str(&indent);
+            write_start_tag(out, name, attributes, options.sort_attributes);
+            match children.as_slice() {
+                [] => out.push_str(" />\n"),
+                [Node::Ssml(content)] => out.push_str(&format!(">{content}</{name}>\n")),
+                [Node::Text(text)] => {
+                    out.push_str(&format!(">{}</{name}>\n", normalize_whitespace(text)))
+                }
+                children => {
+                    out.push_str(">\n");
+                    for child in children {
+                        write_pretty(out, child, depth + 1, options);
+                    }
+                    out.push_str(&format!("{indent}</{name}>\n"));
+                }
+            }
+        }
+        Node::Text(text) => {
+            let text = normalize_whitespace(text);
+            if !text.is_empty() {
+                out.push_str(&format!("{indent}{text}\n"));
+            }
+        }
+        Node::Ssml(content) => out.push_str(content),
+    }
+}
+
+fn write_canonical(out: &mut String, node: &Node) {
+    match node {
+        Node::Element {
+            name,
+            attributes,
+            children,
+        } => {
+            write_start_tag(out, name, attributes, true);
+            if children.is_empty() {
+                out.push_str(" />");
+            } else {
+                out.push('>');
+                for child in children {
+                    write_canonical(out, child);
+                }
+                out.push_str(&format!("</{name}>"));
+            }
+        }
+        Node::Text(text) => out.push_str(&normalize_whitespace(text)),
+        Node::Ssml(content) => out.push_str(content),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    const IVR: &str = r#"<Response>
+        <Gather numDigits="1" action="/menu">
+            <Say voice="Polly.Joanna">Press <emphasis>1</emphasis>  for   sales &amp; 2 &lt; 3.</Say>
+            <Pause length="2"/>
+        </Gather>
+        <Play>
+            https://example.com/hold.mp3
+        </Play>
+        <Hangup/>
+    </Response>"#;
+
+    #[test]
+    fn test_pretty_keeps_ssml() {
+        let resp = Response::from_str(IVR).unwrap();
+        let options = FormatOptions::builder().indent(2).build();
+        assert_eq!(
+            resp.to_xml_pretty_with(&options),
+            r#"<Response>
+  <Gather action="/menu" actionOnEmptyResult="false" input="dtmf" language="en-US" method="POST" numDigits="1">
+    <Say voice="Polly.Joanna" loop="1">Press <emphasis>1</emphasis>  for   sales &amp; 2 &lt; 3.</Say>
+    <Pause length="2" />
+  </Gather>
+  <Play loop="1">https://example.com/hold.mp3</Play>
+  <Hangup />
+</Response>
+"#
+        );
+        assert_eq!(Response::from_str(&resp.to_xml_pretty()).unwrap(), resp);
+    }
+
+    #[test]
+    fn test_canonical() {
+        let resp = Response::from_str(IVR).unwrap();
+        assert_eq!(
+            resp.to_xml_canonical(),
+            concat!(
+                r#"<Response><Gather action="/menu" actionOnEmptyResult="false" input="dtmf" language="en-US" method="POST" numDigits="1">"#,
+                r#"<Say loop="1" voice="Polly.Joanna">Press <emphasis>1</emphasis>  for   sales &amp; 2 &lt; 3.</Say>"#,
+                r#"<Pause length="2" /></Gather><Play loop="1">https://example.com/hold.mp3</Play><Hangup /></Response>"#,
+            )
+        );
+
+        let reordered =
+            r#"<Response><Play>https://example.com/hold.mp3</Play><Hangup/></Response>"#;
+        let spaced = "<Response>\n  <Play loop=\"1\">\n    https://example.com/hold.mp3\n  </Play>\n  <Hangup />\n</Response>";
+        assert_eq!(
+            Response::from_str(reordered).unwrap().to_xml_canonical(),
+            Response::from_str(spaced).unwrap().to_xml_canonical()
+        );
+
+        let gather = |attributes: &str| {
+            let xml = format!("<Response><Gather {attributes}><Say>Hi</Say></Gather></Response>");
+            Response::from_str(&xml).unwrap().to_xml_canonical()
+        };
+        assert_eq!(
+            gather(r#"numDigits="1" action="/x""#),
+            gather(r#"action="/x" numDigits="1""#)
+        );
+        assert!(gather(r#"numDigits="1" action="/x""#).contains(r#"<Gather action="/x" "#));
+    }
+
+    #[test]
+    fn test_empty_response() {
+        let resp = Response::builder().build();
+        assert_eq!(resp.to_xml_pretty(), "<Response />\n");
+        assert_eq!(resp.to_xml_canonical(), "<Response />");
+    }
+}
@@ -108,7 +108,7 @@ pub struct Gather {
     /// After the caller finishes entering digits or reaches the timeout, Twilio sends a POST HTTP request to the specified URL. If you omit this attribute, Twilio calls the TwiML document making the request. This might lead to unwanted looping behavior.
     ///
     /// Documentation: https://www.twilio.com/docs/voice/twiml/gather#action
-    #[serde(rename = "@action")]
+    #[serde(default, rename = "@action")]
     #[builder(default)]
     pub action: String,
 
@@ -121,7 +121,7 @@ pub struct Gather {
     /// Default value: `false`
     ///
     /// Documentation: https://www.twilio.com/docs/voice/twiml/gather#actiononemptyresult
-    #[serde(rename = "@actionOnEmptyResult")]
+    #[serde(default, rename = "@actionOnEmptyResult")]
     #[builder(default)]
     pub action_on_empty_result: bool,
 
@@ -168,7 +168,7 @@ pub struct Gather {
     /// - When you set this attribute value to `dtmf speech`, Twilio gives precedence to the first input it detects. If Twilio detects `speech` first, it ignores the `finishOnKey` attribute.
     ///
     /// Documentation: https://www.twilio.com/docs/voice/twiml/gather#input
-    #[serde(rename = "@input")]
+    #[serde(default, rename = "@input")]
     #[builder(default)]
     pub input: GatherInput,
 
@@ -188,7 +188,7 @@ pub struct Gather {
     ///   - Google STT V1 models map to [any language Twilio supports](https://www.twilio.com/docs/voice/twiml/gather#languagetags "any language Twilio supports"). _Twilio has deprecated Google STT V1 as a language model._
     ///
     /// Documentation: https://www.twilio.com/docs/voice/twiml/gather#language
-    #[serde(rename = "@language")]
+    #[serde(default = "default_language", rename = "@language")]
     #[builder(default = Language::EnUs)]
     pub language: Language,
 
@@ -201,7 +201,7 @@ pub struct Gather {
     /// Default value: `POST`
     ///
     /// Documentation: https://www.twilio.com/docs/voice/twiml/gather#method
-    #[serde(rename = "@method")]
+    #[serde(default, rename = "@method")]
     #[builder(default)]
     pub method: Method,
 
@@ -329,21 +329,9 @@ pub struct Gather {
 }
 
 impl VoicePrice for Gather {
+    /// See [`CostReport`](crate::cost::CostReport) for how the price is estimated.
     fn price(&self) -> Option<PriceType> {
-        let says = self
-            .verbs
-            .iter()
-            .filter_map(|v| match v {
-                GatherVerb::Say(say) => Some(say),
-                _ => None,
-            })
-            .collect::<Vec<_>>();
-        let prices = says.iter().filter_map(|s| s.price()).collect::<Vec<_>>();
-        if prices.is_empty() || prices.len() != says.len() {
-            None
-        } else {
-            Some(prices.iter().sum())
-        }
+        crate::cost::CostReport::for_gather(self, &crate::cost::GeneratedPrices).total()
     }
 }
 
@@ -396,6 +384,10 @@ impl From<usize> for SpeechTimeout {
     }
 }
 
+fn default_language() -> Language {
+    Language::EnUs
+}
+
 fn deserialize_seconds<'de, D>(deserializer: D) -> Result<u8, D::Error>
 where
     D: Deserializer<'de>,
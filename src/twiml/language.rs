@@ -0,0 +1,256 @@
+use std::str::FromStr;
+
+use crate::TwilioError;
+
+use super::voices::Language;
+
+/// Deprecated or macro-language codes and the code Twilio's voices use instead
+const LANGUAGE_ALIASES: &[(&str, &str)] = &[
+    ("iw", "he"),
+    ("in", "id"),
+    ("no", "nb"),
+    ("nn", "nb"),
+    ("tl", "fil"),
+];
+
+/// Where to go for a language or tag that has no voices of its own, before trying any
+/// other region of the same language. Languages not listed fall back to the region of the
+/// same name, e.g. `fr` to `fr-FR`.
+const DEFAULT_TAGS: &[(&str, &str)] = &[
+    ("ar", "arb"),
+    ("cmn", "cmn-CN"),
+    ("en", "en-US"),
+    ("es-419", "es-MX"),
+    ("pt", "pt-BR"),
+    ("yue", "yue-HK"),
+];
+
+impl Language {
+    /// The compiled-in language for a BCP-47 tag, falling back to a related language.
+    ///
+    /// Tags are matched case-insensitively and `_` is accepted as a separator, so `en_us`
+    /// finds `en-US`. Without an exact match the fallback chain is:
+    ///
+    /// 1. the tag without its script, variant and extension subtags, e.g. `zh-Hant-TW`
+    ///    becomes `cmn-TW` and `de-DE-1996` becomes `de-DE`, with Chinese mapped to
+    ///    Mandarin (`cmn`) or Cantonese (`yue`) by region or script
+    /// 2. the language's default, e.g. `de-CH` → `de-DE`, `pt` → `pt-BR`, `ar` → `arb`
+    /// 3. any other region of the language, e.g. `pt-PT` when only the `pt-pt` feature is on
+    pub fn resolve(tag: &str) -> Option<Language> {
+        let candidates = fallback_chain(tag)?;
+        let primary = candidates.first()?.split('-').next()?.to_string();
+
+        candidates
+            .iter()
+            .find_map(|candidate| Self::exact(candidate))
+            .or_else(|| {
+                Self::ALL
+                    .iter()
+                    .find(|language| {
+                        language
+                            .to_string()
+                            .split('-')
+                            .next()
+                            .is_some_and(|p| p.eq_ignore_ascii_case(&primary))
+                    })
+                    .copied()
+            })
+    }
+
+    /// The compiled-in language with exactly this tag, ignoring case and accepting `_`.
+    pub fn exact(tag: &str) -> Option<Language> {
+        let tag = tag.trim().replace('_', "-");
+        Self::ALL
+            .iter()
+            .find(|language| language.to_string().eq_ignore_ascii_case(&tag))
+            .copied()
+    }
+
+    /// The best compiled-in language for an `Accept-Language` header value, e.g.
+    /// `de-CH, fr;q=0.9, en;q=0.8`.
+    ///
+    /// Ranges are tried from highest to lowest weight, each with its [`Language::resolve`]
+    /// fallbacks. `*` and ranges with `q=0` are ignored.
+    pub fn negotiate(accept_language: &str) -> Option<Language> {
+        let mut ranges = accept_language
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';');
+                let tag = parts.next()?.trim();
+                let weight = parts
+                    .filter_map(|param| param.trim().strip_prefix("q="))
+                    .find_map(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.);
+                (!tag.is_empty() && tag != "*" && weight > 0.).then_some((tag, weight))
+            })
+            .collect::<Vec<_>>();
+        ranges.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        ranges.into_iter().find_map(|(tag, _)| Self::resolve(tag))
+    }
+}
+
+impl FromStr for Language {
+    type Err = TwilioError;
+
+    /// Same as [`Language::resolve`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::resolve(s).ok_or_else(|| TwilioError::Language(s.to_string()))
+    }
+}
+
+/// Candidate tags for [`Language::resolve`], most specific first, or `None` if `tag` isn't a
+/// well-formed language tag.
+fn fallback_chain(tag: &str) -> Option<Vec<String>> {
+    let subtags = tag
+        .trim()
+        .split(['-', '_'])
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>();
+    if subtags
+        .iter()
+        .any(|s| s.is_empty() || !s.chars().all(|c| c.is_ascii_alphanumeric()))
+    {
+        return None;
+    }
+
+    let mut language = subtags.first()?.clone();
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    // Extensions and private use start at the first single-character subtag
+    let rest = subtags[1..]
+        .iter()
+        .take_while(|s| s.len() > 1)
+        .collect::<Vec<_>>();
+    let script = rest
+        .iter()
+        .find(|s| s.len() == 4 && s.chars().all(|c| c.is_ascii_alphabetic()));
+    let mut region = rest
+        .iter()
+        .find(|s| (s.len() == 2 && s.chars().all(|c| c.is_ascii_alphabetic())) || s.len() == 3)
+        .map(|s| s.to_string());
+    let variants = rest
+        .iter()
+        .skip_while(|s| Some(s.as_str()) != region.as_deref())
+        .skip(1)
+        .collect::<Vec<_>>();
+
+    if let Some((_, alias)) = LANGUAGE_ALIASES.iter().find(|(code, _)| *code == language) {
+        language = alias.to_string();
+    }
+    if language == "zh" {
+        language = match region.as_deref() {
+            Some("hk" | "mo") => "yue",
+            _ => "cmn",
+        }
+        .to_string();
+        if region.is_none() {
+            region = match script.map(|s| s.as_str()) {
+                Some("hant") => Some("tw".to_string()),
+                _ => Some("cn".to_string()),
+            };
+        }
+    }
+
+    let mut chain = Vec::new();
+    if let Some(region) = &region {
+        let base = format!("{language}-{region}");
+        for n in (1..=variants.len()).rev() {
+            let variants = variants[..n].iter().map(|s| s.as_str());
+            chain.push(
+                std::iter::once(base.as_str())
+                    .chain(variants)
+                    .collect::<Vec<_>>()
+                    .join("-"),
+            );
+        }
+        chain.push(base.clone());
+        if let Some((_, default)) = DEFAULT_TAGS.iter().find(|(tag, _)| *tag == base) {
+            chain.push(default.to_string());
+        }
+    } else {
+        chain.push(language.clone());
+    }
+    match DEFAULT_TAGS.iter().find(|(tag, _)| *tag == language) {
+        Some((_, default)) => chain.push(default.to_string()),
+        None => chain.push(format!("{language}-{language}")),
+    }
+    chain.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+
+    Some(chain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Language::from_str("en-US").unwrap(), Language::EnUs);
+        assert_eq!(Language::from_str("en_us").unwrap(), Language::EnUs);
+        assert_eq!(Language::from_str("EN-gb-wls").unwrap(), Language::EnGbWls);
+        assert_eq!(Language::from_str("en").unwrap(), Language::EnUs);
+        assert_eq!(Language::from_str("en-CA").unwrap(), Language::EnUs);
+        assert_eq!(
+            Language::from_str("en-AU-x-custom").unwrap(),
+            Language::EnAu
+        );
+        assert_eq!(Language::exact("en-CA"), None);
+        assert!(Language::from_str("english").is_err());
+        assert!(Language::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_fallback_chain() {
+        assert_eq!(
+            fallback_chain("de-CH").unwrap(),
+            ["de-ch", "de-de"].map(String::from)
+        );
+        assert_eq!(
+            fallback_chain("zh-TW").unwrap(),
+            ["cmn-tw", "cmn-CN"].map(String::from)
+        );
+        assert_eq!(
+            fallback_chain("zh-Hant").unwrap(),
+            ["cmn-tw", "cmn-CN"].map(String::from)
+        );
+        assert_eq!(
+            fallback_chain("zh-HK").unwrap(),
+            ["yue-hk"].map(String::from)
+        );
+        assert_eq!(
+            fallback_chain("pt").unwrap(),
+            ["pt", "pt-BR"].map(String::from)
+        );
+        assert_eq!(
+            fallback_chain("iw").unwrap(),
+            ["he", "he-he"].map(String::from)
+        );
+        assert_eq!(
+            fallback_chain("es-419").unwrap(),
+            ["es-419", "es-MX", "es-es"].map(String::from)
+        );
+    }
+
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(
+            Language::negotiate("sw-KE, en-GB;q=0.9, *;q=0.5"),
+            Some(Language::EnGb)
+        );
+        assert_eq!(
+            Language::negotiate("en-US;q=0.1, en-IN;q=0.8"),
+            Some(Language::EnIn)
+        );
+        assert_eq!(Language::negotiate("en;q=0"), None);
+        assert_eq!(Language::negotiate(""), None);
+    }
+
+    #[cfg(all(feature = "cmn-tw", feature = "de-de"))]
+    #[test]
+    fn test_resolve_other_languages() {
+        assert_eq!(Language::resolve("de-CH"), Some(Language::DeDe));
+        assert_eq!(Language::resolve("zh_TW"), Some(Language::CmnTw));
+    }
+}
@@ -4,17 +4,42 @@ pub use redirect::*;
 mod responses;
 pub use responses::*;
 
+mod catalog;
+pub use catalog::*;
+
+mod format;
+pub use format::*;
+
 mod gather;
 pub use gather::*;
 
+mod language;
+
 mod play;
 pub use play::*;
 
+mod readback;
+pub use readback::*;
+
+mod readout;
+pub use readout::*;
+
 mod say;
 pub use say::*;
 
+mod selector;
+pub use selector::*;
+
+mod source;
+pub use source::*;
+
+mod validation;
+pub use validation::*;
+
 pub mod voices;
-pub use voices::{Gender, Language, Voice, VoiceGender, VoicePrice};
+pub use voices::{
+    Gender, Language, Provider, Voice, VoiceGender, VoiceInfo, VoicePrice, VoiceTier,
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -26,3 +51,8 @@ pub enum Method {
     #[default]
     POST,
 }
+
+/// Twilio's default `loop` attribute for `<Say>` and `<Play>`.
+fn default_loop_count() -> u32 {
+    1
+}
@@ -7,7 +7,7 @@ use crate::Digits;
 /// https://www.twilio.com/docs/voice/twiml/play
 #[derive(Debug, Clone, TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Play {
-    #[serde(rename = "@loop")]
+    #[serde(default = "super::default_loop_count", rename = "@loop")]
     #[builder(default = 1)]
     pub loop_count: u32,
     #[serde(rename = "@digits")]
@@ -17,3 +17,15 @@ pub struct Play {
     #[serde(rename = "#text")]
     pub url: Option<String>,
 }
+
+impl From<&str> for Play {
+    fn from(url: &str) -> Self {
+        Play::builder().url(url.to_string()).build()
+    }
+}
+
+impl From<String> for Play {
+    fn from(url: String) -> Self {
+        Play::builder().url(url).build()
+    }
+}
@@ -0,0 +1,244 @@
+use std::time::Duration;
+
+use quick_xml::escape::partial_escape;
+use typed_builder::TypedBuilder;
+
+use crate::Digit;
+
+use super::{Language, ReadOut, Say, Voice};
+
+/// How a read-back splits the keys into groups
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Grouping {
+    /// Split only where the keys change between numbers and symbols, e.g. "4 1 2, pound"
+    #[default]
+    Ungrouped,
+    /// Groups of this many keys, e.g. 3 for "123, 456, 78"
+    By(usize),
+    /// Like a phone number, see [`ReadOut::phone`]
+    Phone,
+}
+
+impl Grouping {
+    /// Group sizes for the keys in `digits`, pauses not counted. Keys past the last group
+    /// form a final group.
+    pub(crate) fn sizes(self, digits: &[Digit]) -> Vec<usize> {
+        let keys = digits.iter().filter(|digit| !digit.is_pause()).count();
+        match self {
+            Grouping::Ungrouped => vec![],
+            Grouping::By(0) => vec![],
+            Grouping::By(n) => vec![n; keys / n],
+            Grouping::Phone => match (keys, digits.first()) {
+                (10, _) => vec![3, 3, 4],
+                (11, Some(Digit::One)) => vec![1, 3, 3, 4],
+                (n, _) if n % 3 == 1 && n > 4 => [vec![3; n / 3 - 1], vec![4]].concat(),
+                (n, _) => vec![3; n / 3],
+            },
+        }
+    }
+}
+
+/// Pauses and speaking rate of a read-back
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pacing {
+    /// Between keys in a group
+    pub key: Duration,
+    /// Between groups
+    pub group: Duration,
+    /// Speaking rate in percent of the voice's default, as `<prosody rate>`
+    pub rate: Option<u16>,
+}
+
+impl Pacing {
+    pub const NORMAL: Pacing = Pacing {
+        key: Duration::ZERO,
+        group: Duration::from_millis(400),
+        rate: None,
+    };
+
+    /// For callers checking a long number key by key
+    pub const SLOW: Pacing = Pacing {
+        key: Duration::from_millis(200),
+        group: Duration::from_millis(700),
+        rate: Some(90),
+    };
+}
+
+impl Default for Pacing {
+    fn default() -> Self {
+        Self::NORMAL
+    }
+}
+
+/// Reads back what a caller typed, e.g. "You entered four one two, pound".
+///
+/// Every key is spoken, including `*`, `#` and `A`–`D`, and `w`/`W` pauses become SSML
+/// `<break>`s. The result is a [`Say`] to put in the next [`Gather`](super::Gather) so the
+/// caller can confirm.
+///
+/// ```
+/// use twilio_voice::{Digits, twiml::{Gather, GatherBuilderVerbs, Grouping, ReadBack}};
+///
+/// let digits: Digits = "4125550100#".parse().unwrap();
+/// let read_back = ReadBack::builder()
+///     .prefix("You entered")
+///     .grouping(Grouping::Phone)
+///     .suffix("Press 1 if that's correct.")
+///     .build();
+/// let gather = Gather::builder()
+///     .num_digits(1)
+///     .say(read_back.say(&digits))
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, TypedBuilder)]
+pub struct ReadBack {
+    /// Language of the key names; English when unset
+    #[builder(default, setter(strip_option))]
+    pub language: Option<Language>,
+    #[builder(default, setter(strip_option))]
+    pub voice: Option<Voice>,
+    #[builder(default)]
+    pub grouping: Grouping,
+    #[builder(default)]
+    pub pacing: Pacing,
+    /// Text before the keys
+    #[builder(default, setter(strip_option, into))]
+    pub prefix: Option<String>,
+    /// Text after the keys
+    #[builder(default, setter(strip_option, into))]
+    pub suffix: Option<String>,
+}
+
+impl ReadBack {
+    /// The SSML for the keys, without prefix and suffix
+    pub fn ssml(&self, digits: &[Digit]) -> String {
+        let word = |digit: Digit| match self.language {
+            Some(language) => ReadOut::text(language).word(digit),
+            None => digit.word(),
+        };
+        let boundaries = self
+            .grouping
+            .sizes(digits)
+            .into_iter()
+            .scan(0, |end, size| {
+                *end += size;
+                Some(*end)
+            })
+            .collect::<Vec<_>>();
+
+        let mut parts = Vec::new();
+        let mut keys = 0;
+        let mut previous: Option<Digit> = None;
+        let mut paused = false;
+        for digit in digits {
+            match digit {
+                Digit::W => parts.push(break_tag(Duration::from_millis(500))),
+                Digit::WW => parts.push(break_tag(Duration::from_secs(1))),
+                digit => {
+                    // A typed pause replaces the pause between keys
+                    if let Some(previous) = previous.filter(|_| !paused) {
+                        let numeric = |digit: Digit| digit.to_int().is_some();
+                        if boundaries.contains(&keys) || numeric(previous) != numeric(*digit) {
+                            parts.push(break_tag(self.pacing.group));
+                        } else if !self.pacing.key.is_zero() {
+                            parts.push(break_tag(self.pacing.key));
+                        }
+                    }
+                    parts.push(partial_escape(word(*digit)).to_string());
+                    previous = Some(*digit);
+                    keys += 1;
+                }
+            }
+            paused = digit.is_pause();
+        }
+
+        let ssml = parts.join(" ");
+        match self.pacing.rate {
+            Some(rate) => format!(r#"<prosody rate="{rate}%">{ssml}</prosody>"#),
+            None => ssml,
+        }
+    }
+
+    /// A `<Say>` with the prefix, the keys and the suffix
+    pub fn say(&self, digits: &[Digit]) -> Say {
+        let ssml = [
+            self.prefix
+                .as_deref()
+                .map(|prefix| partial_escape(prefix).to_string()),
+            Some(self.ssml(digits)),
+            self.suffix
+                .as_deref()
+                .map(|suffix| partial_escape(suffix).to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+        Say {
+            language: self.language,
+            voice: self.voice.clone(),
+            loop_count: 1,
+            ssml: ssml.into(),
+        }
+    }
+}
+
+fn break_tag(duration: Duration) -> String {
+    format!(r#"<break time="{}ms"/>"#, duration.as_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::Digits;
+
+    use super::*;
+
+    fn digits(s: &str) -> Digits {
+        Digits::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_read_back() {
+        let read_back = ReadBack::builder().prefix("You entered").build();
+        let say = read_back.say(&digits("412#"));
+        assert_eq!(
+            say.ssml.raw(),
+            r#"You entered four one two <break time="400ms"/> pound"#
+        );
+        assert_eq!(say.text().split_whitespace().count(), 6);
+        assert_eq!(say.language, None);
+
+        assert_eq!(
+            read_back.ssml(&digits("1A*w2W")),
+            r#"one <break time="400ms"/> A star <break time="500ms"/> two <break time="1000ms"/>"#
+        );
+    }
+
+    #[test]
+    fn test_grouping_and_pacing() {
+        let by_four = ReadBack::builder().grouping(Grouping::By(4)).build();
+        assert_eq!(
+            by_four.ssml(&digits("123456")),
+            r#"one two three four <break time="400ms"/> five six"#
+        );
+
+        let phone = ReadBack::builder()
+            .grouping(Grouping::Phone)
+            .pacing(Pacing::SLOW)
+            .build();
+        assert_eq!(
+            phone.ssml(&digits("5550100")),
+            concat!(
+                r#"<prosody rate="90%">five <break time="200ms"/> five <break time="200ms"/> five"#,
+                r#" <break time="700ms"/> zero <break time="200ms"/> one <break time="200ms"/>"#,
+                r#" zero <break time="200ms"/> zero</prosody>"#
+            )
+        );
+        assert_eq!(Grouping::Phone.sizes(&digits("15551234567")), [1, 3, 3, 4]);
+        assert_eq!(Grouping::By(3).sizes(&digits("12w3456")), [3, 3]);
+    }
+}
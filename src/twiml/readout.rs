@@ -0,0 +1,1239 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Digit;
+
+use super::{Grouping, voices::Language};
+
+/// Largest magnitude spelled out in words; larger numbers are left as numerals.
+const MAX_SPELLED: u64 = 999_999_999_999;
+
+/// What a [`ReadOut`] produces
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReadOutFormat {
+    /// Words, for [`SayBuilder::text`](super::SayBuilder)
+    #[default]
+    Text,
+    /// SSML `<say-as>` markup, for [`SayBuilder::ssml`](super::SayBuilder), leaving the
+    /// wording to the voice
+    Ssml,
+}
+
+/// Currencies [`ReadOut::currency`] knows the names of
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    strum::Display,
+    strum::EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[strum(serialize_all = "UPPERCASE")]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum Currency {
+    Usd,
+    Eur,
+    Gbp,
+    Mxn,
+    Brl,
+}
+
+/// Renders digits and numbers the way a caller expects to hear them in a language.
+///
+/// Words are built in for English, Spanish, French, German, Italian and Portuguese,
+/// including regional differences such as `septante` in `fr-BE` or `hash` for `#` outside
+/// `en-US`. Other languages get numerals and symbols, which the voice reads in its own
+/// language.
+///
+/// ```
+/// use twilio_voice::{Digits, twiml::{Language, ReadOut, Say}};
+///
+/// let code: Digits = "4521#".parse().unwrap();
+/// assert_eq!(
+///     ReadOut::text(Language::EnUs).digits(&code),
+///     "four five two one pound"
+/// );
+///
+/// let ssml = ReadOut::ssml(Language::EnUs).digits(&code);
+/// assert_eq!(ssml, r#"<say-as interpret-as="digits">4521</say-as> pound"#);
+/// let say = Say::builder().ssml(ssml.into()).build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadOut {
+    pub language: Language,
+    pub format: ReadOutFormat,
+}
+
+impl ReadOut {
+    pub fn new(language: Language, format: ReadOutFormat) -> Self {
+        Self { language, format }
+    }
+
+    pub fn text(language: Language) -> Self {
+        Self::new(language, ReadOutFormat::Text)
+    }
+
+    pub fn ssml(language: Language) -> Self {
+        Self::new(language, ReadOutFormat::Ssml)
+    }
+
+    /// The word for a key, empty for pauses. Letters and, for languages without built-in
+    /// words, numerals and symbols are returned as is.
+    pub fn word(&self, digit: Digit) -> String {
+        let locale = Locale::of(self.language);
+        let word = match digit {
+            Digit::Star => locale.star(),
+            Digit::Pound => locale.pound(),
+            Digit::W | Digit::WW => Some(""),
+            digit => digit.to_int().and_then(|n| locale.digit(n)),
+        };
+        word.map_or_else(|| digit.to_string(), str::to_string)
+    }
+
+    /// Digit by digit, e.g. "one two three" or `<say-as interpret-as="digits">123</say-as>`.
+    ///
+    /// Pauses are skipped in text and become `<break>`s in SSML.
+    pub fn digits(&self, digits: &[Digit]) -> String {
+        match self.format {
+            ReadOutFormat::Text => digits
+                .iter()
+                .filter(|digit| !digit.is_pause())
+                .map(|digit| self.word(*digit))
+                .collect::<Vec<_>>()
+                .join(" "),
+            ReadOutFormat::Ssml => {
+                let mut parts = Vec::new();
+                let mut run = String::new();
+                for digit in digits {
+                    if digit.to_int().is_some() {
+                        run.push_str(digit.as_ref());
+                        continue;
+                    }
+                    if !run.is_empty() {
+                        parts.push(say_as("digits", &std::mem::take(&mut run)));
+                    }
+                    parts.push(match digit {
+                        Digit::W => r#"<break time="500ms"/>"#.to_string(),
+                        Digit::WW => r#"<break time="1s"/>"#.to_string(),
+                        digit => self.word(*digit),
+                    });
+                }
+                if !run.is_empty() {
+                    parts.push(say_as("digits", &run));
+                }
+                parts.join(" ")
+            }
+        }
+    }
+
+    /// Digit by digit in groups of the given sizes, separated by a short pause, e.g.
+    /// `&[3, 3, 4]` for "five five five, one two three, four five six seven". Digits past
+    /// the last group form a final group.
+    pub fn grouped(&self, digits: &[Digit], sizes: &[usize]) -> String {
+        let digits = digits
+            .iter()
+            .filter(|digit| !digit.is_pause())
+            .copied()
+            .collect::<Vec<_>>();
+        let mut groups = Vec::new();
+        let mut rest = digits.as_slice();
+        for size in sizes.iter().filter(|size| **size > 0) {
+            if rest.is_empty() {
+                break;
+            }
+            let (group, tail) = rest.split_at((*size).min(rest.len()));
+            groups.push(group);
+            rest = tail;
+        }
+        if !rest.is_empty() {
+            groups.push(rest);
+        }
+        groups
+            .into_iter()
+            .map(|group| self.digits(group))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Digit by digit, grouped like a phone number: 3-3-4 for ten digits, 1-3-3-4 for
+    /// eleven starting with `1`, otherwise threes with a final four when that avoids a
+    /// single trailing digit.
+    pub fn phone(&self, digits: &[Digit]) -> String {
+        self.grouped(digits, &Grouping::Phone.sizes(digits))
+    }
+
+    /// The number as a cardinal, e.g. "one thousand two hundred five" or
+    /// `<say-as interpret-as="cardinal">1205</say-as>`.
+    pub fn cardinal(&self, n: i64) -> String {
+        match self.format {
+            ReadOutFormat::Text => Locale::of(self.language).cardinal(n, Form::Standalone),
+            ReadOutFormat::Ssml => say_as("cardinal", &n.to_string()),
+        }
+    }
+
+    /// An amount in minor units (cents), e.g. `1250` in [`Currency::Usd`] as "twelve
+    /// dollars and fifty cents".
+    ///
+    /// Voices don't agree on `<say-as interpret-as="currency">`, so SSML gets the same
+    /// words as text. Languages without built-in words get e.g. "12.50 USD".
+    pub fn currency(&self, minor_units: i64, currency: Currency) -> String {
+        let locale = Locale::of(self.language);
+        let (major, minor) = (
+            minor_units.unsigned_abs() / 100,
+            minor_units.unsigned_abs() % 100,
+        );
+        let sign = if minor_units < 0 { "-" } else { "" };
+        let (Some(names), Some(minus)) = (locale.currency(currency), locale.minus()) else {
+            return format!("{sign}{major}.{minor:02} {currency}");
+        };
+        if major > MAX_SPELLED {
+            return format!("{sign}{major}.{minor:02} {currency}");
+        }
+
+        let amount = |n: u64, (singular, plural, feminine): Noun| {
+            let form = if feminine {
+                Form::Feminine
+            } else {
+                Form::Masculine
+            };
+            let noun = if n == 1 { singular } else { plural };
+            let number = locale.spell(n, form).unwrap_or_else(|| n.to_string());
+            match locale.of_millions(noun) {
+                Some(of) if n >= 1_000_000 && n.is_multiple_of(1_000_000) => {
+                    format!("{number} {of}{noun}")
+                }
+                _ => format!("{number} {noun}"),
+            }
+        };
+        let words = match (major, minor) {
+            (0, minor) if minor > 0 => amount(minor, names.minor),
+            (major, 0) => amount(major, names.major),
+            (major, minor) => format!(
+                "{} {} {}",
+                amount(major, names.major),
+                locale.and(),
+                amount(minor, names.minor)
+            ),
+        };
+        if minor_units < 0 {
+            format!("{minus} {words}")
+        } else {
+            words
+        }
+    }
+}
+
+fn say_as(interpret_as: &str, content: &str) -> String {
+    format!(r#"<say-as interpret-as="{interpret_as}">{content}</say-as>"#)
+}
+
+/// Singular, plural, and whether the noun is feminine
+type Noun = (&'static str, &'static str, bool);
+
+struct CurrencyNames {
+    major: Noun,
+    minor: Noun,
+}
+
+/// Grammatical form of a number, which changes "one" in most languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Form {
+    /// Counting, e.g. Spanish `uno`, German `eins`
+    Standalone,
+    /// Before a masculine (or neuter) noun, e.g. Spanish `un`, German `ein`
+    Masculine,
+    /// Before a feminine noun, e.g. Spanish `una`, Portuguese `duas`
+    Feminine,
+}
+
+/// The built-in words for a language
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Locale {
+    /// `american` leaves out the "and" in "one hundred and five" and says "pound" for `#`
+    En {
+        american: bool,
+    },
+    Es {
+        european: bool,
+    },
+    Fr(FrenchRegion),
+    De,
+    It,
+    Pt {
+        european: bool,
+    },
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FrenchRegion {
+    France,
+    /// `septante` and `nonante`
+    Belgium,
+    /// `carré` for `#`
+    Canada,
+}
+
+impl Locale {
+    fn of(language: Language) -> Self {
+        let tag = language.to_string();
+        let mut subtags = tag.split('-');
+        let primary = subtags.next().unwrap_or_default();
+        let region = subtags.next().unwrap_or_default();
+        match primary {
+            "en" => Locale::En {
+                american: region == "US",
+            },
+            "es" => Locale::Es {
+                european: region == "ES",
+            },
+            "fr" => Locale::Fr(match region {
+                "BE" => FrenchRegion::Belgium,
+                "CA" => FrenchRegion::Canada,
+                _ => FrenchRegion::France,
+            }),
+            "de" => Locale::De,
+            "it" => Locale::It,
+            "pt" => Locale::Pt {
+                european: region == "PT",
+            },
+            _ => Locale::Other,
+        }
+    }
+
+    fn digit(self, n: usize) -> Option<&'static str> {
+        let ones: &[&str] = match self {
+            Locale::En { .. } => &EN_ONES,
+            Locale::Es { .. } => &ES_ONES,
+            Locale::Fr(_) => &FR_ONES,
+            Locale::De => &DE_ONES,
+            Locale::It => &IT_ONES,
+            Locale::Pt { european } => pt_ones(european),
+            Locale::Other => return None,
+        };
+        ones.get(n).copied()
+    }
+
+    fn star(self) -> Option<&'static str> {
+        match self {
+            Locale::En { .. } => Some("star"),
+            Locale::Es { .. } | Locale::It | Locale::Pt { .. } => Some("asterisco"),
+            Locale::Fr(_) => Some("étoile"),
+            Locale::De => Some("Stern"),
+            Locale::Other => None,
+        }
+    }
+
+    fn pound(self) -> Option<&'static str> {
+        match self {
+            Locale::En { american: true } => Some("pound"),
+            Locale::En { american: false } => Some("hash"),
+            Locale::Es { european: true } => Some("almohadilla"),
+            Locale::Es { european: false } => Some("numeral"),
+            Locale::Fr(FrenchRegion::Canada) => Some("carré"),
+            Locale::Fr(_) => Some("dièse"),
+            Locale::De => Some("Raute"),
+            Locale::It => Some("cancelletto"),
+            Locale::Pt { european: true } => Some("cardinal"),
+            Locale::Pt { european: false } => Some("cerquilha"),
+            Locale::Other => None,
+        }
+    }
+
+    fn minus(self) -> Option<&'static str> {
+        match self {
+            Locale::En { .. } | Locale::De => Some("minus"),
+            Locale::Es { .. } | Locale::Pt { .. } => Some("menos"),
+            Locale::Fr(_) => Some("moins"),
+            Locale::It => Some("meno"),
+            Locale::Other => None,
+        }
+    }
+
+    /// Joins the major and minor units of an amount
+    fn and(self) -> &'static str {
+        match self {
+            Locale::Es { .. } => "con",
+            Locale::Fr(_) => "et",
+            Locale::De => "und",
+            Locale::It | Locale::Pt { .. } => "e",
+            Locale::En { .. } | Locale::Other => "and",
+        }
+    }
+
+    /// The preposition between round millions and a noun, e.g. "un millón de dólares"
+    fn of_millions(self, noun: &str) -> Option<&'static str> {
+        match self {
+            Locale::Es { .. } | Locale::Pt { .. } => Some("de "),
+            Locale::It => Some("di "),
+            Locale::Fr(_) if noun.starts_with(['a', 'e', 'é', 'i', 'o', 'u']) => Some("d'"),
+            Locale::Fr(_) => Some("de "),
+            Locale::En { .. } | Locale::De | Locale::Other => None,
+        }
+    }
+
+    fn cardinal(self, n: i64, form: Form) -> String {
+        match (self.spell(n.unsigned_abs(), form), self.minus()) {
+            (Some(words), Some(minus)) if n < 0 => format!("{minus} {words}"),
+            (Some(words), _) => words,
+            (None, _) => n.to_string(),
+        }
+    }
+
+    /// The number in words, or `None` if it's too large or the language has no words
+    fn spell(self, n: u64, form: Form) -> Option<String> {
+        if n > MAX_SPELLED {
+            return None;
+        }
+        let n = n as usize;
+        match self {
+            Locale::En { american } => Some(en_cardinal(n, !american)),
+            Locale::Es { .. } => Some(es_cardinal(n, form)),
+            Locale::Fr(region) => Some(fr_cardinal(n, form, region == FrenchRegion::Belgium)),
+            Locale::De => Some(de_cardinal(n, form)),
+            Locale::It => Some(it_cardinal(n, form)),
+            Locale::Pt { european } => Some(pt_cardinal(n, form, european)),
+            Locale::Other => None,
+        }
+    }
+
+    fn currency(self, currency: Currency) -> Option<CurrencyNames> {
+        use Currency::*;
+        let (major, minor): (Noun, Noun) = match (self, currency) {
+            (Locale::En { .. }, Usd) => (("dollar", "dollars", false), ("cent", "cents", false)),
+            (Locale::En { .. }, Eur) => (("euro", "euros", false), ("cent", "cents", false)),
+            (Locale::En { .. }, Gbp) => (("pound", "pounds", false), ("penny", "pence", false)),
+            (Locale::En { .. }, Mxn) => (("peso", "pesos", false), ("centavo", "centavos", false)),
+            (Locale::En { .. }, Brl) => (("real", "reais", false), ("centavo", "centavos", false)),
+            (Locale::Es { .. }, Usd) => {
+                (("dólar", "dólares", false), ("centavo", "centavos", false))
+            }
+            (Locale::Es { .. }, Eur) => (("euro", "euros", false), ("céntimo", "céntimos", false)),
+            (Locale::Es { .. }, Gbp) => (("libra", "libras", true), ("penique", "peniques", false)),
+            (Locale::Es { .. }, Mxn) => (("peso", "pesos", false), ("centavo", "centavos", false)),
+            (Locale::Es { .. }, Brl) => (("real", "reales", false), ("centavo", "centavos", false)),
+            (Locale::Fr(_), Usd) => (("dollar", "dollars", false), ("cent", "cents", false)),
+            (Locale::Fr(_), Eur) => (("euro", "euros", false), ("centime", "centimes", false)),
+            (Locale::Fr(_), Gbp) => (("livre", "livres", true), ("penny", "pence", false)),
+            (Locale::Fr(_), Mxn) => (("peso", "pesos", false), ("centavo", "centavos", false)),
+            (Locale::Fr(_), Brl) => (("réal", "réais", false), ("centavo", "centavos", false)),
+            (Locale::De, Usd) => (("Dollar", "Dollar", false), ("Cent", "Cent", false)),
+            (Locale::De, Eur) => (("Euro", "Euro", false), ("Cent", "Cent", false)),
+            (Locale::De, Gbp) => (("Pfund", "Pfund", false), ("Penny", "Pence", false)),
+            (Locale::De, Mxn) => (("Peso", "Pesos", false), ("Centavo", "Centavos", false)),
+            (Locale::De, Brl) => (("Real", "Reais", false), ("Centavo", "Centavos", false)),
+            (Locale::It, Usd) => (
+                ("dollaro", "dollari", false),
+                ("centesimo", "centesimi", false),
+            ),
+            (Locale::It, Eur) => (("euro", "euro", false), ("centesimo", "centesimi", false)),
+            (Locale::It, Gbp) => (("sterlina", "sterline", true), ("penny", "pence", false)),
+            (Locale::It, Mxn) => (("peso", "pesos", false), ("centavo", "centavos", false)),
+            (Locale::It, Brl) => (("real", "reais", false), ("centavo", "centavos", false)),
+            (Locale::Pt { .. }, Usd) => {
+                (("dólar", "dólares", false), ("centavo", "centavos", false))
+            }
+            (Locale::Pt { .. }, Eur) => (("euro", "euros", false), ("cêntimo", "cêntimos", false)),
+            (Locale::Pt { .. }, Gbp) => (("libra", "libras", true), ("pêni", "pence", false)),
+            (Locale::Pt { .. }, Mxn) => (("peso", "pesos", false), ("centavo", "centavos", false)),
+            (Locale::Pt { .. }, Brl) => (("real", "reais", false), ("centavo", "centavos", false)),
+            (Locale::Other, _) => return None,
+        };
+        Some(CurrencyNames { major, minor })
+    }
+}
+
+/// Splits `n` into its billions, millions, thousands and units, each below 1000
+fn groups(n: usize) -> [usize; 4] {
+    [
+        n / 1_000_000_000,
+        n / 1_000_000 % 1000,
+        n / 1000 % 1000,
+        n % 1000,
+    ]
+}
+
+const EN_ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const EN_TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+fn en_below_100(n: usize) -> String {
+    match n {
+        0..20 => EN_ONES[n].to_string(),
+        _ if n.is_multiple_of(10) => EN_TENS[n / 10 - 2].to_string(),
+        _ => format!("{}-{}", EN_TENS[n / 10 - 2], EN_ONES[n % 10]),
+    }
+}
+
+/// `and` is the British "one hundred and five"
+fn en_cardinal(n: usize, and: bool) -> String {
+    if n == 0 {
+        return EN_ONES[0].to_string();
+    }
+    let mut words = Vec::new();
+    for (group, scale) in groups(n)
+        .into_iter()
+        .zip(["billion", "million", "thousand", ""])
+    {
+        if group == 0 {
+            continue;
+        }
+        let (hundreds, rest) = (group / 100, group % 100);
+        if hundreds > 0 {
+            words.push(format!("{} hundred", EN_ONES[hundreds]));
+        }
+        if rest > 0 {
+            if and && (hundreds > 0 || (scale.is_empty() && n >= 1000)) {
+                words.push("and".to_string());
+            }
+            words.push(en_below_100(rest));
+        }
+        if !scale.is_empty() {
+            words.push(scale.to_string());
+        }
+    }
+    words.join(" ")
+}
+
+const ES_ONES: [&str; 30] = [
+    "cero",
+    "uno",
+    "dos",
+    "tres",
+    "cuatro",
+    "cinco",
+    "seis",
+    "siete",
+    "ocho",
+    "nueve",
+    "diez",
+    "once",
+    "doce",
+    "trece",
+    "catorce",
+    "quince",
+    "dieciséis",
+    "diecisiete",
+    "dieciocho",
+    "diecinueve",
+    "veinte",
+    "veintiuno",
+    "veintidós",
+    "veintitrés",
+    "veinticuatro",
+    "veinticinco",
+    "veintiséis",
+    "veintisiete",
+    "veintiocho",
+    "veintinueve",
+];
+const ES_TENS: [&str; 7] = [
+    "treinta",
+    "cuarenta",
+    "cincuenta",
+    "sesenta",
+    "setenta",
+    "ochenta",
+    "noventa",
+];
+const ES_HUNDREDS: [&str; 9] = [
+    "ciento",
+    "doscientos",
+    "trescientos",
+    "cuatrocientos",
+    "quinientos",
+    "seiscientos",
+    "setecientos",
+    "ochocientos",
+    "novecientos",
+];
+
+fn es_below_1000(n: usize, form: Form) -> String {
+    let one = match form {
+        Form::Standalone => "uno",
+        Form::Masculine => "un",
+        Form::Feminine => "una",
+    };
+    let below_100 = |n: usize| match n {
+        1 => one.to_string(),
+        21 => format!("veinti{}", if one == "un" { "ún" } else { one }),
+        0..30 => ES_ONES[n].to_string(),
+        _ if n.is_multiple_of(10) => ES_TENS[n / 10 - 3].to_string(),
+        _ if n % 10 == 1 => format!("{} y {one}", ES_TENS[n / 10 - 3]),
+        _ => format!("{} y {}", ES_TENS[n / 10 - 3], ES_ONES[n % 10]),
+    };
+    let (hundreds, rest) = (n / 100, n % 100);
+    let hundreds = match hundreds {
+        0 => None,
+        1 if rest == 0 => Some("cien".to_string()),
+        h if form == Form::Feminine => Some(ES_HUNDREDS[h - 1].replace("ientos", "ientas")),
+        h => Some(ES_HUNDREDS[h - 1].to_string()),
+    };
+    let rest = (rest > 0).then(|| below_100(rest));
+    [hundreds, rest]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Below a million, used again for the number of millions ("mil millones")
+fn es_below_million(n: usize, form: Form) -> String {
+    let (thousands, rest) = (n / 1000, n % 1000);
+    let thousands = match thousands {
+        0 => None,
+        1 => Some("mil".to_string()),
+        t if form == Form::Feminine => Some(format!("{} mil", es_below_1000(t, form))),
+        t => Some(format!("{} mil", es_below_1000(t, Form::Masculine))),
+    };
+    let rest = (rest > 0).then(|| es_below_1000(rest, form));
+    [thousands, rest]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn es_cardinal(n: usize, form: Form) -> String {
+    if n == 0 {
+        return ES_ONES[0].to_string();
+    }
+    let (millions, rest) = (n / 1_000_000, n % 1_000_000);
+    let millions = match millions {
+        0 => None,
+        1 => Some("un millón".to_string()),
+        m => Some(format!("{} millones", es_below_million(m, Form::Masculine))),
+    };
+    let rest = (rest > 0).then(|| es_below_million(rest, form));
+    [millions, rest]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+const FR_ONES: [&str; 17] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize",
+];
+const FR_TENS: [&str; 8] = [
+    "vingt",
+    "trente",
+    "quarante",
+    "cinquante",
+    "soixante",
+    "septante",
+    "quatre-vingt",
+    "nonante",
+];
+
+fn fr_below_100(n: usize, one: &str, belgian: bool) -> String {
+    let (tens, units) = (n / 10, n % 10);
+    match n {
+        1 => one.to_string(),
+        0..17 => FR_ONES[n].to_string(),
+        17..20 => format!("dix-{}", FR_ONES[units]),
+        80 => "quatre-vingts".to_string(),
+        _ if tens == 8 && units == 1 => format!("quatre-vingt-{one}"),
+        _ if (tens == 7 || tens == 9) && !belgian => {
+            let base = FR_TENS[tens - 3];
+            match units {
+                1 if tens == 7 => format!("{base} et onze"),
+                _ => format!("{base}-{}", fr_below_100(10 + units, one, belgian)),
+            }
+        }
+        _ if units == 0 => FR_TENS[tens - 2].to_string(),
+        _ if units == 1 => format!("{} et {one}", FR_TENS[tens - 2]),
+        _ => format!("{}-{}", FR_TENS[tens - 2], FR_ONES[units]),
+    }
+}
+
+/// `plural` is false before `mille`, which drops the `s` of "deux cents" and
+/// "quatre-vingts"
+fn fr_below_1000(n: usize, one: &str, belgian: bool, plural: bool) -> String {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let mut words = match hundreds {
+        0 => String::new(),
+        1 => "cent".to_string(),
+        h if rest == 0 && plural => format!("{} cents", FR_ONES[h]),
+        h => format!("{} cent", FR_ONES[h]),
+    };
+    if rest > 0 {
+        if !words.is_empty() {
+            words.push(' ');
+        }
+        words.push_str(&fr_below_100(rest, one, belgian));
+    }
+    if !plural && words.ends_with("vingts") {
+        words.pop();
+    }
+    words
+}
+
+fn fr_cardinal(n: usize, form: Form, belgian: bool) -> String {
+    if n == 0 {
+        return FR_ONES[0].to_string();
+    }
+    let one = if form == Form::Feminine { "une" } else { "un" };
+    let [billions, millions, thousands, rest] = groups(n);
+    let scale = |n: usize, singular: &str, plural: &str| match n {
+        0 => None,
+        1 => Some(format!("un {singular}")),
+        n => Some(format!(
+            "{} {plural}",
+            fr_below_1000(n, "un", belgian, true)
+        )),
+    };
+    let thousands = match thousands {
+        0 => None,
+        1 => Some("mille".to_string()),
+        t => Some(format!("{} mille", fr_below_1000(t, "un", belgian, false))),
+    };
+    [
+        scale(billions, "milliard", "milliards"),
+        scale(millions, "million", "millions"),
+        thousands,
+        (rest > 0).then(|| fr_below_1000(rest, one, belgian, true)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+const DE_ONES: [&str; 20] = [
+    "null",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+const DE_TENS: [&str; 8] = [
+    "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+/// `one` is the word for a trailing 1: "eins", "ein" or "eine"
+fn de_below_1000(n: usize, one: &str) -> String {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let mut words = match hundreds {
+        0 => String::new(),
+        1 => "einhundert".to_string(),
+        h => format!("{}hundert", DE_ONES[h]),
+    };
+    match rest {
+        0 => {}
+        1 => words.push_str(one),
+        2..20 => words.push_str(DE_ONES[rest]),
+        _ => {
+            match rest % 10 {
+                0 => {}
+                1 => words.push_str("einund"),
+                u => words.push_str(&format!("{}und", DE_ONES[u])),
+            }
+            words.push_str(DE_TENS[rest / 10 - 2]);
+        }
+    }
+    words
+}
+
+fn de_cardinal(n: usize, form: Form) -> String {
+    if n == 0 {
+        return DE_ONES[0].to_string();
+    }
+    let one = match form {
+        Form::Standalone => "eins",
+        Form::Masculine => "ein",
+        Form::Feminine => "eine",
+    };
+    let [billions, millions, thousands, rest] = groups(n);
+    let scale = |n: usize, singular: &str, plural: &str| match n {
+        0 => None,
+        1 => Some(format!("eine {singular}")),
+        n => Some(format!("{} {plural}", de_below_1000(n, "ein"))),
+    };
+    let mut below_million = String::new();
+    if thousands > 0 {
+        below_million = format!("{}tausend", de_below_1000(thousands, "ein"));
+    }
+    if rest > 0 {
+        below_million.push_str(&de_below_1000(rest, one));
+    }
+    [
+        scale(billions, "Milliarde", "Milliarden"),
+        scale(millions, "Million", "Millionen"),
+        (!below_million.is_empty()).then_some(below_million),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+const IT_ONES: [&str; 20] = [
+    "zero",
+    "uno",
+    "due",
+    "tre",
+    "quattro",
+    "cinque",
+    "sei",
+    "sette",
+    "otto",
+    "nove",
+    "dieci",
+    "undici",
+    "dodici",
+    "tredici",
+    "quattordici",
+    "quindici",
+    "sedici",
+    "diciassette",
+    "diciotto",
+    "diciannove",
+];
+const IT_TENS: [&str; 8] = [
+    "venti",
+    "trenta",
+    "quaranta",
+    "cinquanta",
+    "sessanta",
+    "settanta",
+    "ottanta",
+    "novanta",
+];
+
+fn it_below_1000(n: usize) -> String {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let mut words = match hundreds {
+        0 => String::new(),
+        1 => "cento".to_string(),
+        h => format!("{}cento", IT_ONES[h]),
+    };
+    // "cento" and the tens drop their last vowel before "otto", "ottanta" and "uno"
+    let elide = |words: &mut String| {
+        words.pop();
+    };
+    match rest {
+        0 => {}
+        3 if hundreds > 0 => words.push_str("tré"),
+        1 | 8 | 80..90 if hundreds > 0 => {
+            elide(&mut words);
+            words.push_str(&it_below_1000(rest));
+        }
+        1..20 => words.push_str(IT_ONES[rest]),
+        _ => {
+            let mut tens = IT_TENS[rest / 10 - 2].to_string();
+            match rest % 10 {
+                0 => {}
+                1 | 8 => {
+                    elide(&mut tens);
+                    tens.push_str(IT_ONES[rest % 10]);
+                }
+                3 => tens.push_str("tré"),
+                u => tens.push_str(IT_ONES[u]),
+            }
+            words.push_str(&tens);
+        }
+    }
+    words
+}
+
+fn it_cardinal(n: usize, form: Form) -> String {
+    match (n, form) {
+        (0, _) => return IT_ONES[0].to_string(),
+        (1, Form::Masculine) => return "un".to_string(),
+        (1, Form::Feminine) => return "una".to_string(),
+        _ => {}
+    }
+    let [billions, millions, thousands, rest] = groups(n);
+    let scale = |n: usize, singular: &str, plural: &str| match n {
+        0 => None,
+        1 => Some(format!("un {singular}")),
+        n => Some(format!("{} {plural}", it_below_1000(n))),
+    };
+    let mut below_million = match thousands {
+        0 => String::new(),
+        1 => "mille".to_string(),
+        t => format!("{}mila", it_below_1000(t)),
+    };
+    below_million.push_str(&it_below_1000(rest));
+    [
+        scale(billions, "miliardo", "miliardi"),
+        scale(millions, "milione", "milioni"),
+        (!below_million.is_empty()).then_some(below_million),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+const PT_ONES_BR: [&str; 20] = [
+    "zero",
+    "um",
+    "dois",
+    "três",
+    "quatro",
+    "cinco",
+    "seis",
+    "sete",
+    "oito",
+    "nove",
+    "dez",
+    "onze",
+    "doze",
+    "treze",
+    "catorze",
+    "quinze",
+    "dezesseis",
+    "dezessete",
+    "dezoito",
+    "dezenove",
+];
+const PT_ONES_PT: [&str; 20] = [
+    "zero",
+    "um",
+    "dois",
+    "três",
+    "quatro",
+    "cinco",
+    "seis",
+    "sete",
+    "oito",
+    "nove",
+    "dez",
+    "onze",
+    "doze",
+    "treze",
+    "catorze",
+    "quinze",
+    "dezasseis",
+    "dezassete",
+    "dezoito",
+    "dezanove",
+];
+const PT_TENS: [&str; 8] = [
+    "vinte",
+    "trinta",
+    "quarenta",
+    "cinquenta",
+    "sessenta",
+    "setenta",
+    "oitenta",
+    "noventa",
+];
+const PT_HUNDREDS: [&str; 9] = [
+    "cento",
+    "duzentos",
+    "trezentos",
+    "quatrocentos",
+    "quinhentos",
+    "seiscentos",
+    "setecentos",
+    "oitocentos",
+    "novecentos",
+];
+
+fn pt_ones(european: bool) -> &'static [&'static str; 20] {
+    if european { &PT_ONES_PT } else { &PT_ONES_BR }
+}
+
+fn pt_below_1000(n: usize, form: Form, european: bool) -> String {
+    let unit = |n: usize| match (n, form) {
+        (1, Form::Feminine) => "uma",
+        (2, Form::Feminine) => "duas",
+        (n, _) => pt_ones(european)[n],
+    };
+    let (hundreds, rest) = (n / 100, n % 100);
+    let hundreds = match hundreds {
+        0 => None,
+        1 if rest == 0 => Some("cem".to_string()),
+        h if form == Form::Feminine && h > 1 => Some(PT_HUNDREDS[h - 1].replace("entos", "entas")),
+        h => Some(PT_HUNDREDS[h - 1].to_string()),
+    };
+    let rest = match rest {
+        0 => None,
+        1..20 => Some(unit(rest).to_string()),
+        _ if rest.is_multiple_of(10) => Some(PT_TENS[rest / 10 - 2].to_string()),
+        _ => Some(format!("{} e {}", PT_TENS[rest / 10 - 2], unit(rest % 10))),
+    };
+    [hundreds, rest]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" e ")
+}
+
+/// Joins the groups of a number, with "e" before a last group below 100 or of round
+/// hundreds, e.g. "mil e cem" but "mil cento e um"
+fn pt_join(parts: Vec<String>, last: usize) -> String {
+    let mut words = String::new();
+    let count = parts.len();
+    for (i, part) in parts.into_iter().enumerate() {
+        if i > 0 {
+            let round = i == count - 1 && last > 0 && (last < 100 || last.is_multiple_of(100));
+            words.push_str(if round { " e " } else { " " });
+        }
+        words.push_str(&part);
+    }
+    words
+}
+
+fn pt_cardinal(n: usize, form: Form, european: bool) -> String {
+    if n == 0 {
+        return pt_ones(european)[0].to_string();
+    }
+    let [billions, millions, thousands, rest] = groups(n);
+    let scale = |n: usize, singular: &str, plural: &str| match n {
+        0 => None,
+        1 => Some(format!("um {singular}")),
+        n => Some(format!(
+            "{} {plural}",
+            pt_below_1000(n, Form::Masculine, european)
+        )),
+    };
+    let mut parts = Vec::new();
+    if european {
+        // Long scale: a thousand million ("mil milhões") instead of a billion
+        let millions = billions * 1000 + millions;
+        parts.extend(match millions {
+            0 => None,
+            1 => Some("um milhão".to_string()),
+            m => Some(format!(
+                "{} milhões",
+                pt_join(
+                    [
+                        (m / 1000 > 0).then(|| pt_thousands(m / 1000, Form::Masculine, true)),
+                        (m % 1000 > 0).then(|| pt_below_1000(m % 1000, Form::Masculine, true)),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+                    m % 1000,
+                )
+            )),
+        });
+    } else {
+        parts.extend(scale(billions, "bilhão", "bilhões"));
+        parts.extend(scale(millions, "milhão", "milhões"));
+    }
+    if thousands > 0 {
+        parts.push(pt_thousands(thousands, form, european));
+    }
+    if rest > 0 {
+        parts.push(pt_below_1000(rest, form, european));
+    }
+    pt_join(parts, if rest > 0 { rest } else { thousands * 1000 })
+}
+
+fn pt_thousands(n: usize, form: Form, european: bool) -> String {
+    match n {
+        1 => "mil".to_string(),
+        n => format!("{} mil", pt_below_1000(n, form, european)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::Digits;
+
+    use super::*;
+
+    fn digits(s: &str) -> Digits {
+        Digits::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_english() {
+        let us = ReadOut::text(Language::EnUs);
+        assert_eq!(us.digits(&digits("12w3*")), "one two three star");
+        assert_eq!(us.cardinal(0), "zero");
+        assert_eq!(us.cardinal(-15), "minus fifteen");
+        assert_eq!(
+            us.cardinal(1_234_005),
+            "one million two hundred thirty-four thousand five"
+        );
+        assert_eq!(us.cardinal(i64::MAX), i64::MAX.to_string());
+        assert_eq!(
+            us.currency(1250, Currency::Usd),
+            "twelve dollars and fifty cents"
+        );
+        assert_eq!(us.currency(101, Currency::Usd), "one dollar and one cent");
+        assert_eq!(us.currency(-5, Currency::Gbp), "minus five pence");
+        assert_eq!(us.currency(0, Currency::Eur), "zero euros");
+
+        let gb = ReadOut::text(Language::EnGb);
+        assert_eq!(gb.word(Digit::Pound), "hash");
+        assert_eq!(gb.cardinal(105), "one hundred and five");
+        assert_eq!(gb.cardinal(2_007), "two thousand and seven");
+    }
+
+    #[test]
+    fn test_grouping() {
+        let us = ReadOut::text(Language::EnUs);
+        assert_eq!(
+            us.phone(&digits("5551234567")),
+            "five five five, one two three, four five six seven"
+        );
+        assert_eq!(
+            us.phone(&digits("15551234567")),
+            "one, five five five, one two three, four five six seven"
+        );
+        assert_eq!(
+            us.grouped(&digits("12345"), &[2]),
+            "one two, three four five"
+        );
+        assert_eq!(
+            us.phone(&digits("1234567")),
+            "one two three, four five six seven"
+        );
+    }
+
+    #[test]
+    fn test_ssml() {
+        let ssml = ReadOut::ssml(Language::EnUs);
+        assert_eq!(
+            ssml.digits(&digits("12W34#")),
+            r#"<say-as interpret-as="digits">12</say-as> <break time="1s"/> <say-as interpret-as="digits">34</say-as> pound"#
+        );
+        assert_eq!(
+            ssml.cardinal(-42),
+            r#"<say-as interpret-as="cardinal">-42</say-as>"#
+        );
+        assert_eq!(ssml.currency(200, Currency::Usd), "two dollars");
+        assert_eq!(Digit::Star.word_in(Language::EnUs), Digit::Star.word());
+    }
+
+    #[test]
+    fn test_spelling() {
+        let es = |n| es_cardinal(n, Form::Standalone);
+        assert_eq!(es(21), "veintiuno");
+        assert_eq!(es(100), "cien");
+        assert_eq!(es(121_000), "ciento veintiún mil");
+        assert_eq!(es(1_000_000_000), "mil millones");
+        assert_eq!(es_cardinal(201, Form::Feminine), "doscientas una");
+
+        let fr = |n| fr_cardinal(n, Form::Standalone, false);
+        assert_eq!(fr(71), "soixante et onze");
+        assert_eq!(fr(80), "quatre-vingts");
+        assert_eq!(fr(91), "quatre-vingt-onze");
+        assert_eq!(fr(280_000), "deux cent quatre-vingt mille");
+        assert_eq!(fr(2_000_000), "deux millions");
+        assert_eq!(fr_cardinal(97, Form::Standalone, true), "nonante-sept");
+
+        let de = |n| de_cardinal(n, Form::Standalone);
+        assert_eq!(de(1), "eins");
+        assert_eq!(de(101), "einhunderteins");
+        assert_eq!(de(21_354), "einundzwanzigtausenddreihundertvierundfünfzig");
+        assert_eq!(de(2_000_001), "zwei Millionen eins");
+
+        let it = |n| it_cardinal(n, Form::Standalone);
+        assert_eq!(it(28), "ventotto");
+        assert_eq!(it(33), "trentatré");
+        assert_eq!(it(180), "centottanta");
+        assert_eq!(it(2_021), "duemilaventuno");
+        assert_eq!(it(1_000_000), "un milione");
+
+        let br = |n| pt_cardinal(n, Form::Standalone, false);
+        assert_eq!(br(16), "dezesseis");
+        assert_eq!(br(1_100), "mil e cem");
+        assert_eq!(br(1_101), "mil cento e um");
+        assert_eq!(br(2_000_000_000), "dois bilhões");
+        assert_eq!(
+            pt_cardinal(2_000_000_000, Form::Standalone, true),
+            "dois mil milhões"
+        );
+        assert_eq!(pt_cardinal(202, Form::Feminine, false), "duzentas e duas");
+    }
+
+    #[cfg(all(
+        feature = "es-mx",
+        feature = "fr-fr",
+        feature = "de-de",
+        feature = "pt-br"
+    ))]
+    #[test]
+    fn test_languages() {
+        let es = ReadOut::text(Language::EsMx);
+        assert_eq!(es.digits(&digits("21#")), "dos uno numeral");
+        assert_eq!(
+            es.currency(2_101, Currency::Usd),
+            "veintiún dólares con un centavo"
+        );
+        assert_eq!(
+            es.currency(100_000_000, Currency::Mxn),
+            "un millón de pesos"
+        );
+        assert_eq!(es.currency(2_100, Currency::Gbp), "veintiuna libras");
+
+        let fr = ReadOut::text(Language::FrFr);
+        assert_eq!(
+            fr.currency(200_000_000, Currency::Eur),
+            "deux millions d'euros"
+        );
+        assert_eq!(fr.currency(100, Currency::Gbp), "une livre");
+
+        let de = ReadOut::text(Language::DeDe);
+        assert_eq!(de.word(Digit::Pound), "Raute");
+        assert_eq!(
+            de.currency(199, Currency::Eur),
+            "ein Euro und neunundneunzig Cent"
+        );
+
+        let pt = ReadOut::text(Language::PtBr);
+        assert_eq!(pt.currency(200, Currency::Gbp), "duas libras");
+        assert_eq!(pt.cardinal(-1), "menos um");
+    }
+
+    #[cfg(feature = "ja-jp")]
+    #[test]
+    fn test_other_languages() {
+        let ja = ReadOut::text(Language::JaJp);
+        assert_eq!(ja.digits(&digits("12#")), "1 2 #");
+        assert_eq!(ja.cardinal(1200), "1200");
+        assert_eq!(ja.currency(-1250, Currency::Usd), "-12.50 USD");
+    }
+}
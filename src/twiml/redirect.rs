@@ -7,7 +7,7 @@ use super::Method;
 /// https://www.twilio.com/docs/voice/twiml/redirect
 #[derive(Debug, Clone, TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Redirect {
-    #[serde(rename = "@method")]
+    #[serde(default, rename = "@method")]
     #[builder(default)]
     pub method: Method,
     #[serde(rename = "#text")]
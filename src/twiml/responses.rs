@@ -2,7 +2,7 @@ use std::{fmt::Display, str::FromStr};
 
 use crate::{PriceType, TwilioError};
 
-use super::{Gather, Play, Redirect, Say, VoicePrice};
+use super::{Gather, GatherVerb, Play, Redirect, Say, Voice, VoicePrice};
 use quick_xml::escape::{escape, unescape};
 use regex::{Captures, Regex};
 use serde::{Deserialize, Serialize};
@@ -48,6 +48,85 @@ impl FromStr for Response {
     }
 }
 
+impl Response {
+    /// Like [`Response::from_str`](FromStr::from_str), but fails for the elements it would
+    /// leave out, with their line and column: verbs this crate doesn't support, like `<Dial>`,
+    /// and misplaced ones.
+    pub fn from_str_exact(s: &str) -> Result<Self, TwilioError> {
+        let source = super::scan_source(s);
+        let invalid = |i: usize, element: &super::SourceElement| {
+            let message = if i == 0 {
+                format!("{}:{}: expected <Response>", element.line, element.column)
+            } else {
+                format!(
+                    "{}:{}: invalid or unsupported <{}> element",
+                    element.line, element.column, element.name
+                )
+            };
+            TwilioError::ResponseDeser(message, s.to_string())
+        };
+
+        let response = Self::from_str(s).map_err(|e| {
+            match source
+                .iter()
+                .enumerate()
+                .find(|(i, e)| (*i == 0) != (e.name == "Response") || !is_element(&e.name))
+            {
+                Some((i, element)) => invalid(i, element),
+                None => e,
+            }
+        })?;
+
+        // serde-xml-rs ends a sequence at the first child it can't deserialize instead of
+        // failing, so compare against the source to catch dropped verbs.
+        let parsed = response.element_names();
+        match source
+            .iter()
+            .enumerate()
+            .find(|(i, e)| parsed.get(*i) != Some(&e.name.as_str()))
+        {
+            Some((i, element)) => Err(invalid(i, element)),
+            None => Ok(response),
+        }
+    }
+
+    /// Like [`Response::from_str_exact`], but also fails for voices that aren't in the
+    /// catalog of the compiled-in languages instead of keeping them as [`Voice::Other`].
+    pub fn from_str_strict(s: &str) -> Result<Self, TwilioError> {
+        let response = Self::from_str_exact(s)?;
+        let source = super::scan_source(s);
+
+        // Elements in document order, matching `element_names`
+        let mut says = vec![None];
+        for verb in &response.verbs {
+            says.push(match verb {
+                ResponseVerb::Say(say) => Some(say),
+                _ => None,
+            });
+            if let ResponseVerb::Gather(gather) = verb {
+                says.extend(gather.verbs.iter().map(|verb| match verb {
+                    GatherVerb::Say(say) => Some(say),
+                    _ => None,
+                }));
+            }
+        }
+        for (say, element) in says.into_iter().zip(&source) {
+            if let Some(Voice::Other(id)) = say.and_then(|say| say.voice.as_ref()) {
+                let message = format!("{}:{}: unknown voice `{id}`", element.line, element.column);
+                return Err(TwilioError::ResponseDeser(message, s.to_string()));
+            }
+        }
+        Ok(response)
+    }
+}
+
+fn is_element(name: &str) -> bool {
+    matches!(
+        name,
+        "Response" | "Say" | "Gather" | "Pause" | "Play" | "Redirect" | "Hangup"
+    )
+}
+
 fn remove_xml_decl(xml: &str) -> String {
     XML_DECL_REGEX.replace(xml, "").to_string()
 }
@@ -77,61 +156,12 @@ impl Response {
         let xml = self.to_string();
         remove_xml_decl(&unescape_say(&xml))
     }
-
-    pub fn to_xml_pretty(&self) -> String {
-        #[cfg(not(debug_assertions))]
-        {
-            self.to_xml()
-        }
-        #[cfg(debug_assertions)]
-        {
-            let xml = self
-                .to_xml()
-                .replace('<', "\n<")
-                .replace('>', ">\n")
-                .trim()
-                .to_string();
-
-            if xml.is_empty() {
-                return xml;
-            }
-
-            let mut indent_level: usize = 0;
-            let mut result = String::new();
-
-            for chunk in xml.split('\n') {
-                let chunk = chunk.trim();
-                if chunk.is_empty() {
-                    continue;
-                }
-                if chunk.starts_with("</") {
-                    indent_level = indent_level.saturating_sub(1);
-                }
-
-                result.push_str(&" ".repeat(indent_level * 4));
-                result.push_str(chunk);
-                result.push('\n');
-
-                if chunk.starts_with("<") && !chunk.starts_with("</") && !chunk.ends_with("/>") {
-                    indent_level += 1;
-                }
-            }
-
-            result
-        }
-    }
 }
 
 impl VoicePrice for Response {
+    /// See [`CostReport`](crate::cost::CostReport) for how the price is estimated.
     fn price(&self) -> Option<PriceType> {
-        self.verbs
-            .iter()
-            .filter_map(|v| match v {
-                ResponseVerb::Say(say) => Some(say.price()),
-                ResponseVerb::Gather(gather) => Some(gather.price()),
-                _ => None,
-            })
-            .sum()
+        self.cost_report(&crate::cost::GeneratedPrices).total()
     }
 }
 
@@ -143,6 +173,17 @@ impl axum::response::IntoResponse for Response {
     }
 }
 
+#[cfg(feature = "actix")]
+impl actix_web::Responder for Response {
+    type Body = actix_web::body::BoxBody;
+
+    fn respond_to(self, _request: &actix_web::HttpRequest) -> actix_web::HttpResponse {
+        actix_web::HttpResponse::Ok()
+            .content_type("text/xml")
+            .body(self.to_xml())
+    }
+}
+
 impl ResponseBuilder<((),)> {
     pub fn say(self, say: Say) -> ResponseBuilder<((Vec<ResponseVerb>,),)> {
         self.verbs(vec![ResponseVerb::Say(say)])
@@ -209,10 +250,14 @@ impl ResponseBuilder<((Vec<ResponseVerb>,),)> {
 /// https://www.twilio.com/docs/voice/twiml/pause
 #[derive(Debug, Clone, TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Pause {
-    #[serde(rename = "@length")]
+    #[serde(default = "default_pause_length", rename = "@length")]
     pub length: u8,
 }
 
+fn default_pause_length() -> u8 {
+    1
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -502,7 +547,7 @@ mod tests {
         assert_eq!(voice.to_string(), voice_id);
 
         // test invalid id
-        let voice_err = twiml::Voice::from_str("invalid-voice-id");
+        let voice_err = twiml::Voice::from_str_strict("invalid-voice-id");
         assert!(voice_err.is_err());
     }
 
@@ -824,4 +869,78 @@ mod tests {
 
         assert_eq!(resp.price(), Some(expected_price));
     }
+
+    #[test]
+    fn test_deserialize_twilio_defaults() {
+        let xml = r#"<Response>
+            <Say>Hi</Say>
+            <Pause/>
+            <Play>https://example.com/a.mp3</Play>
+            <Gather numDigits="1"><Say>Press 1</Say></Gather>
+            <Redirect>/next</Redirect>
+        </Response>"#;
+        let resp = Response::from_str(xml).unwrap();
+
+        let expected = Response::builder()
+            .say(Say::builder().text("Hi").build())
+            .pause(Pause::builder().length(1).build())
+            .play("https://example.com/a.mp3".into())
+            .gather(
+                Gather::builder()
+                    .num_digits(1)
+                    .say(Say::builder().text("Press 1").build())
+                    .build(),
+            )
+            .redirect(Redirect::builder().url("/next".to_string()).build())
+            .build();
+        assert_eq!(resp, expected);
+    }
+
+    #[test]
+    fn test_deserialize_reports_dropped_verb() {
+        let xml = "<Response>\n  <Say>Hi</Say>\n  <Dial>+15558675310</Dial>\n</Response>";
+        let Err(TwilioError::ResponseDeser(message, _)) = Response::from_str_exact(xml) else {
+            panic!("expected a deserialization error");
+        };
+        assert_eq!(message, "3:3: invalid or unsupported <Dial> element");
+
+        let xml = r#"<Response><Say voice="Polly.Nope">Hi</Say><Hangup/></Response>"#;
+        let Err(TwilioError::ResponseDeser(message, _)) = Response::from_str_strict(xml) else {
+            panic!("expected a deserialization error");
+        };
+        assert_eq!(message, "1:11: unknown voice `Polly.Nope`");
+    }
+
+    #[test]
+    fn test_unknown_voice_roundtrip() {
+        let xml = concat!(
+            "<Response>",
+            r#"<Say voice="Polly.Nope">Hi</Say>"#,
+            r#"<Gather><Say voice="Google.xx-XX-Future-A">Press 1</Say></Gather>"#,
+            "</Response>"
+        );
+        let response = Response::from_str(xml).unwrap();
+        let ResponseVerb::Say(say) = &response.verbs[0] else {
+            panic!("expected <Say>");
+        };
+        let voice = say.voice.as_ref().unwrap();
+        assert_eq!(voice, &Voice::Other("Polly.Nope".to_string()));
+        assert_eq!(voice.price(), None);
+        assert_eq!(voice.gender(), None);
+        let xml_out = response.to_xml();
+        assert!(xml_out.contains(r#"<Say voice="Polly.Nope" loop="1">Hi</Say>"#));
+        assert!(xml_out.contains(r#"voice="Google.xx-XX-Future-A""#));
+        assert_eq!(Response::from_str(&xml_out).unwrap(), response);
+
+        let Err(TwilioError::ResponseDeser(message, _)) =
+            Response::from_str_strict(&xml.replace("Polly.Nope", "Polly.Joanna"))
+        else {
+            panic!("expected a deserialization error");
+        };
+        assert_eq!(message, "1:53: unknown voice `Google.xx-XX-Future-A`");
+        assert!(
+            Response::from_str_strict(r#"<Response><Say voice="Polly.Joanna">Hi</Say></Response>"#)
+                .is_ok()
+        );
+    }
 }
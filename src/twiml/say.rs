@@ -27,15 +27,7 @@ impl Serialize for Ssml {
     where
         S: serde::Serializer,
     {
-        #[allow(clippy::unnecessary_filter_map)]
-        let text = self
-            .0
-            .iter()
-            .filter_map(|tag| match tag {
-                Tag::Text(s) => Some(s.as_str()),
-            })
-            .collect::<String>();
-        serializer.serialize_str(&text)
+        serializer.serialize_str(&self.raw())
     }
 }
 
@@ -69,6 +61,17 @@ impl FromStr for Ssml {
 }
 
 impl Ssml {
+    /// The content as written, tags included.
+    pub(crate) fn raw(&self) -> String {
+        #[allow(clippy::unnecessary_filter_map)]
+        self.0
+            .iter()
+            .filter_map(|tag| match tag {
+                Tag::Text(s) => Some(s.as_str()),
+            })
+            .collect()
+    }
+
     fn text(&self) -> String {
         static TAG_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"<[^>]*>"#).unwrap());
         #[allow(clippy::unnecessary_filter_map)]
@@ -114,7 +117,7 @@ pub struct Say {
     #[serde(rename = "@voice")]
     #[builder(default, setter(strip_option))]
     pub voice: Option<Voice>,
-    #[serde(rename = "@loop")]
+    #[serde(default = "super::default_loop_count", rename = "@loop")]
     #[builder(default = 1)]
     pub loop_count: u32,
     #[serde(default, rename = "#text")]
@@ -141,9 +144,8 @@ impl<A, B, C> SayBuilder<(A, B, C, (Ssml,))> {
 }
 
 impl VoicePrice for Say {
+    /// See [`CostReport`](crate::cost::CostReport) for how the price is estimated.
     fn price(&self) -> Option<PriceType> {
-        let price = self.voice.and_then(|v| v.price())?;
-        let qty = crate::price_type_from_f64_ok(self.text().len().div_ceil(100) as f64)?;
-        Some(price * qty)
+        crate::cost::say_cost(self)
     }
 }
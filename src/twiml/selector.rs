@@ -0,0 +1,210 @@
+use std::cmp::Reverse;
+
+use typed_builder::TypedBuilder;
+
+use crate::PriceType;
+
+use super::voices::{Gender, Language, Provider, Voice, VoiceInfo, VoicePrice, VoiceTier};
+
+/// Picks a voice from the catalog for a language, preferred gender and budget.
+///
+/// The language is a BCP-47 tag resolved with [`Language::resolve`], so a tag whose
+/// language feature isn't compiled in still finds a related language when one is. Among the
+/// voices within budget, candidates are ranked by:
+///
+/// 1. gender: the preferred gender, then its child or adult counterpart, then any other
+/// 2. provider: in the order of `providers`, then unlisted providers
+/// 3. tier: the most expensive tier allowed, as a proxy for quality
+/// 4. id, alphabetically, so the choice is deterministic
+///
+/// ```
+/// use twilio_voice::twiml::{Gender, VoiceSelector, VoiceTier};
+///
+/// let selector = VoiceSelector::builder()
+///     .language("en_US")
+///     .gender(Gender::Female)
+///     .max_tier(VoiceTier::Neural)
+///     .build();
+/// let info = selector.select().unwrap();
+/// assert_eq!(info.gender, Gender::Female);
+/// assert_eq!(info.tier, VoiceTier::Neural);
+/// ```
+#[derive(Debug, Clone, PartialEq, TypedBuilder)]
+pub struct VoiceSelector {
+    /// BCP-47 tag, e.g. `de-CH` or `pt`
+    #[builder(setter(into))]
+    pub language: String,
+    /// Preferred, not required
+    #[builder(default, setter(strip_option))]
+    pub gender: Option<Gender>,
+    /// Most expensive tier allowed
+    #[builder(default, setter(strip_option))]
+    pub max_tier: Option<VoiceTier>,
+    /// Highest price per 100 characters allowed. Voices without a known price are skipped.
+    #[builder(default, setter(strip_option))]
+    pub max_price: Option<PriceType>,
+    /// Providers in order of preference
+    #[builder(default, setter(into))]
+    pub providers: Vec<Provider>,
+}
+
+impl VoiceSelector {
+    /// The best voice, or `None` if no compiled-in language matches or every voice is over
+    /// budget.
+    ///
+    /// When the resolved language has no voice within budget, the other regions of the same
+    /// language are tried in catalog order, e.g. `en-AU` for `en-IE`.
+    pub fn select(&self) -> Option<&'static VoiceInfo> {
+        self.languages().into_iter().find_map(|language| {
+            Voice::all()
+                .filter(|info| info.language == language && self.within_budget(info))
+                .min_by_key(|info| {
+                    (
+                        self.gender_rank(info.gender),
+                        self.provider_rank(info.provider),
+                        Reverse(info.tier),
+                        info.id,
+                    )
+                })
+        })
+    }
+
+    /// The selected voice, or the basic voice of the preferred gender when nothing in the
+    /// catalog fits.
+    pub fn voice(&self) -> Voice {
+        match self.select() {
+            Some(info) => info.voice.clone(),
+            None if matches!(self.gender, Some(Gender::Male | Gender::MaleChild)) => Voice::Man,
+            None => Voice::Woman,
+        }
+    }
+
+    /// The resolved language, followed by other compiled-in regions of the same language.
+    fn languages(&self) -> Vec<Language> {
+        let Some(resolved) = Language::resolve(&self.language) else {
+            return vec![];
+        };
+        let primary = |language: &Language| {
+            let tag = language.to_string();
+            tag.split('-').next().unwrap_or_default().to_string()
+        };
+
+        let mut languages = vec![resolved];
+        languages.extend(
+            Language::ALL.iter().filter(|language| {
+                **language != resolved && primary(language) == primary(&resolved)
+            }),
+        );
+        languages
+    }
+
+    fn within_budget(&self, info: &VoiceInfo) -> bool {
+        self.max_tier.is_none_or(|max| info.tier <= max)
+            && self
+                .max_price
+                .as_ref()
+                .is_none_or(|max| info.price().is_some_and(|price| &price <= max))
+    }
+
+    fn gender_rank(&self, gender: Gender) -> u8 {
+        let Some(preferred) = self.gender else {
+            return 0;
+        };
+        let adult = |gender| match gender {
+            Gender::FemaleChild => Gender::Female,
+            Gender::MaleChild => Gender::Male,
+            gender => gender,
+        };
+        if gender == preferred {
+            0
+        } else if adult(gender) == adult(preferred) {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn provider_rank(&self, provider: Provider) -> usize {
+        self.providers
+            .iter()
+            .position(|p| *p == provider)
+            .unwrap_or(self.providers.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select() {
+        let selector = VoiceSelector::builder()
+            .language("en-US")
+            .gender(Gender::Male)
+            .max_tier(VoiceTier::Standard)
+            .providers(vec![Provider::Polly])
+            .build();
+        let info = selector.select().unwrap();
+        assert_eq!(
+            (info.language, info.gender, info.provider, info.tier),
+            (
+                Language::EnUs,
+                Gender::Male,
+                Provider::Polly,
+                VoiceTier::Standard
+            )
+        );
+        // Ties are broken by id
+        assert_eq!(info.id, "Polly.Joey");
+        assert_eq!(selector.select(), selector.select());
+
+        let google = VoiceSelector::builder()
+            .language("en-US")
+            .providers(vec![Provider::Google, Provider::Polly])
+            .build();
+        let info = google.select().unwrap();
+        assert_eq!(info.provider, Provider::Google);
+        assert_eq!(info.tier, VoiceTier::Generative);
+    }
+
+    #[test]
+    fn test_budget() {
+        let neural = crate::price_type_from_f64_ok(crate::twiml::voices::NEURAL_VOICE_PRICE);
+        let selector = VoiceSelector::builder()
+            .language("en")
+            .max_price(neural.unwrap())
+            .build();
+        let info = selector.select().unwrap();
+        assert_eq!(info.language, Language::EnUs);
+        assert_eq!(info.tier, VoiceTier::Neural);
+    }
+
+    #[test]
+    fn test_fallbacks() {
+        // en-GB-WLS only has a male voice
+        let selector = VoiceSelector::builder()
+            .language("en-GB-WLS")
+            .gender(Gender::Female)
+            .build();
+        let info = selector.select().unwrap();
+        assert_eq!(info.id, "Polly.Geraint");
+        assert_eq!(info.gender, Gender::Male);
+
+        // en-IE only has neural voices, so another English region is used
+        let cheap = VoiceSelector::builder()
+            .language("en-IE")
+            .max_tier(VoiceTier::Standard)
+            .build();
+        assert_eq!(
+            cheap.select().map(|info| info.language),
+            Some(Language::EnAu)
+        );
+
+        let unknown = VoiceSelector::builder()
+            .language("sw-KE")
+            .gender(Gender::Male)
+            .build();
+        assert_eq!(unknown.select(), None);
+        assert_eq!(unknown.voice(), Voice::Man);
+    }
+}
@@ -0,0 +1,131 @@
+/// A TwiML element start tag found in a source document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceElement {
+    /// Element name, e.g. `Say`
+    pub name: String,
+    /// 1-based line of the start tag
+    pub line: usize,
+    /// 1-based column (in characters) of the start tag
+    pub column: usize,
+    /// Byte offset of the start tag's `<`
+    pub offset: usize,
+}
+
+/// Scan a TwiML document for its element start tags, in document order.
+///
+/// `<Say>` content is treated as opaque SSML, so SSML tags are not reported. Comments,
+/// processing instructions, CDATA sections and closing tags are skipped.
+pub fn scan_source(xml: &str) -> Vec<SourceElement> {
+    let mut elements = Vec::new();
+    let mut pos = 0;
+
+    while let Some(found) = xml[pos..].find('<') {
+        let start = pos + found;
+        let rest = &xml[start..];
+
+        let skip_to = |end: &str| {
+            rest.find(end)
+                .map(|i| start + i + end.len())
+                .unwrap_or(xml.len())
+        };
+
+        if rest.starts_with("<!--") {
+            pos = skip_to("-->");
+        } else if rest.starts_with("<![CDATA[") {
+            pos = skip_to("]]>");
+        } else if rest.starts_with("<?") {
+            pos = skip_to("?>");
+        } else if rest.starts_with("<!") || rest.starts_with("</") {
+            pos = skip_to(">");
+        } else {
+            let name_len = rest[1..]
+                .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+                .unwrap_or(rest.len() - 1);
+            let name = &rest[1..1 + name_len];
+            let tag_end = find_tag_end(xml, start + 1 + name_len);
+            let self_closing = xml[..tag_end].ends_with("/>");
+
+            let (line, column) = line_column(xml, start);
+            elements.push(SourceElement {
+                name: name.to_string(),
+                line,
+                column,
+                offset: start,
+            });
+
+            pos = if name == "Say" && !self_closing {
+                xml[tag_end..]
+                    .find("</Say>")
+                    .map(|i| tag_end + i + "</Say>".len())
+                    .unwrap_or(xml.len())
+            } else {
+                tag_end
+            };
+        }
+    }
+
+    elements
+}
+
+/// Offset just past the `>` closing a start tag, ignoring `>` inside quoted attribute values.
+fn find_tag_end(xml: &str, from: usize) -> usize {
+    let mut quote = None;
+    for (i, c) in xml[from..].char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return from + i + 1,
+            _ => {}
+        }
+    }
+    xml.len()
+}
+
+/// 1-based line and column of a byte offset.
+pub(crate) fn line_column(xml: &str, offset: usize) -> (usize, usize) {
+    let before = &xml[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(xml: &str) -> Vec<String> {
+        scan_source(xml).into_iter().map(|e| e.name).collect()
+    }
+
+    #[test]
+    fn test_scan_skips_ssml_and_markup() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- main menu -->
+<Response>
+    <Gather numDigits="1" finishOnKey=">">
+        <Say loop="1"><speak>Press <emphasis>1</emphasis> <break time="1s"/></speak></Say>
+        <Pause length="2"/>
+    </Gather>
+    <Say><![CDATA[<message>Bye</message>]]></Say>
+    <Hangup/>
+</Response>"#;
+        assert_eq!(
+            names(xml),
+            ["Response", "Gather", "Say", "Pause", "Say", "Hangup"]
+        );
+
+        let elements = scan_source(xml);
+        assert_eq!((elements[0].line, elements[0].column), (3, 1));
+        assert_eq!((elements[2].line, elements[2].column), (5, 9));
+        assert_eq!((elements[5].line, elements[5].column), (9, 5));
+    }
+
+    #[test]
+    fn test_scan_self_closing_say() {
+        assert_eq!(
+            names("<Response><Say/><Play>a.mp3</Play></Response>"),
+            ["Response", "Say", "Play"]
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use super::{
+    Gather, GatherInput, GatherVerb, Pause, Play, Redirect, Response, ResponseVerb, Say, Voice,
+};
+
+/// Maximum number of characters Twilio accepts in a single `<Say>`.
+pub const MAX_SAY_CHARS: usize = 4096;
+/// Maximum number of comma-separated entries in `<Gather hints>`.
+pub const MAX_GATHER_HINTS: usize = 500;
+/// Maximum length of a single `<Gather hints>` entry.
+pub const MAX_GATHER_HINT_CHARS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum Severity {
+    /// Valid TwiML that probably doesn't do what was intended
+    Warning,
+    /// TwiML that Twilio rejects or can't execute
+    Error,
+}
+
+/// A problem found by [`Response::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwimlIssue {
+    pub severity: Severity,
+    /// XPath-like location of the element, e.g. `/Response/Gather[1]/Say[2]`
+    pub path: String,
+    /// Index of the element in document order, `<Response>` being 0. Matches the index of
+    /// the element in [`scan_source`](super::scan_source) for documents that parsed cleanly.
+    pub element: usize,
+    pub message: String,
+}
+
+impl Response {
+    /// Check the response against the constraints Twilio enforces at call time.
+    pub fn validate(&self) -> Vec<TwimlIssue> {
+        let mut validator = Validator::default();
+        validator.response(self);
+        validator.issues
+    }
+
+    /// Element names in document order, starting with `Response`.
+    pub(crate) fn element_names(&self) -> Vec<&'static str> {
+        let mut names = vec!["Response"];
+        for verb in &self.verbs {
+            names.push(verb_name(verb));
+            if let ResponseVerb::Gather(gather) = verb {
+                names.extend(gather.verbs.iter().map(gather_verb_name));
+            }
+        }
+        names
+    }
+}
+
+pub(crate) fn verb_name(verb: &ResponseVerb) -> &'static str {
+    match verb {
+        ResponseVerb::Say(_) => "Say",
+        ResponseVerb::Gather(_) => "Gather",
+        ResponseVerb::Pause(_) => "Pause",
+        ResponseVerb::Play(_) => "Play",
+        ResponseVerb::Redirect(_) => "Redirect",
+        ResponseVerb::Hangup => "Hangup",
+    }
+}
+
+pub(crate) fn gather_verb_name(verb: &GatherVerb) -> &'static str {
+    match verb {
+        GatherVerb::Pause(_) => "Pause",
+        GatherVerb::Play(_) => "Play",
+        GatherVerb::Say(_) => "Say",
+    }
+}
+
+/// Builds `Name[n]` path segments, counting siblings with the same name.
+pub(crate) fn sibling_paths<'a>(parent: &str, names: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut counts = std::collections::HashMap::new();
+    names
+        .map(|name| {
+            let n = counts.entry(name).or_insert(0);
+            *n += 1;
+            format!("{parent}/{name}[{n}]")
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct Validator {
+    issues: Vec<TwimlIssue>,
+    element: usize,
+    path: String,
+}
+
+impl Validator {
+    fn push(&mut self, severity: Severity, message: impl Into<String>) {
+        self.issues.push(TwimlIssue {
+            severity,
+            path: self.path.clone(),
+            element: self.element,
+            message: message.into(),
+        });
+    }
+
+    fn enter(&mut self, path: String) {
+        self.element += 1;
+        self.path = path;
+    }
+
+    fn response(&mut self, response: &Response) {
+        let paths = sibling_paths("/Response", response.verbs.iter().map(verb_name));
+        let mut terminated_by = None;
+
+        for (verb, path) in response.verbs.iter().zip(paths) {
+            self.enter(path);
+            if let Some(previous) = terminated_by {
+                self.push(
+                    Severity::Warning,
+                    format!("unreachable: follows <{previous}>"),
+                );
+            }
+            match verb {
+                ResponseVerb::Say(say) => self.say(say),
+                ResponseVerb::Gather(gather) => self.gather(gather),
+                ResponseVerb::Pause(pause) => self.pause(pause),
+                ResponseVerb::Play(play) => self.play(play),
+                ResponseVerb::Redirect(redirect) => {
+                    self.redirect(redirect);
+                    terminated_by.get_or_insert("Redirect");
+                }
+                ResponseVerb::Hangup => {
+                    terminated_by.get_or_insert("Hangup");
+                }
+            }
+        }
+    }
+
+    fn say(&mut self, say: &Say) {
+        let text = say.text();
+        let chars = text.chars().count();
+        if text.trim().is_empty() {
+            self.push(Severity::Warning, "<Say> has no text");
+        } else if chars > MAX_SAY_CHARS {
+            self.push(
+                Severity::Error,
+                format!("<Say> text is {chars} characters, Twilio allows at most {MAX_SAY_CHARS}"),
+            );
+        }
+        if say.loop_count == 0 {
+            self.push(
+                Severity::Warning,
+                "<Say loop=\"0\"> repeats until the call hangs up",
+            );
+        }
+        if let Some(Voice::Other(id)) = &say.voice {
+            self.push(
+                Severity::Warning,
+                format!("<Say voice> `{id}` isn't in the catalog of the compiled-in languages"),
+            );
+        }
+    }
+
+    fn gather(&mut self, gather: &Gather) {
+        if gather.num_digits == Some(0) {
+            self.push(Severity::Error, "<Gather numDigits> must be at least 1");
+        }
+        if gather.timeout == Some(0) {
+            self.push(
+                Severity::Error,
+                "<Gather timeout> must be at least 1 second",
+            );
+        }
+        if gather.input == GatherInput::Dtmf {
+            let speech_only = [
+                ("hints", gather.hints.is_some()),
+                (
+                    "partialResultCallback",
+                    gather.partial_result_callback.is_some(),
+                ),
+                ("profanityFilter", gather.profanity_filter.is_some()),
+                ("speechModel", gather.speech_model.is_some()),
+                ("speechTimeout", gather.speech_timeout.is_some()),
+            ];
+            for (attribute, _) in speech_only.iter().filter(|(_, set)| *set) {
+                self.push(
+                    Severity::Warning,
+                    format!("<Gather {attribute}> has no effect unless input includes speech"),
+                );
+            }
+        }
+        if let Some(hints) = &gather.hints {
+            let entries = hints.split(',').map(str::trim).collect::<Vec<_>>();
+            if entries.len() > MAX_GATHER_HINTS {
+                self.push(
+                    Severity::Error,
+                    format!(
+                        "<Gather hints> has {} entries, Twilio allows at most {MAX_GATHER_HINTS}",
+                        entries.len()
+                    ),
+                );
+            }
+            if let Some(entry) = entries
+                .iter()
+                .find(|e| e.chars().count() > MAX_GATHER_HINT_CHARS)
+            {
+                self.push(
+                    Severity::Error,
+                    format!(
+                        "<Gather hints> entry \"{entry}\" is longer than {MAX_GATHER_HINT_CHARS} characters"
+                    ),
+                );
+            }
+        }
+
+        let parent = self.path.clone();
+        let paths = sibling_paths(&parent, gather.verbs.iter().map(gather_verb_name));
+        for (verb, path) in gather.verbs.iter().zip(paths) {
+            self.enter(path);
+            match verb {
+                GatherVerb::Pause(pause) => self.pause(pause),
+                GatherVerb::Play(play) => self.play(play),
+                GatherVerb::Say(say) => self.say(say),
+            }
+        }
+    }
+
+    fn pause(&mut self, pause: &Pause) {
+        if pause.length == 0 {
+            self.push(Severity::Warning, "<Pause length=\"0\"> has no effect");
+        }
+    }
+
+    fn play(&mut self, play: &Play) {
+        let has_url = play.url.as_ref().is_some_and(|url| !url.trim().is_empty());
+        match (has_url, &play.digits) {
+            (false, None) => self.push(Severity::Error, "<Play> needs a URL or digits"),
+            (true, Some(_)) => {
+                self.push(Severity::Error, "<Play> can't have both a URL and digits")
+            }
+            _ => {}
+        }
+        if play.loop_count == 0 {
+            self.push(
+                Severity::Warning,
+                "<Play loop=\"0\"> repeats until the call hangs up",
+            );
+        }
+    }
+
+    fn redirect(&mut self, redirect: &Redirect) {
+        if redirect.url.trim().is_empty() {
+            self.push(Severity::Error, "<Redirect> needs a URL");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn issues(xml: &str) -> Vec<(Severity, String, usize)> {
+        Response::from_str(xml)
+            .unwrap()
+            .validate()
+            .into_iter()
+            .map(|i| (i.severity, i.path, i.element))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_response() {
+        let xml = r#"<Response>
+            <Gather numDigits="1" action="/menu">
+                <Say>Press 1 for sales.</Say>
+                <Pause length="2"/>
+            </Gather>
+            <Redirect>/menu</Redirect>
+        </Response>"#;
+        assert!(issues(xml).is_empty());
+    }
+
+    #[test]
+    fn test_issue_paths() {
+        let xml = r#"<Response>
+            <Say>Hi</Say>
+            <Gather numDigits="0" action="/menu">
+                <Say>One</Say>
+                <Say loop="0">Two</Say>
+            </Gather>
+            <Play/>
+            <Hangup/>
+            <Say>Unreachable</Say>
+        </Response>"#;
+        assert_eq!(
+            issues(xml),
+            [
+                (Severity::Error, "/Response/Gather[1]".into(), 2),
+                (Severity::Warning, "/Response/Gather[1]/Say[2]".into(), 4),
+                (Severity::Error, "/Response/Play[1]".into(), 5),
+                (Severity::Warning, "/Response/Say[2]".into(), 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_speech_attributes_with_dtmf_input() {
+        let xml = r#"<Response><Gather action="/a" speechTimeout="auto" hints="yes,no"><Say>Yes or no?</Say></Gather></Response>"#;
+        let response = Response::from_str(xml).unwrap();
+        let messages = response
+            .validate()
+            .into_iter()
+            .map(|i| i.message)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "<Gather hints> has no effect unless input includes speech",
+                "<Gather speechTimeout> has no effect unless input includes speech",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_say_length_counts_characters() {
+        let xml = format!(
+            "<Response><Say>{}</Say></Response>",
+            "é".repeat(MAX_SAY_CHARS)
+        );
+        assert!(issues(&xml).is_empty());
+
+        let xml = format!(
+            "<Response><Say>{}</Say></Response>",
+            "é".repeat(MAX_SAY_CHARS + 1)
+        );
+        assert_eq!(
+            issues(&xml),
+            [(Severity::Error, "/Response/Say[1]".into(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_unknown_voice() {
+        let xml = r#"<Response><Say voice="Polly.Nope">Hi</Say></Response>"#;
+        let messages = Response::from_str(xml)
+            .unwrap()
+            .validate()
+            .into_iter()
+            .map(|i| (i.severity, i.message))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [(
+                Severity::Warning,
+                "<Say voice> `Polly.Nope` isn't in the catalog of the compiled-in languages".into()
+            )]
+        );
+    }
+}
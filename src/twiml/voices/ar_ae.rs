@@ -2,7 +2,10 @@
 
 use crate::{
     PriceType,
-    twiml::{Gender, VoiceGender, VoicePrice, voices::NEURAL_VOICE_PRICE},
+    twiml::{
+        Gender, VoiceGender, VoicePrice,
+        voices::{Language, NEURAL_VOICE_PRICE, Provider, VoiceInfo, VoiceTier},
+    },
 };
 
 use serde::{Deserialize, Serialize};
@@ -15,56 +18,56 @@ pub mod neural {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Zayd-Neural")]
-            #[strum(to_string = "Polly.Zayd-Neural")]
-            ZaydNeural,
+        pub enum Female {
+            #[serde(rename = "Polly.Hala-Neural")]
+            #[strum(to_string = "Polly.Hala-Neural")]
+            HalaNeural,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
                 Self::ArAe(super::super::Voice::Neural(super::Voice::Polly(
-                    Voice::Male(value),
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Hala-Neural")]
-            #[strum(to_string = "Polly.Hala-Neural")]
-            HalaNeural,
+        pub enum Male {
+            #[serde(rename = "Polly.Zayd-Neural")]
+            #[strum(to_string = "Polly.Zayd-Neural")]
+            ZaydNeural,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
                 Self::ArAe(super::super::Voice::Neural(super::Voice::Polly(
-                    Voice::Female(value),
+                    Voice::Male(value),
                 )))
             }
         }
@@ -72,8 +75,8 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -85,8 +88,8 @@ pub mod neural {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -133,3 +136,27 @@ impl VoiceGender for Voice {
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArAe(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::HalaNeural),
+        ))),
+        id: "Polly.Hala-Neural",
+        language: Language::ArAe,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArAe(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Male(neural::polly::Male::ZaydNeural),
+        ))),
+        id: "Polly.Zayd-Neural",
+        language: Language::ArAe,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+];
@@ -4,13 +4,16 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-pub mod standard {
+pub mod generative {
     use super::*;
 
     pub mod google {
@@ -18,62 +21,74 @@ pub mod standard {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.ar-XA-Standard-B")]
-            #[strum(to_string = "Google.ar-XA-Standard-B")]
-            StandardB,
-            #[serde(rename = "Google.ar-XA-Standard-C")]
-            #[strum(to_string = "Google.ar-XA-Standard-C")]
-            StandardC,
+        pub enum Female {
+            #[serde(rename = "Google.ar-XA-Chirp3-HD-Aoede")]
+            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Aoede")]
+            Chirp3HdAoede,
+            #[serde(rename = "Google.ar-XA-Chirp3-HD-Kore")]
+            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Kore")]
+            Chirp3HdKore,
+            #[serde(rename = "Google.ar-XA-Chirp3-HD-Leda")]
+            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Leda")]
+            Chirp3HdLeda,
+            #[serde(rename = "Google.ar-XA-Chirp3-HD-Zephyr")]
+            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Zephyr")]
+            Chirp3HdZephyr,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::ArXa(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::ArXa(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.ar-XA-Standard-A")]
-            #[strum(to_string = "Google.ar-XA-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.ar-XA-Standard-D")]
-            #[strum(to_string = "Google.ar-XA-Standard-D")]
-            StandardD,
+        pub enum Male {
+            #[serde(rename = "Google.ar-XA-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.ar-XA-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.ar-XA-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.ar-XA-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::ArXa(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::ArXa(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -81,21 +96,21 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -109,7 +124,7 @@ pub mod standard {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
         }
     }
 
@@ -122,7 +137,7 @@ pub mod standard {
     }
 }
 
-pub mod generative {
+pub mod neural {
     use super::*;
 
     pub mod google {
@@ -131,23 +146,17 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.ar-XA-Chirp3-HD-Aoede")]
-            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Aoede")]
-            Chirp3HdAoede,
-            #[serde(rename = "Google.ar-XA-Chirp3-HD-Kore")]
-            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Kore")]
-            Chirp3HdKore,
-            #[serde(rename = "Google.ar-XA-Chirp3-HD-Leda")]
-            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Leda")]
-            Chirp3HdLeda,
-            #[serde(rename = "Google.ar-XA-Chirp3-HD-Zephyr")]
-            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Zephyr")]
-            Chirp3HdZephyr,
+            #[serde(rename = "Google.ar-XA-Wavenet-A")]
+            #[strum(to_string = "Google.ar-XA-Wavenet-A")]
+            WavenetA,
+            #[serde(rename = "Google.ar-XA-Wavenet-D")]
+            #[strum(to_string = "Google.ar-XA-Wavenet-D")]
+            WavenetD,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -159,7 +168,7 @@ pub mod generative {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::ArXa(super::super::Voice::Generative(super::Voice::Google(
+                Self::ArXa(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -168,23 +177,17 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.ar-XA-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.ar-XA-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.ar-XA-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.ar-XA-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.ar-XA-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
+            #[serde(rename = "Google.ar-XA-Wavenet-B")]
+            #[strum(to_string = "Google.ar-XA-Wavenet-B")]
+            WavenetB,
+            #[serde(rename = "Google.ar-XA-Wavenet-C")]
+            #[strum(to_string = "Google.ar-XA-Wavenet-C")]
+            WavenetC,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -196,7 +199,7 @@ pub mod generative {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::ArXa(super::super::Voice::Generative(super::Voice::Google(
+                Self::ArXa(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -211,7 +214,7 @@ pub mod generative {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -233,7 +236,7 @@ pub mod generative {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
@@ -246,7 +249,7 @@ pub mod generative {
     }
 }
 
-pub mod neural {
+pub mod standard {
     use super::*;
 
     pub mod google {
@@ -254,62 +257,62 @@ pub mod neural {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.ar-XA-Wavenet-B")]
-            #[strum(to_string = "Google.ar-XA-Wavenet-B")]
-            WavenetB,
-            #[serde(rename = "Google.ar-XA-Wavenet-C")]
-            #[strum(to_string = "Google.ar-XA-Wavenet-C")]
-            WavenetC,
+        pub enum Female {
+            #[serde(rename = "Google.ar-XA-Standard-A")]
+            #[strum(to_string = "Google.ar-XA-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.ar-XA-Standard-D")]
+            #[strum(to_string = "Google.ar-XA-Standard-D")]
+            StandardD,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::ArXa(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::ArXa(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.ar-XA-Wavenet-A")]
-            #[strum(to_string = "Google.ar-XA-Wavenet-A")]
-            WavenetA,
-            #[serde(rename = "Google.ar-XA-Wavenet-D")]
-            #[strum(to_string = "Google.ar-XA-Wavenet-D")]
-            WavenetD,
+        pub enum Male {
+            #[serde(rename = "Google.ar-XA-Standard-B")]
+            #[strum(to_string = "Google.ar-XA-Standard-B")]
+            StandardB,
+            #[serde(rename = "Google.ar-XA-Standard-C")]
+            #[strum(to_string = "Google.ar-XA-Standard-C")]
+            StandardC,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::ArXa(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::ArXa(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -317,21 +320,21 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -345,7 +348,7 @@ pub mod neural {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
@@ -361,16 +364,16 @@ pub mod neural {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Voice {
-    Standard(standard::Voice),
     Generative(generative::Voice),
     Neural(neural::Voice),
+    Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
-            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
             Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
 }
@@ -378,9 +381,173 @@ impl VoicePrice for Voice {
 impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
-            Voice::Standard(voice) => voice.gender(),
             Voice::Generative(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
+            Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.ar-XA-Chirp3-HD-Aoede",
+        language: Language::ArXa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.ar-XA-Chirp3-HD-Kore",
+        language: Language::ArXa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.ar-XA-Chirp3-HD-Leda",
+        language: Language::ArXa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.ar-XA-Chirp3-HD-Zephyr",
+        language: Language::ArXa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.ar-XA-Chirp3-HD-Charon",
+        language: Language::ArXa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.ar-XA-Chirp3-HD-Fenrir",
+        language: Language::ArXa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.ar-XA-Chirp3-HD-Orus",
+        language: Language::ArXa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.ar-XA-Chirp3-HD-Puck",
+        language: Language::ArXa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.ar-XA-Wavenet-A",
+        language: Language::ArXa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetD),
+        ))),
+        id: "Google.ar-XA-Wavenet-D",
+        language: Language::ArXa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.ar-XA-Wavenet-B",
+        language: Language::ArXa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetC),
+        ))),
+        id: "Google.ar-XA-Wavenet-C",
+        language: Language::ArXa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.ar-XA-Standard-A",
+        language: Language::ArXa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardD),
+        ))),
+        id: "Google.ar-XA-Standard-D",
+        language: Language::ArXa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.ar-XA-Standard-B",
+        language: Language::ArXa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ArXa(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardC),
+        ))),
+        id: "Google.ar-XA-Standard-C",
+        language: Language::ArXa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+];
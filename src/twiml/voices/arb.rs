@@ -2,7 +2,10 @@
 
 use crate::{
     PriceType,
-    twiml::{Gender, VoiceGender, VoicePrice, voices::STANDARD_VOICE_PRICE},
+    twiml::{
+        Gender, VoiceGender, VoicePrice,
+        voices::{Language, Provider, STANDARD_VOICE_PRICE, VoiceInfo, VoiceTier},
+    },
 };
 
 use serde::{Deserialize, Serialize};
@@ -103,3 +106,15 @@ impl VoiceGender for Voice {
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[VoiceInfo {
+    voice: crate::twiml::Voice::Arb(Voice::Standard(standard::Voice::Polly(
+        standard::polly::Voice::Female(standard::polly::Female::Zeina),
+    ))),
+    id: "Polly.Zeina",
+    language: Language::Arb,
+    gender: Gender::Female,
+    provider: Provider::Polly,
+    tier: VoiceTier::Standard,
+}];
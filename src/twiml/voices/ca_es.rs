@@ -4,29 +4,31 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE, VoiceInfo, VoiceTier,
+        },
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-pub mod standard {
+pub mod neural {
     use super::*;
 
-    pub mod google {
+    pub mod polly {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.ca-ES-Standard-B")]
-            #[strum(to_string = "Google.ca-ES-Standard-B")]
-            StandardB,
+            #[serde(rename = "Polly.Arlet-Neural")]
+            #[strum(to_string = "Polly.Arlet-Neural")]
+            ArletNeural,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -38,7 +40,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::CaEs(super::super::Voice::Standard(super::Voice::Google(
+                Self::CaEs(super::super::Voice::Neural(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -52,7 +54,7 @@ pub mod standard {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -68,41 +70,41 @@ pub mod standard {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
 }
 
-pub mod neural {
+pub mod standard {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Arlet-Neural")]
-            #[strum(to_string = "Polly.Arlet-Neural")]
-            ArletNeural,
+            #[serde(rename = "Google.ca-ES-Standard-B")]
+            #[strum(to_string = "Google.ca-ES-Standard-B")]
+            StandardB,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -114,7 +116,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::CaEs(super::super::Voice::Neural(super::Voice::Polly(
+                Self::CaEs(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -128,7 +130,7 @@ pub mod neural {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -144,19 +146,19 @@ pub mod neural {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
+        Google(google::Voice),
     }
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
+                Voice::Google(voice) => voice.gender(),
             }
         }
     }
@@ -165,14 +167,14 @@ pub mod neural {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Voice {
-    Standard(standard::Voice),
     Neural(neural::Voice),
+    Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
-            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
 }
@@ -180,8 +182,32 @@ impl VoicePrice for Voice {
 impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
-            Voice::Standard(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
+            Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::CaEs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::ArletNeural),
+        ))),
+        id: "Polly.Arlet-Neural",
+        language: Language::CaEs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CaEs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardB),
+        ))),
+        id: "Google.ca-ES-Standard-B",
+        language: Language::CaEs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+];
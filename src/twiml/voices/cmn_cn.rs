@@ -4,7 +4,10 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
@@ -134,23 +137,26 @@ pub mod generative {
     }
 }
 
-pub mod standard {
+pub mod neural {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Zhiyu")]
-            #[strum(to_string = "Polly.Zhiyu")]
-            Zhiyu,
+            #[serde(rename = "Google.cmn-CN-Wavenet-A")]
+            #[strum(to_string = "Google.cmn-CN-Wavenet-A")]
+            WavenetA,
+            #[serde(rename = "Google.cmn-CN-Wavenet-D")]
+            #[strum(to_string = "Google.cmn-CN-Wavenet-D")]
+            WavenetD,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -162,21 +168,53 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::CmnCn(super::super::Voice::Standard(super::Voice::Polly(
+                Self::CmnCn(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
         }
 
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum Male {
+            #[serde(rename = "Google.cmn-CN-Wavenet-B")]
+            #[strum(to_string = "Google.cmn-CN-Wavenet-B")]
+            WavenetB,
+            #[serde(rename = "Google.cmn-CN-Wavenet-C")]
+            #[strum(to_string = "Google.cmn-CN-Wavenet-C")]
+            WavenetC,
+        }
+
+        impl VoicePrice for Male {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Male {
+            fn gender(&self) -> Gender {
+                Gender::Male
+            }
+        }
+
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::CmnCn(super::super::Voice::Neural(super::Voice::Google(
+                    Voice::Male(value),
+                )))
+            }
+        }
+
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -184,28 +222,26 @@ pub mod standard {
             fn gender(&self) -> Gender {
                 match self {
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
     }
 
-    pub mod google {
+    pub mod polly {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.cmn-CN-Standard-A")]
-            #[strum(to_string = "Google.cmn-CN-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.cmn-CN-Standard-D")]
-            #[strum(to_string = "Google.cmn-CN-Standard-D")]
-            StandardD,
+            #[serde(rename = "Polly.Zhiyu-Neural")]
+            #[strum(to_string = "Polly.Zhiyu-Neural")]
+            ZhiyuNeural,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -217,53 +253,21 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::CmnCn(super::super::Voice::Standard(super::Voice::Google(
+                Self::CmnCn(super::super::Voice::Neural(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
         }
 
-        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
-        #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.cmn-CN-Standard-B")]
-            #[strum(to_string = "Google.cmn-CN-Standard-B")]
-            StandardB,
-            #[serde(rename = "Google.cmn-CN-Standard-C")]
-            #[strum(to_string = "Google.cmn-CN-Standard-C")]
-            StandardC,
-        }
-
-        impl VoicePrice for Male {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Male {
-            fn gender(&self) -> Gender {
-                Gender::Male
-            }
-        }
-
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::CmnCn(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Male(value),
-                )))
-            }
-        }
-
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
             Female(Female),
-            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -271,7 +275,6 @@ pub mod standard {
             fn gender(&self) -> Gender {
                 match self {
                     Voice::Female(_) => Gender::Female,
-                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -280,27 +283,27 @@ pub mod standard {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
 }
 
-pub mod neural {
+pub mod standard {
     use super::*;
 
     pub mod google {
@@ -308,62 +311,62 @@ pub mod neural {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.cmn-CN-Wavenet-B")]
-            #[strum(to_string = "Google.cmn-CN-Wavenet-B")]
-            WavenetB,
-            #[serde(rename = "Google.cmn-CN-Wavenet-C")]
-            #[strum(to_string = "Google.cmn-CN-Wavenet-C")]
-            WavenetC,
+        pub enum Female {
+            #[serde(rename = "Google.cmn-CN-Standard-A")]
+            #[strum(to_string = "Google.cmn-CN-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.cmn-CN-Standard-D")]
+            #[strum(to_string = "Google.cmn-CN-Standard-D")]
+            StandardD,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::CmnCn(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::CmnCn(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.cmn-CN-Wavenet-A")]
-            #[strum(to_string = "Google.cmn-CN-Wavenet-A")]
-            WavenetA,
-            #[serde(rename = "Google.cmn-CN-Wavenet-D")]
-            #[strum(to_string = "Google.cmn-CN-Wavenet-D")]
-            WavenetD,
+        pub enum Male {
+            #[serde(rename = "Google.cmn-CN-Standard-B")]
+            #[strum(to_string = "Google.cmn-CN-Standard-B")]
+            StandardB,
+            #[serde(rename = "Google.cmn-CN-Standard-C")]
+            #[strum(to_string = "Google.cmn-CN-Standard-C")]
+            StandardC,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::CmnCn(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::CmnCn(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -371,21 +374,21 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -397,14 +400,14 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Zhiyu-Neural")]
-            #[strum(to_string = "Polly.Zhiyu-Neural")]
-            ZhiyuNeural,
+            #[serde(rename = "Polly.Zhiyu")]
+            #[strum(to_string = "Polly.Zhiyu")]
+            Zhiyu,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -416,7 +419,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::CmnCn(super::super::Voice::Neural(super::Voice::Polly(
+                Self::CmnCn(super::super::Voice::Standard(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -430,7 +433,7 @@ pub mod neural {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -452,7 +455,7 @@ pub mod neural {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
@@ -470,15 +473,15 @@ pub mod neural {
 #[serde(untagged)]
 pub enum Voice {
     Generative(generative::Voice),
-    Standard(standard::Voice),
     Neural(neural::Voice),
+    Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
             Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
-            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
 }
@@ -487,8 +490,192 @@ impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
             Voice::Generative(voice) => voice.gender(),
-            Voice::Standard(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
+            Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.cmn-CN-Chirp3-HD-Aoede",
+        language: Language::CmnCn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.cmn-CN-Chirp3-HD-Kore",
+        language: Language::CmnCn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.cmn-CN-Chirp3-HD-Leda",
+        language: Language::CmnCn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.cmn-CN-Chirp3-HD-Zephyr",
+        language: Language::CmnCn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.cmn-CN-Chirp3-HD-Charon",
+        language: Language::CmnCn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.cmn-CN-Chirp3-HD-Fenrir",
+        language: Language::CmnCn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.cmn-CN-Chirp3-HD-Orus",
+        language: Language::CmnCn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.cmn-CN-Chirp3-HD-Puck",
+        language: Language::CmnCn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.cmn-CN-Wavenet-A",
+        language: Language::CmnCn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetD),
+        ))),
+        id: "Google.cmn-CN-Wavenet-D",
+        language: Language::CmnCn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.cmn-CN-Wavenet-B",
+        language: Language::CmnCn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetC),
+        ))),
+        id: "Google.cmn-CN-Wavenet-C",
+        language: Language::CmnCn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::ZhiyuNeural),
+        ))),
+        id: "Polly.Zhiyu-Neural",
+        language: Language::CmnCn,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.cmn-CN-Standard-A",
+        language: Language::CmnCn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardD),
+        ))),
+        id: "Google.cmn-CN-Standard-D",
+        language: Language::CmnCn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.cmn-CN-Standard-B",
+        language: Language::CmnCn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardC),
+        ))),
+        id: "Google.cmn-CN-Standard-C",
+        language: Language::CmnCn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnCn(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Zhiyu),
+        ))),
+        id: "Polly.Zhiyu",
+        language: Language::CmnCn,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
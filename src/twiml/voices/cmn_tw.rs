@@ -4,7 +4,9 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE, VoiceInfo, VoiceTier,
+        },
     },
 };
 
@@ -251,3 +253,67 @@ impl VoiceGender for Voice {
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnTw(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.cmn-TW-Wavenet-A",
+        language: Language::CmnTw,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnTw(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.cmn-TW-Wavenet-B",
+        language: Language::CmnTw,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnTw(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetC),
+        ))),
+        id: "Google.cmn-TW-Wavenet-C",
+        language: Language::CmnTw,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnTw(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.cmn-TW-Standard-A",
+        language: Language::CmnTw,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnTw(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.cmn-TW-Standard-B",
+        language: Language::CmnTw,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CmnTw(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardC),
+        ))),
+        id: "Google.cmn-TW-Standard-C",
+        language: Language::CmnTw,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+];
@@ -4,7 +4,9 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE, VoiceInfo, VoiceTier,
+        },
     },
 };
 
@@ -185,3 +187,27 @@ impl VoiceGender for Voice {
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::CsCz(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetB),
+        ))),
+        id: "Google.cs-CZ-Wavenet-B",
+        language: Language::CsCz,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::CsCz(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardB),
+        ))),
+        id: "Google.cs-CZ-Standard-B",
+        language: Language::CsCz,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+];
@@ -4,7 +4,9 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE, VoiceInfo, VoiceTier,
+        },
     },
 };
 
@@ -173,61 +175,61 @@ pub mod neural {
 pub mod standard {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Mads")]
-            #[strum(to_string = "Polly.Mads")]
-            Mads,
+        pub enum Female {
+            #[serde(rename = "Google.da-DK-Standard-F")]
+            #[strum(to_string = "Google.da-DK-Standard-F")]
+            StandardF,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::DaDk(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::DaDk(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Naja")]
-            #[strum(to_string = "Polly.Naja")]
-            Naja,
+        pub enum Male {
+            #[serde(rename = "Google.da-DK-Standard-G")]
+            #[strum(to_string = "Google.da-DK-Standard-G")]
+            StandardG,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::DaDk(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::DaDk(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -235,8 +237,8 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -248,22 +250,22 @@ pub mod standard {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
     }
 
-    pub mod google {
+    pub mod polly {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.da-DK-Standard-F")]
-            #[strum(to_string = "Google.da-DK-Standard-F")]
-            StandardF,
+            #[serde(rename = "Polly.Naja")]
+            #[strum(to_string = "Polly.Naja")]
+            Naja,
         }
 
         impl VoicePrice for Female {
@@ -280,7 +282,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::DaDk(super::super::Voice::Standard(super::Voice::Google(
+                Self::DaDk(super::super::Voice::Standard(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -289,9 +291,9 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.da-DK-Standard-G")]
-            #[strum(to_string = "Google.da-DK-Standard-G")]
-            StandardG,
+            #[serde(rename = "Polly.Mads")]
+            #[strum(to_string = "Polly.Mads")]
+            Mads,
         }
 
         impl VoicePrice for Male {
@@ -308,7 +310,7 @@ pub mod standard {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::DaDk(super::super::Voice::Standard(super::Voice::Google(
+                Self::DaDk(super::super::Voice::Standard(super::Voice::Polly(
                     Voice::Male(value),
                 )))
             }
@@ -340,8 +342,8 @@ pub mod standard {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
@@ -353,8 +355,8 @@ pub mod standard {
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
@@ -383,3 +385,77 @@ impl VoiceGender for Voice {
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::DaDk(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetF),
+        ))),
+        id: "Google.da-DK-Wavenet-F",
+        language: Language::DaDk,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DaDk(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetG),
+        ))),
+        id: "Google.da-DK-Wavenet-G",
+        language: Language::DaDk,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DaDk(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::SofieNeural),
+        ))),
+        id: "Polly.Sofie-Neural",
+        language: Language::DaDk,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DaDk(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardF),
+        ))),
+        id: "Google.da-DK-Standard-F",
+        language: Language::DaDk,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DaDk(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardG),
+        ))),
+        id: "Google.da-DK-Standard-G",
+        language: Language::DaDk,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DaDk(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Naja),
+        ))),
+        id: "Polly.Naja",
+        language: Language::DaDk,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DaDk(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Male(standard::polly::Male::Mads),
+        ))),
+        id: "Polly.Mads",
+        language: Language::DaDk,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
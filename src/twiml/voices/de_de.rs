@@ -4,7 +4,10 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
@@ -221,15 +224,18 @@ pub mod generative {
 pub mod neural {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Vicki-Neural")]
-            #[strum(to_string = "Polly.Vicki-Neural")]
-            VickiNeural,
+            #[serde(rename = "Google.de-DE-Neural2-G")]
+            #[strum(to_string = "Google.de-DE-Neural2-G")]
+            Neural2G,
+            #[serde(rename = "Google.de-DE-Wavenet-G")]
+            #[strum(to_string = "Google.de-DE-Wavenet-G")]
+            WavenetG,
         }
 
         impl VoicePrice for Female {
@@ -246,7 +252,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::DeDe(super::super::Voice::Neural(super::Voice::Polly(
+                Self::DeDe(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -255,9 +261,12 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Polly.Daniel-Neural")]
-            #[strum(to_string = "Polly.Daniel-Neural")]
-            DanielNeural,
+            #[serde(rename = "Google.de-DE-Neural2-H")]
+            #[strum(to_string = "Google.de-DE-Neural2-H")]
+            Neural2H,
+            #[serde(rename = "Google.de-DE-Wavenet-H")]
+            #[strum(to_string = "Google.de-DE-Wavenet-H")]
+            WavenetH,
         }
 
         impl VoicePrice for Male {
@@ -274,7 +283,7 @@ pub mod neural {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::DeDe(super::super::Voice::Neural(super::Voice::Polly(
+                Self::DeDe(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -303,18 +312,15 @@ pub mod neural {
         }
     }
 
-    pub mod google {
+    pub mod polly {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.de-DE-Neural2-G")]
-            #[strum(to_string = "Google.de-DE-Neural2-G")]
-            Neural2G,
-            #[serde(rename = "Google.de-DE-Wavenet-G")]
-            #[strum(to_string = "Google.de-DE-Wavenet-G")]
-            WavenetG,
+            #[serde(rename = "Polly.Vicki-Neural")]
+            #[strum(to_string = "Polly.Vicki-Neural")]
+            VickiNeural,
         }
 
         impl VoicePrice for Female {
@@ -331,7 +337,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::DeDe(super::super::Voice::Neural(super::Voice::Google(
+                Self::DeDe(super::super::Voice::Neural(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -340,12 +346,9 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.de-DE-Neural2-H")]
-            #[strum(to_string = "Google.de-DE-Neural2-H")]
-            Neural2H,
-            #[serde(rename = "Google.de-DE-Wavenet-H")]
-            #[strum(to_string = "Google.de-DE-Wavenet-H")]
-            WavenetH,
+            #[serde(rename = "Polly.Daniel-Neural")]
+            #[strum(to_string = "Polly.Daniel-Neural")]
+            DanielNeural,
         }
 
         impl VoicePrice for Male {
@@ -362,7 +365,7 @@ pub mod neural {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::DeDe(super::super::Voice::Neural(super::Voice::Google(
+                Self::DeDe(super::super::Voice::Neural(super::Voice::Polly(
                     Voice::Male(value),
                 )))
             }
@@ -394,8 +397,8 @@ pub mod neural {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
@@ -407,8 +410,8 @@ pub mod neural {
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
@@ -417,18 +420,15 @@ pub mod neural {
 pub mod standard {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Marlene")]
-            #[strum(to_string = "Polly.Marlene")]
-            Marlene,
-            #[serde(rename = "Polly.Vicki")]
-            #[strum(to_string = "Polly.Vicki")]
-            Vicki,
+            #[serde(rename = "Google.de-DE-Standard-G")]
+            #[strum(to_string = "Google.de-DE-Standard-G")]
+            StandardG,
         }
 
         impl VoicePrice for Female {
@@ -445,7 +445,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::DeDe(super::super::Voice::Standard(super::Voice::Polly(
+                Self::DeDe(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -454,9 +454,9 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Polly.Hans")]
-            #[strum(to_string = "Polly.Hans")]
-            Hans,
+            #[serde(rename = "Google.de-DE-Standard-H")]
+            #[strum(to_string = "Google.de-DE-Standard-H")]
+            StandardH,
         }
 
         impl VoicePrice for Male {
@@ -473,7 +473,7 @@ pub mod standard {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::DeDe(super::super::Voice::Standard(super::Voice::Polly(
+                Self::DeDe(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -502,61 +502,64 @@ pub mod standard {
         }
     }
 
-    pub mod google {
+    pub mod polly {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.de-DE-Standard-H")]
-            #[strum(to_string = "Google.de-DE-Standard-H")]
-            StandardH,
+        pub enum Female {
+            #[serde(rename = "Polly.Marlene")]
+            #[strum(to_string = "Polly.Marlene")]
+            Marlene,
+            #[serde(rename = "Polly.Vicki")]
+            #[strum(to_string = "Polly.Vicki")]
+            Vicki,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::DeDe(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::DeDe(super::super::Voice::Standard(super::Voice::Polly(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.de-DE-Standard-G")]
-            #[strum(to_string = "Google.de-DE-Standard-G")]
-            StandardG,
+        pub enum Male {
+            #[serde(rename = "Polly.Hans")]
+            #[strum(to_string = "Polly.Hans")]
+            Hans,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::DeDe(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::DeDe(super::super::Voice::Standard(super::Voice::Polly(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -564,8 +567,8 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -577,8 +580,8 @@ pub mod standard {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -587,8 +590,8 @@ pub mod standard {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
@@ -600,8 +603,8 @@ pub mod standard {
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
@@ -633,3 +636,217 @@ impl VoiceGender for Voice {
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.de-DE-Chirp3-HD-Aoede",
+        language: Language::DeDe,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.de-DE-Chirp3-HD-Kore",
+        language: Language::DeDe,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.de-DE-Chirp3-HD-Leda",
+        language: Language::DeDe,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.de-DE-Chirp3-HD-Zephyr",
+        language: Language::DeDe,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.de-DE-Chirp3-HD-Charon",
+        language: Language::DeDe,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.de-DE-Chirp3-HD-Fenrir",
+        language: Language::DeDe,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.de-DE-Chirp3-HD-Orus",
+        language: Language::DeDe,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.de-DE-Chirp3-HD-Puck",
+        language: Language::DeDe,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Female(generative::polly::Female::VickiGenerative),
+        ))),
+        id: "Polly.Vicki-Generative",
+        language: Language::DeDe,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Male(generative::polly::Male::DanielGenerative),
+        ))),
+        id: "Polly.Daniel-Generative",
+        language: Language::DeDe,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2G),
+        ))),
+        id: "Google.de-DE-Neural2-G",
+        language: Language::DeDe,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetG),
+        ))),
+        id: "Google.de-DE-Wavenet-G",
+        language: Language::DeDe,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2H),
+        ))),
+        id: "Google.de-DE-Neural2-H",
+        language: Language::DeDe,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetH),
+        ))),
+        id: "Google.de-DE-Wavenet-H",
+        language: Language::DeDe,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::VickiNeural),
+        ))),
+        id: "Polly.Vicki-Neural",
+        language: Language::DeDe,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Male(neural::polly::Male::DanielNeural),
+        ))),
+        id: "Polly.Daniel-Neural",
+        language: Language::DeDe,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardG),
+        ))),
+        id: "Google.de-DE-Standard-G",
+        language: Language::DeDe,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardH),
+        ))),
+        id: "Google.de-DE-Standard-H",
+        language: Language::DeDe,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Marlene),
+        ))),
+        id: "Polly.Marlene",
+        language: Language::DeDe,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Vicki),
+        ))),
+        id: "Polly.Vicki",
+        language: Language::DeDe,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::DeDe(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Male(standard::polly::Male::Hans),
+        ))),
+        id: "Polly.Hans",
+        language: Language::DeDe,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
@@ -4,13 +4,15 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE, VoiceInfo, VoiceTier,
+        },
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-pub mod standard {
+pub mod neural {
     use super::*;
 
     pub mod google {
@@ -19,14 +21,14 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.el-GR-Standard-B")]
-            #[strum(to_string = "Google.el-GR-Standard-B")]
-            StandardB,
+            #[serde(rename = "Google.el-GR-Wavenet-B")]
+            #[strum(to_string = "Google.el-GR-Wavenet-B")]
+            WavenetB,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -38,7 +40,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::ElGr(super::super::Voice::Standard(super::Voice::Google(
+                Self::ElGr(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -52,7 +54,7 @@ pub mod standard {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -73,7 +75,7 @@ pub mod standard {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
@@ -86,7 +88,7 @@ pub mod standard {
     }
 }
 
-pub mod neural {
+pub mod standard {
     use super::*;
 
     pub mod google {
@@ -95,14 +97,14 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.el-GR-Wavenet-B")]
-            #[strum(to_string = "Google.el-GR-Wavenet-B")]
-            WavenetB,
+            #[serde(rename = "Google.el-GR-Standard-B")]
+            #[strum(to_string = "Google.el-GR-Standard-B")]
+            StandardB,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -114,7 +116,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::ElGr(super::super::Voice::Neural(super::Voice::Google(
+                Self::ElGr(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -128,7 +130,7 @@ pub mod neural {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -149,7 +151,7 @@ pub mod neural {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
@@ -165,14 +167,14 @@ pub mod neural {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Voice {
-    Standard(standard::Voice),
     Neural(neural::Voice),
+    Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
-            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
 }
@@ -180,8 +182,32 @@ impl VoicePrice for Voice {
 impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
-            Voice::Standard(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
+            Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::ElGr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetB),
+        ))),
+        id: "Google.el-GR-Wavenet-B",
+        language: Language::ElGr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::ElGr(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardB),
+        ))),
+        id: "Google.el-GR-Standard-B",
+        language: Language::ElGr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+];
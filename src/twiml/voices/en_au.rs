@@ -4,13 +4,16 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-pub mod neural {
+pub mod generative {
     use super::*;
 
     pub mod google {
@@ -18,74 +21,74 @@ pub mod neural {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.en-AU-Neural2-B")]
-            #[strum(to_string = "Google.en-AU-Neural2-B")]
-            Neural2B,
-            #[serde(rename = "Google.en-AU-Neural2-D")]
-            #[strum(to_string = "Google.en-AU-Neural2-D")]
-            Neural2D,
-            #[serde(rename = "Google.en-AU-Wavenet-B")]
-            #[strum(to_string = "Google.en-AU-Wavenet-B")]
-            WavenetB,
-            #[serde(rename = "Google.en-AU-Wavenet-D")]
-            #[strum(to_string = "Google.en-AU-Wavenet-D")]
-            WavenetD,
+        pub enum Female {
+            #[serde(rename = "Google.en-AU-Chirp3-HD-Aoede")]
+            #[strum(to_string = "Google.en-AU-Chirp3-HD-Aoede")]
+            Chirp3HdAoede,
+            #[serde(rename = "Google.en-AU-Chirp3-HD-Kore")]
+            #[strum(to_string = "Google.en-AU-Chirp3-HD-Kore")]
+            Chirp3HdKore,
+            #[serde(rename = "Google.en-AU-Chirp3-HD-Leda")]
+            #[strum(to_string = "Google.en-AU-Chirp3-HD-Leda")]
+            Chirp3HdLeda,
+            #[serde(rename = "Google.en-AU-Chirp3-HD-Zephyr")]
+            #[strum(to_string = "Google.en-AU-Chirp3-HD-Zephyr")]
+            Chirp3HdZephyr,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EnAu(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EnAu(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.en-AU-Neural2-A")]
-            #[strum(to_string = "Google.en-AU-Neural2-A")]
-            Neural2A,
-            #[serde(rename = "Google.en-AU-Neural2-C")]
-            #[strum(to_string = "Google.en-AU-Neural2-C")]
-            Neural2C,
-            #[serde(rename = "Google.en-AU-Wavenet-A")]
-            #[strum(to_string = "Google.en-AU-Wavenet-A")]
-            WavenetA,
-            #[serde(rename = "Google.en-AU-Wavenet-C")]
-            #[strum(to_string = "Google.en-AU-Wavenet-C")]
-            WavenetC,
+        pub enum Male {
+            #[serde(rename = "Google.en-AU-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.en-AU-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.en-AU-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.en-AU-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.en-AU-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.en-AU-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.en-AU-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.en-AU-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EnAu(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EnAu(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -93,21 +96,21 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -119,14 +122,14 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Olivia-Neural")]
-            #[strum(to_string = "Polly.Olivia-Neural")]
-            OliviaNeural,
+            #[serde(rename = "Polly.Olivia-Generative")]
+            #[strum(to_string = "Polly.Olivia-Generative")]
+            OliviaGenerative,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -138,7 +141,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnAu(super::super::Voice::Neural(super::Voice::Polly(
+                Self::EnAu(super::super::Voice::Generative(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -152,7 +155,7 @@ pub mod neural {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -174,7 +177,7 @@ pub mod neural {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
         }
     }
 
@@ -188,7 +191,7 @@ pub mod neural {
     }
 }
 
-pub mod generative {
+pub mod neural {
     use super::*;
 
     pub mod google {
@@ -196,74 +199,74 @@ pub mod generative {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.en-AU-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.en-AU-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.en-AU-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.en-AU-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.en-AU-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.en-AU-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.en-AU-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.en-AU-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
+        pub enum Female {
+            #[serde(rename = "Google.en-AU-Neural2-A")]
+            #[strum(to_string = "Google.en-AU-Neural2-A")]
+            Neural2A,
+            #[serde(rename = "Google.en-AU-Neural2-C")]
+            #[strum(to_string = "Google.en-AU-Neural2-C")]
+            Neural2C,
+            #[serde(rename = "Google.en-AU-Wavenet-A")]
+            #[strum(to_string = "Google.en-AU-Wavenet-A")]
+            WavenetA,
+            #[serde(rename = "Google.en-AU-Wavenet-C")]
+            #[strum(to_string = "Google.en-AU-Wavenet-C")]
+            WavenetC,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EnAu(super::super::Voice::Generative(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EnAu(super::super::Voice::Neural(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.en-AU-Chirp3-HD-Aoede")]
-            #[strum(to_string = "Google.en-AU-Chirp3-HD-Aoede")]
-            Chirp3HdAoede,
-            #[serde(rename = "Google.en-AU-Chirp3-HD-Kore")]
-            #[strum(to_string = "Google.en-AU-Chirp3-HD-Kore")]
-            Chirp3HdKore,
-            #[serde(rename = "Google.en-AU-Chirp3-HD-Leda")]
-            #[strum(to_string = "Google.en-AU-Chirp3-HD-Leda")]
-            Chirp3HdLeda,
-            #[serde(rename = "Google.en-AU-Chirp3-HD-Zephyr")]
-            #[strum(to_string = "Google.en-AU-Chirp3-HD-Zephyr")]
-            Chirp3HdZephyr,
+        pub enum Male {
+            #[serde(rename = "Google.en-AU-Neural2-B")]
+            #[strum(to_string = "Google.en-AU-Neural2-B")]
+            Neural2B,
+            #[serde(rename = "Google.en-AU-Neural2-D")]
+            #[strum(to_string = "Google.en-AU-Neural2-D")]
+            Neural2D,
+            #[serde(rename = "Google.en-AU-Wavenet-B")]
+            #[strum(to_string = "Google.en-AU-Wavenet-B")]
+            WavenetB,
+            #[serde(rename = "Google.en-AU-Wavenet-D")]
+            #[strum(to_string = "Google.en-AU-Wavenet-D")]
+            WavenetD,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EnAu(super::super::Voice::Generative(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EnAu(super::super::Voice::Neural(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -271,21 +274,21 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -297,14 +300,14 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Olivia-Generative")]
-            #[strum(to_string = "Polly.Olivia-Generative")]
-            OliviaGenerative,
+            #[serde(rename = "Polly.Olivia-Neural")]
+            #[strum(to_string = "Polly.Olivia-Neural")]
+            OliviaNeural,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -316,7 +319,7 @@ pub mod generative {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnAu(super::super::Voice::Generative(super::Voice::Polly(
+                Self::EnAu(super::super::Voice::Neural(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -330,7 +333,7 @@ pub mod generative {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -352,7 +355,7 @@ pub mod generative {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
@@ -369,61 +372,67 @@ pub mod generative {
 pub mod standard {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Russell")]
-            #[strum(to_string = "Polly.Russell")]
-            Russell,
+        pub enum Female {
+            #[serde(rename = "Google.en-AU-Standard-A")]
+            #[strum(to_string = "Google.en-AU-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.en-AU-Standard-C")]
+            #[strum(to_string = "Google.en-AU-Standard-C")]
+            StandardC,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EnAu(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EnAu(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Nicole")]
-            #[strum(to_string = "Polly.Nicole")]
-            Nicole,
+        pub enum Male {
+            #[serde(rename = "Google.en-AU-Standard-B")]
+            #[strum(to_string = "Google.en-AU-Standard-B")]
+            StandardB,
+            #[serde(rename = "Google.en-AU-Standard-D")]
+            #[strum(to_string = "Google.en-AU-Standard-D")]
+            StandardD,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EnAu(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EnAu(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -431,8 +440,8 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -444,74 +453,68 @@ pub mod standard {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
     }
 
-    pub mod google {
+    pub mod polly {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.en-AU-Standard-B")]
-            #[strum(to_string = "Google.en-AU-Standard-B")]
-            StandardB,
-            #[serde(rename = "Google.en-AU-Standard-D")]
-            #[strum(to_string = "Google.en-AU-Standard-D")]
-            StandardD,
+        pub enum Female {
+            #[serde(rename = "Polly.Nicole")]
+            #[strum(to_string = "Polly.Nicole")]
+            Nicole,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EnAu(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EnAu(super::super::Voice::Standard(super::Voice::Polly(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.en-AU-Standard-A")]
-            #[strum(to_string = "Google.en-AU-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.en-AU-Standard-C")]
-            #[strum(to_string = "Google.en-AU-Standard-C")]
-            StandardC,
+        pub enum Male {
+            #[serde(rename = "Polly.Russell")]
+            #[strum(to_string = "Polly.Russell")]
+            Russell,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EnAu(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EnAu(super::super::Voice::Standard(super::Voice::Polly(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -519,8 +522,8 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -532,8 +535,8 @@ pub mod standard {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -542,8 +545,8 @@ pub mod standard {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
@@ -555,8 +558,8 @@ pub mod standard {
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
@@ -565,15 +568,15 @@ pub mod standard {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Voice {
-    Neural(neural::Voice),
     Generative(generative::Voice),
+    Neural(neural::Voice),
     Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
-            Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
             Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
+            Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
             Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
@@ -582,9 +585,253 @@ impl VoicePrice for Voice {
 impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
-            Voice::Neural(voice) => voice.gender(),
             Voice::Generative(voice) => voice.gender(),
+            Voice::Neural(voice) => voice.gender(),
             Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.en-AU-Chirp3-HD-Aoede",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.en-AU-Chirp3-HD-Kore",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.en-AU-Chirp3-HD-Leda",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.en-AU-Chirp3-HD-Zephyr",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.en-AU-Chirp3-HD-Charon",
+        language: Language::EnAu,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.en-AU-Chirp3-HD-Fenrir",
+        language: Language::EnAu,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.en-AU-Chirp3-HD-Orus",
+        language: Language::EnAu,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.en-AU-Chirp3-HD-Puck",
+        language: Language::EnAu,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Female(generative::polly::Female::OliviaGenerative),
+        ))),
+        id: "Polly.Olivia-Generative",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2A),
+        ))),
+        id: "Google.en-AU-Neural2-A",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2C),
+        ))),
+        id: "Google.en-AU-Neural2-C",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.en-AU-Wavenet-A",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetC),
+        ))),
+        id: "Google.en-AU-Wavenet-C",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2B),
+        ))),
+        id: "Google.en-AU-Neural2-B",
+        language: Language::EnAu,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2D),
+        ))),
+        id: "Google.en-AU-Neural2-D",
+        language: Language::EnAu,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.en-AU-Wavenet-B",
+        language: Language::EnAu,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetD),
+        ))),
+        id: "Google.en-AU-Wavenet-D",
+        language: Language::EnAu,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::OliviaNeural),
+        ))),
+        id: "Polly.Olivia-Neural",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.en-AU-Standard-A",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardC),
+        ))),
+        id: "Google.en-AU-Standard-C",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.en-AU-Standard-B",
+        language: Language::EnAu,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardD),
+        ))),
+        id: "Google.en-AU-Standard-D",
+        language: Language::EnAu,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Nicole),
+        ))),
+        id: "Polly.Nicole",
+        language: Language::EnAu,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnAu(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Male(standard::polly::Male::Russell),
+        ))),
+        id: "Polly.Russell",
+        language: Language::EnAu,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
@@ -4,13 +4,16 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-pub mod standard {
+pub mod generative {
     use super::*;
 
     pub mod google {
@@ -19,14 +22,23 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.en-GB-Standard-N")]
-            #[strum(to_string = "Google.en-GB-Standard-N")]
-            StandardN,
+            #[serde(rename = "Google.en-GB-Chirp3-HD-Aoede")]
+            #[strum(to_string = "Google.en-GB-Chirp3-HD-Aoede")]
+            Chirp3HdAoede,
+            #[serde(rename = "Google.en-GB-Chirp3-HD-Kore")]
+            #[strum(to_string = "Google.en-GB-Chirp3-HD-Kore")]
+            Chirp3HdKore,
+            #[serde(rename = "Google.en-GB-Chirp3-HD-Leda")]
+            #[strum(to_string = "Google.en-GB-Chirp3-HD-Leda")]
+            Chirp3HdLeda,
+            #[serde(rename = "Google.en-GB-Chirp3-HD-Zephyr")]
+            #[strum(to_string = "Google.en-GB-Chirp3-HD-Zephyr")]
+            Chirp3HdZephyr,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -38,7 +50,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnGb(super::super::Voice::Standard(super::Voice::Google(
+                Self::EnGb(super::super::Voice::Generative(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -47,14 +59,23 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.en-GB-Standard-O")]
-            #[strum(to_string = "Google.en-GB-Standard-O")]
-            StandardO,
+            #[serde(rename = "Google.en-GB-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.en-GB-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.en-GB-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.en-GB-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.en-GB-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.en-GB-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.en-GB-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.en-GB-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -66,7 +87,7 @@ pub mod standard {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::EnGb(super::super::Voice::Standard(super::Voice::Google(
+                Self::EnGb(super::super::Voice::Generative(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -81,7 +102,7 @@ pub mod standard {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -98,48 +119,17 @@ pub mod standard {
     pub mod polly {
         use super::*;
 
-        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
-        #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Brian")]
-            #[strum(to_string = "Polly.Brian")]
-            Brian,
-        }
-
-        impl VoicePrice for Male {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Male {
-            fn gender(&self) -> Gender {
-                Gender::Male
-            }
-        }
-
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EnGb(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::Male(value),
-                )))
-            }
-        }
-
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Amy")]
-            #[strum(to_string = "Polly.Amy")]
-            Amy,
-            #[serde(rename = "Polly.Emma")]
-            #[strum(to_string = "Polly.Emma")]
-            Emma,
+            #[serde(rename = "Polly.Amy-Generative")]
+            #[strum(to_string = "Polly.Amy-Generative")]
+            AmyGenerative,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -151,7 +141,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnGb(super::super::Voice::Standard(super::Voice::Polly(
+                Self::EnGb(super::super::Voice::Generative(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -160,20 +150,18 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
                 }
             }
@@ -189,7 +177,7 @@ pub mod standard {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
         }
     }
 
@@ -203,7 +191,7 @@ pub mod standard {
     }
 }
 
-pub mod generative {
+pub mod neural {
     use super::*;
 
     pub mod google {
@@ -212,23 +200,17 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.en-GB-Chirp3-HD-Aoede")]
-            #[strum(to_string = "Google.en-GB-Chirp3-HD-Aoede")]
-            Chirp3HdAoede,
-            #[serde(rename = "Google.en-GB-Chirp3-HD-Kore")]
-            #[strum(to_string = "Google.en-GB-Chirp3-HD-Kore")]
-            Chirp3HdKore,
-            #[serde(rename = "Google.en-GB-Chirp3-HD-Leda")]
-            #[strum(to_string = "Google.en-GB-Chirp3-HD-Leda")]
-            Chirp3HdLeda,
-            #[serde(rename = "Google.en-GB-Chirp3-HD-Zephyr")]
-            #[strum(to_string = "Google.en-GB-Chirp3-HD-Zephyr")]
-            Chirp3HdZephyr,
+            #[serde(rename = "Google.en-GB-Neural2-N")]
+            #[strum(to_string = "Google.en-GB-Neural2-N")]
+            Neural2N,
+            #[serde(rename = "Google.en-GB-Wavenet-N")]
+            #[strum(to_string = "Google.en-GB-Wavenet-N")]
+            WavenetN,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -240,7 +222,7 @@ pub mod generative {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnGb(super::super::Voice::Generative(super::Voice::Google(
+                Self::EnGb(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -249,23 +231,17 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.en-GB-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.en-GB-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.en-GB-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.en-GB-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.en-GB-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.en-GB-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.en-GB-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.en-GB-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
+            #[serde(rename = "Google.en-GB-Neural2-O")]
+            #[strum(to_string = "Google.en-GB-Neural2-O")]
+            Neural2O,
+            #[serde(rename = "Google.en-GB-Wavenet-O")]
+            #[strum(to_string = "Google.en-GB-Wavenet-O")]
+            WavenetO,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -277,7 +253,7 @@ pub mod generative {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::EnGb(super::super::Voice::Generative(super::Voice::Google(
+                Self::EnGb(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -292,7 +268,7 @@ pub mod generative {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -312,14 +288,17 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Amy-Generative")]
-            #[strum(to_string = "Polly.Amy-Generative")]
-            AmyGenerative,
+            #[serde(rename = "Polly.Amy-Neural")]
+            #[strum(to_string = "Polly.Amy-Neural")]
+            AmyNeural,
+            #[serde(rename = "Polly.Emma-Neural")]
+            #[strum(to_string = "Polly.Emma-Neural")]
+            EmmaNeural,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -331,21 +310,53 @@ pub mod generative {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnGb(super::super::Voice::Generative(super::Voice::Polly(
+                Self::EnGb(super::super::Voice::Neural(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
         }
 
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum Male {
+            #[serde(rename = "Polly.Arthur-Neural")]
+            #[strum(to_string = "Polly.Arthur-Neural")]
+            ArthurNeural,
+            #[serde(rename = "Polly.Brian-Neural")]
+            #[strum(to_string = "Polly.Brian-Neural")]
+            BrianNeural,
+        }
+
+        impl VoicePrice for Male {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Male {
+            fn gender(&self) -> Gender {
+                Gender::Male
+            }
+        }
+
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EnGb(super::super::Voice::Neural(super::Voice::Polly(
+                    Voice::Male(value),
+                )))
+            }
+        }
+
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -353,6 +364,7 @@ pub mod generative {
             fn gender(&self) -> Gender {
                 match self {
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -367,7 +379,7 @@ pub mod generative {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
@@ -381,26 +393,23 @@ pub mod generative {
     }
 }
 
-pub mod neural {
+pub mod standard {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Amy-Neural")]
-            #[strum(to_string = "Polly.Amy-Neural")]
-            AmyNeural,
-            #[serde(rename = "Polly.Emma-Neural")]
-            #[strum(to_string = "Polly.Emma-Neural")]
-            EmmaNeural,
+            #[serde(rename = "Google.en-GB-Standard-N")]
+            #[strum(to_string = "Google.en-GB-Standard-N")]
+            StandardN,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -412,7 +421,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnGb(super::super::Voice::Neural(super::Voice::Polly(
+                Self::EnGb(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -421,17 +430,14 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Polly.Arthur-Neural")]
-            #[strum(to_string = "Polly.Arthur-Neural")]
-            ArthurNeural,
-            #[serde(rename = "Polly.Brian-Neural")]
-            #[strum(to_string = "Polly.Brian-Neural")]
-            BrianNeural,
+            #[serde(rename = "Google.en-GB-Standard-O")]
+            #[strum(to_string = "Google.en-GB-Standard-O")]
+            StandardO,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -443,7 +449,7 @@ pub mod neural {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::EnGb(super::super::Voice::Neural(super::Voice::Polly(
+                Self::EnGb(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -458,7 +464,7 @@ pub mod neural {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -472,67 +478,64 @@ pub mod neural {
         }
     }
 
-    pub mod google {
+    pub mod polly {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.en-GB-Neural2-O")]
-            #[strum(to_string = "Google.en-GB-Neural2-O")]
-            Neural2O,
-            #[serde(rename = "Google.en-GB-Wavenet-O")]
-            #[strum(to_string = "Google.en-GB-Wavenet-O")]
-            WavenetO,
+        pub enum Female {
+            #[serde(rename = "Polly.Amy")]
+            #[strum(to_string = "Polly.Amy")]
+            Amy,
+            #[serde(rename = "Polly.Emma")]
+            #[strum(to_string = "Polly.Emma")]
+            Emma,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EnGb(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EnGb(super::super::Voice::Standard(super::Voice::Polly(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.en-GB-Neural2-N")]
-            #[strum(to_string = "Google.en-GB-Neural2-N")]
-            Neural2N,
-            #[serde(rename = "Google.en-GB-Wavenet-N")]
-            #[strum(to_string = "Google.en-GB-Wavenet-N")]
-            WavenetN,
+        pub enum Male {
+            #[serde(rename = "Polly.Brian")]
+            #[strum(to_string = "Polly.Brian")]
+            Brian,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EnGb(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EnGb(super::super::Voice::Standard(super::Voice::Polly(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -540,21 +543,21 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -563,21 +566,21 @@ pub mod neural {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
@@ -586,16 +589,16 @@ pub mod neural {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Voice {
-    Standard(standard::Voice),
     Generative(generative::Voice),
     Neural(neural::Voice),
+    Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
-            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
             Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
 }
@@ -603,9 +606,233 @@ impl VoicePrice for Voice {
 impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
-            Voice::Standard(voice) => voice.gender(),
             Voice::Generative(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
+            Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.en-GB-Chirp3-HD-Aoede",
+        language: Language::EnGb,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.en-GB-Chirp3-HD-Kore",
+        language: Language::EnGb,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.en-GB-Chirp3-HD-Leda",
+        language: Language::EnGb,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.en-GB-Chirp3-HD-Zephyr",
+        language: Language::EnGb,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.en-GB-Chirp3-HD-Charon",
+        language: Language::EnGb,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.en-GB-Chirp3-HD-Fenrir",
+        language: Language::EnGb,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.en-GB-Chirp3-HD-Orus",
+        language: Language::EnGb,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.en-GB-Chirp3-HD-Puck",
+        language: Language::EnGb,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Female(generative::polly::Female::AmyGenerative),
+        ))),
+        id: "Polly.Amy-Generative",
+        language: Language::EnGb,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2N),
+        ))),
+        id: "Google.en-GB-Neural2-N",
+        language: Language::EnGb,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetN),
+        ))),
+        id: "Google.en-GB-Wavenet-N",
+        language: Language::EnGb,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2O),
+        ))),
+        id: "Google.en-GB-Neural2-O",
+        language: Language::EnGb,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetO),
+        ))),
+        id: "Google.en-GB-Wavenet-O",
+        language: Language::EnGb,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::AmyNeural),
+        ))),
+        id: "Polly.Amy-Neural",
+        language: Language::EnGb,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::EmmaNeural),
+        ))),
+        id: "Polly.Emma-Neural",
+        language: Language::EnGb,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Male(neural::polly::Male::ArthurNeural),
+        ))),
+        id: "Polly.Arthur-Neural",
+        language: Language::EnGb,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Male(neural::polly::Male::BrianNeural),
+        ))),
+        id: "Polly.Brian-Neural",
+        language: Language::EnGb,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardN),
+        ))),
+        id: "Google.en-GB-Standard-N",
+        language: Language::EnGb,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardO),
+        ))),
+        id: "Google.en-GB-Standard-O",
+        language: Language::EnGb,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Amy),
+        ))),
+        id: "Polly.Amy",
+        language: Language::EnGb,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Emma),
+        ))),
+        id: "Polly.Emma",
+        language: Language::EnGb,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnGb(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Male(standard::polly::Male::Brian),
+        ))),
+        id: "Polly.Brian",
+        language: Language::EnGb,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
@@ -2,7 +2,10 @@
 
 use crate::{
     PriceType,
-    twiml::{Gender, VoiceGender, VoicePrice, voices::NEURAL_VOICE_PRICE},
+    twiml::{
+        Gender, VoiceGender, VoicePrice,
+        voices::{Language, NEURAL_VOICE_PRICE, Provider, VoiceInfo, VoiceTier},
+    },
 };
 
 use serde::{Deserialize, Serialize};
@@ -103,3 +106,15 @@ impl VoiceGender for Voice {
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[VoiceInfo {
+    voice: crate::twiml::Voice::EnIe(Voice::Neural(neural::Voice::Polly(
+        neural::polly::Voice::Female(neural::polly::Female::NiamhNeural),
+    ))),
+    id: "Polly.Niamh-Neural",
+    language: Language::EnIe,
+    gender: Gender::Female,
+    provider: Provider::Polly,
+    tier: VoiceTier::Neural,
+}];
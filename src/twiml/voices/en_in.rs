@@ -4,13 +4,16 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-pub mod neural {
+pub mod generative {
     use super::*;
 
     pub mod google {
@@ -19,26 +22,23 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.en-IN-Neural2-A")]
-            #[strum(to_string = "Google.en-IN-Neural2-A")]
-            Neural2A,
-            #[serde(rename = "Google.en-IN-Neural2-D")]
-            #[strum(to_string = "Google.en-IN-Neural2-D")]
-            Neural2D,
-            #[serde(rename = "Google.en-IN-Wavenet-A")]
-            #[strum(to_string = "Google.en-IN-Wavenet-A")]
-            WavenetA,
-            #[serde(rename = "Google.en-IN-Wavenet-D")]
-            #[strum(to_string = "Google.en-IN-Wavenet-D")]
-            WavenetD,
-            #[serde(rename = "Google.en-IN-Wavenet-E")]
-            #[strum(to_string = "Google.en-IN-Wavenet-E")]
-            WavenetE,
+            #[serde(rename = "Google.en-IN-Chirp3-HD-Aoede")]
+            #[strum(to_string = "Google.en-IN-Chirp3-HD-Aoede")]
+            Chirp3HdAoede,
+            #[serde(rename = "Google.en-IN-Chirp3-HD-Kore")]
+            #[strum(to_string = "Google.en-IN-Chirp3-HD-Kore")]
+            Chirp3HdKore,
+            #[serde(rename = "Google.en-IN-Chirp3-HD-Leda")]
+            #[strum(to_string = "Google.en-IN-Chirp3-HD-Leda")]
+            Chirp3HdLeda,
+            #[serde(rename = "Google.en-IN-Chirp3-HD-Zephyr")]
+            #[strum(to_string = "Google.en-IN-Chirp3-HD-Zephyr")]
+            Chirp3HdZephyr,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -50,7 +50,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnIn(super::super::Voice::Neural(super::Voice::Google(
+                Self::EnIn(super::super::Voice::Generative(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -59,26 +59,23 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.en-IN-Neural2-B")]
-            #[strum(to_string = "Google.en-IN-Neural2-B")]
-            Neural2B,
-            #[serde(rename = "Google.en-IN-Neural2-C")]
-            #[strum(to_string = "Google.en-IN-Neural2-C")]
-            Neural2C,
-            #[serde(rename = "Google.en-IN-Wavenet-B")]
-            #[strum(to_string = "Google.en-IN-Wavenet-B")]
-            WavenetB,
-            #[serde(rename = "Google.en-IN-Wavenet-C")]
-            #[strum(to_string = "Google.en-IN-Wavenet-C")]
-            WavenetC,
-            #[serde(rename = "Google.en-IN-Wavenet-F")]
-            #[strum(to_string = "Google.en-IN-Wavenet-F")]
-            WavenetF,
+            #[serde(rename = "Google.en-IN-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.en-IN-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.en-IN-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.en-IN-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.en-IN-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.en-IN-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.en-IN-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.en-IN-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -90,7 +87,7 @@ pub mod neural {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::EnIn(super::super::Voice::Neural(super::Voice::Google(
+                Self::EnIn(super::super::Voice::Generative(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -105,7 +102,7 @@ pub mod neural {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -125,14 +122,14 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Kajal-Neural")]
-            #[strum(to_string = "Polly.Kajal-Neural")]
-            KajalNeural,
+            #[serde(rename = "Polly.Kajal-Generative")]
+            #[strum(to_string = "Polly.Kajal-Generative")]
+            KajalGenerative,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -144,7 +141,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnIn(super::super::Voice::Neural(super::Voice::Polly(
+                Self::EnIn(super::super::Voice::Generative(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -158,7 +155,7 @@ pub mod neural {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -180,7 +177,7 @@ pub mod neural {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
         }
     }
 
@@ -194,7 +191,7 @@ pub mod neural {
     }
 }
 
-pub mod standard {
+pub mod neural {
     use super::*;
 
     pub mod google {
@@ -202,68 +199,80 @@ pub mod standard {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.en-IN-Standard-B")]
-            #[strum(to_string = "Google.en-IN-Standard-B")]
-            StandardB,
-            #[serde(rename = "Google.en-IN-Standard-C")]
-            #[strum(to_string = "Google.en-IN-Standard-C")]
-            StandardC,
-            #[serde(rename = "Google.en-IN-Standard-F")]
-            #[strum(to_string = "Google.en-IN-Standard-F")]
-            StandardF,
+        pub enum Female {
+            #[serde(rename = "Google.en-IN-Neural2-A")]
+            #[strum(to_string = "Google.en-IN-Neural2-A")]
+            Neural2A,
+            #[serde(rename = "Google.en-IN-Neural2-D")]
+            #[strum(to_string = "Google.en-IN-Neural2-D")]
+            Neural2D,
+            #[serde(rename = "Google.en-IN-Wavenet-A")]
+            #[strum(to_string = "Google.en-IN-Wavenet-A")]
+            WavenetA,
+            #[serde(rename = "Google.en-IN-Wavenet-D")]
+            #[strum(to_string = "Google.en-IN-Wavenet-D")]
+            WavenetD,
+            #[serde(rename = "Google.en-IN-Wavenet-E")]
+            #[strum(to_string = "Google.en-IN-Wavenet-E")]
+            WavenetE,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EnIn(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EnIn(super::super::Voice::Neural(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.en-IN-Standard-A")]
-            #[strum(to_string = "Google.en-IN-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.en-IN-Standard-D")]
-            #[strum(to_string = "Google.en-IN-Standard-D")]
-            StandardD,
-            #[serde(rename = "Google.en-IN-Standard-E")]
-            #[strum(to_string = "Google.en-IN-Standard-E")]
-            StandardE,
+        pub enum Male {
+            #[serde(rename = "Google.en-IN-Neural2-B")]
+            #[strum(to_string = "Google.en-IN-Neural2-B")]
+            Neural2B,
+            #[serde(rename = "Google.en-IN-Neural2-C")]
+            #[strum(to_string = "Google.en-IN-Neural2-C")]
+            Neural2C,
+            #[serde(rename = "Google.en-IN-Wavenet-B")]
+            #[strum(to_string = "Google.en-IN-Wavenet-B")]
+            WavenetB,
+            #[serde(rename = "Google.en-IN-Wavenet-C")]
+            #[strum(to_string = "Google.en-IN-Wavenet-C")]
+            WavenetC,
+            #[serde(rename = "Google.en-IN-Wavenet-F")]
+            #[strum(to_string = "Google.en-IN-Wavenet-F")]
+            WavenetF,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EnIn(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EnIn(super::super::Voice::Neural(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -271,21 +280,21 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -297,17 +306,14 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Aditi")]
-            #[strum(to_string = "Polly.Aditi")]
-            Aditi,
-            #[serde(rename = "Polly.Raveena")]
-            #[strum(to_string = "Polly.Raveena")]
-            Raveena,
+            #[serde(rename = "Polly.Kajal-Neural")]
+            #[strum(to_string = "Polly.Kajal-Neural")]
+            KajalNeural,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -319,7 +325,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnIn(super::super::Voice::Standard(super::Voice::Polly(
+                Self::EnIn(super::super::Voice::Neural(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -333,7 +339,7 @@ pub mod standard {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -355,7 +361,7 @@ pub mod standard {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
@@ -369,23 +375,29 @@ pub mod standard {
     }
 }
 
-pub mod generative {
+pub mod standard {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Kajal-Generative")]
-            #[strum(to_string = "Polly.Kajal-Generative")]
-            KajalGenerative,
+            #[serde(rename = "Google.en-IN-Standard-A")]
+            #[strum(to_string = "Google.en-IN-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.en-IN-Standard-D")]
+            #[strum(to_string = "Google.en-IN-Standard-D")]
+            StandardD,
+            #[serde(rename = "Google.en-IN-Standard-E")]
+            #[strum(to_string = "Google.en-IN-Standard-E")]
+            StandardE,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -397,56 +409,29 @@ pub mod generative {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnIn(super::super::Voice::Generative(super::Voice::Polly(
+                Self::EnIn(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
         }
 
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-        #[serde(untagged)]
-        pub enum Voice {
-            Female(Female),
-        }
-
-        impl VoicePrice for Voice {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Voice {
-            fn gender(&self) -> Gender {
-                match self {
-                    Voice::Female(_) => Gender::Female,
-                }
-            }
-        }
-    }
-
-    pub mod google {
-        use super::*;
-
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.en-IN-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.en-IN-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.en-IN-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.en-IN-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.en-IN-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.en-IN-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.en-IN-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.en-IN-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
+            #[serde(rename = "Google.en-IN-Standard-B")]
+            #[strum(to_string = "Google.en-IN-Standard-B")]
+            StandardB,
+            #[serde(rename = "Google.en-IN-Standard-C")]
+            #[strum(to_string = "Google.en-IN-Standard-C")]
+            StandardC,
+            #[serde(rename = "Google.en-IN-Standard-F")]
+            #[strum(to_string = "Google.en-IN-Standard-F")]
+            StandardF,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -458,32 +443,52 @@ pub mod generative {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::EnIn(super::super::Voice::Generative(super::Voice::Google(
+                Self::EnIn(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
         }
 
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+        #[serde(untagged)]
+        pub enum Voice {
+            Female(Female),
+            Male(Male),
+        }
+
+        impl VoicePrice for Voice {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Voice {
+            fn gender(&self) -> Gender {
+                match self {
+                    Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
+                }
+            }
+        }
+    }
+
+    pub mod polly {
+        use super::*;
+
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.en-IN-Chirp3-HD-Aoede")]
-            #[strum(to_string = "Google.en-IN-Chirp3-HD-Aoede")]
-            Chirp3HdAoede,
-            #[serde(rename = "Google.en-IN-Chirp3-HD-Kore")]
-            #[strum(to_string = "Google.en-IN-Chirp3-HD-Kore")]
-            Chirp3HdKore,
-            #[serde(rename = "Google.en-IN-Chirp3-HD-Leda")]
-            #[strum(to_string = "Google.en-IN-Chirp3-HD-Leda")]
-            Chirp3HdLeda,
-            #[serde(rename = "Google.en-IN-Chirp3-HD-Zephyr")]
-            #[strum(to_string = "Google.en-IN-Chirp3-HD-Zephyr")]
-            Chirp3HdZephyr,
+            #[serde(rename = "Polly.Aditi")]
+            #[strum(to_string = "Polly.Aditi")]
+            Aditi,
+            #[serde(rename = "Polly.Raveena")]
+            #[strum(to_string = "Polly.Raveena")]
+            Raveena,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -495,7 +500,7 @@ pub mod generative {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnIn(super::super::Voice::Generative(super::Voice::Google(
+                Self::EnIn(super::super::Voice::Standard(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -504,20 +509,18 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
                 }
             }
@@ -527,21 +530,21 @@ pub mod generative {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
@@ -550,16 +553,16 @@ pub mod generative {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Voice {
+    Generative(generative::Voice),
     Neural(neural::Voice),
     Standard(standard::Voice),
-    Generative(generative::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
+            Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
             Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
-            Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
         }
     }
 }
@@ -567,9 +570,293 @@ impl VoicePrice for Voice {
 impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
+            Voice::Generative(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
             Voice::Standard(voice) => voice.gender(),
-            Voice::Generative(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.en-IN-Chirp3-HD-Aoede",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.en-IN-Chirp3-HD-Kore",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.en-IN-Chirp3-HD-Leda",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.en-IN-Chirp3-HD-Zephyr",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.en-IN-Chirp3-HD-Charon",
+        language: Language::EnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.en-IN-Chirp3-HD-Fenrir",
+        language: Language::EnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.en-IN-Chirp3-HD-Orus",
+        language: Language::EnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.en-IN-Chirp3-HD-Puck",
+        language: Language::EnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Female(generative::polly::Female::KajalGenerative),
+        ))),
+        id: "Polly.Kajal-Generative",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2A),
+        ))),
+        id: "Google.en-IN-Neural2-A",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2D),
+        ))),
+        id: "Google.en-IN-Neural2-D",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.en-IN-Wavenet-A",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetD),
+        ))),
+        id: "Google.en-IN-Wavenet-D",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetE),
+        ))),
+        id: "Google.en-IN-Wavenet-E",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2B),
+        ))),
+        id: "Google.en-IN-Neural2-B",
+        language: Language::EnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2C),
+        ))),
+        id: "Google.en-IN-Neural2-C",
+        language: Language::EnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.en-IN-Wavenet-B",
+        language: Language::EnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetC),
+        ))),
+        id: "Google.en-IN-Wavenet-C",
+        language: Language::EnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetF),
+        ))),
+        id: "Google.en-IN-Wavenet-F",
+        language: Language::EnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::KajalNeural),
+        ))),
+        id: "Polly.Kajal-Neural",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.en-IN-Standard-A",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardD),
+        ))),
+        id: "Google.en-IN-Standard-D",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardE),
+        ))),
+        id: "Google.en-IN-Standard-E",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.en-IN-Standard-B",
+        language: Language::EnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardC),
+        ))),
+        id: "Google.en-IN-Standard-C",
+        language: Language::EnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardF),
+        ))),
+        id: "Google.en-IN-Standard-F",
+        language: Language::EnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Aditi),
+        ))),
+        id: "Polly.Aditi",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnIn(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Raveena),
+        ))),
+        id: "Polly.Raveena",
+        language: Language::EnIn,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
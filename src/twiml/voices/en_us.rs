@@ -4,7 +4,10 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
@@ -13,70 +16,79 @@ use serde::{Deserialize, Serialize};
 pub mod generative {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Matthew-Generative")]
-            #[strum(to_string = "Polly.Matthew-Generative")]
-            MatthewGenerative,
-            #[serde(rename = "Polly.Stephen-Generative")]
-            #[strum(to_string = "Polly.Stephen-Generative")]
-            StephenGenerative,
+        pub enum Female {
+            #[serde(rename = "Google.en-US-Chirp3-HD-Aoede")]
+            #[strum(to_string = "Google.en-US-Chirp3-HD-Aoede")]
+            Chirp3HdAoede,
+            #[serde(rename = "Google.en-US-Chirp3-HD-Kore")]
+            #[strum(to_string = "Google.en-US-Chirp3-HD-Kore")]
+            Chirp3HdKore,
+            #[serde(rename = "Google.en-US-Chirp3-HD-Leda")]
+            #[strum(to_string = "Google.en-US-Chirp3-HD-Leda")]
+            Chirp3HdLeda,
+            #[serde(rename = "Google.en-US-Chirp3-HD-Zephyr")]
+            #[strum(to_string = "Google.en-US-Chirp3-HD-Zephyr")]
+            Chirp3HdZephyr,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EnUs(super::super::Voice::Generative(super::Voice::Polly(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EnUs(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Danielle-Generative")]
-            #[strum(to_string = "Polly.Danielle-Generative")]
-            DanielleGenerative,
-            #[serde(rename = "Polly.Joanna-Generative")]
-            #[strum(to_string = "Polly.Joanna-Generative")]
-            JoannaGenerative,
-            #[serde(rename = "Polly.Ruth-Generative")]
-            #[strum(to_string = "Polly.Ruth-Generative")]
-            RuthGenerative,
+        pub enum Male {
+            #[serde(rename = "Google.en-US-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.en-US-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.en-US-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.en-US-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.en-US-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.en-US-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.en-US-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.en-US-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EnUs(super::super::Voice::Generative(super::Voice::Polly(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EnUs(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -84,8 +96,8 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -97,31 +109,28 @@ pub mod generative {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
     }
 
-    pub mod google {
+    pub mod polly {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.en-US-Chirp3-HD-Aoede")]
-            #[strum(to_string = "Google.en-US-Chirp3-HD-Aoede")]
-            Chirp3HdAoede,
-            #[serde(rename = "Google.en-US-Chirp3-HD-Kore")]
-            #[strum(to_string = "Google.en-US-Chirp3-HD-Kore")]
-            Chirp3HdKore,
-            #[serde(rename = "Google.en-US-Chirp3-HD-Leda")]
-            #[strum(to_string = "Google.en-US-Chirp3-HD-Leda")]
-            Chirp3HdLeda,
-            #[serde(rename = "Google.en-US-Chirp3-HD-Zephyr")]
-            #[strum(to_string = "Google.en-US-Chirp3-HD-Zephyr")]
-            Chirp3HdZephyr,
+            #[serde(rename = "Polly.Danielle-Generative")]
+            #[strum(to_string = "Polly.Danielle-Generative")]
+            DanielleGenerative,
+            #[serde(rename = "Polly.Joanna-Generative")]
+            #[strum(to_string = "Polly.Joanna-Generative")]
+            JoannaGenerative,
+            #[serde(rename = "Polly.Ruth-Generative")]
+            #[strum(to_string = "Polly.Ruth-Generative")]
+            RuthGenerative,
         }
 
         impl VoicePrice for Female {
@@ -138,7 +147,7 @@ pub mod generative {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnUs(super::super::Voice::Generative(super::Voice::Google(
+                Self::EnUs(super::super::Voice::Generative(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -147,18 +156,12 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.en-US-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.en-US-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.en-US-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.en-US-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.en-US-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.en-US-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.en-US-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.en-US-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
+            #[serde(rename = "Polly.Matthew-Generative")]
+            #[strum(to_string = "Polly.Matthew-Generative")]
+            MatthewGenerative,
+            #[serde(rename = "Polly.Stephen-Generative")]
+            #[strum(to_string = "Polly.Stephen-Generative")]
+            StephenGenerative,
         }
 
         impl VoicePrice for Male {
@@ -175,7 +178,7 @@ pub mod generative {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::EnUs(super::super::Voice::Generative(super::Voice::Google(
+                Self::EnUs(super::super::Voice::Generative(super::Voice::Polly(
                     Voice::Male(value),
                 )))
             }
@@ -207,8 +210,8 @@ pub mod generative {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
@@ -220,14 +223,14 @@ pub mod generative {
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
 }
 
-pub mod standard {
+pub mod neural {
     use super::*;
 
     pub mod google {
@@ -236,26 +239,41 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.en-US-Standard-C")]
-            #[strum(to_string = "Google.en-US-Standard-C")]
-            StandardC,
-            #[serde(rename = "Google.en-US-Standard-E")]
-            #[strum(to_string = "Google.en-US-Standard-E")]
-            StandardE,
-            #[serde(rename = "Google.en-US-Standard-F")]
-            #[strum(to_string = "Google.en-US-Standard-F")]
-            StandardF,
-            #[serde(rename = "Google.en-US-Standard-G")]
-            #[strum(to_string = "Google.en-US-Standard-G")]
-            StandardG,
-            #[serde(rename = "Google.en-US-Standard-H")]
-            #[strum(to_string = "Google.en-US-Standard-H")]
-            StandardH,
+            #[serde(rename = "Google.en-US-Neural2-C")]
+            #[strum(to_string = "Google.en-US-Neural2-C")]
+            Neural2C,
+            #[serde(rename = "Google.en-US-Neural2-E")]
+            #[strum(to_string = "Google.en-US-Neural2-E")]
+            Neural2E,
+            #[serde(rename = "Google.en-US-Neural2-F")]
+            #[strum(to_string = "Google.en-US-Neural2-F")]
+            Neural2F,
+            #[serde(rename = "Google.en-US-Neural2-G")]
+            #[strum(to_string = "Google.en-US-Neural2-G")]
+            Neural2G,
+            #[serde(rename = "Google.en-US-Neural2-H")]
+            #[strum(to_string = "Google.en-US-Neural2-H")]
+            Neural2H,
+            #[serde(rename = "Google.en-US-Wavenet-C")]
+            #[strum(to_string = "Google.en-US-Wavenet-C")]
+            WavenetC,
+            #[serde(rename = "Google.en-US-Wavenet-E")]
+            #[strum(to_string = "Google.en-US-Wavenet-E")]
+            WavenetE,
+            #[serde(rename = "Google.en-US-Wavenet-F")]
+            #[strum(to_string = "Google.en-US-Wavenet-F")]
+            WavenetF,
+            #[serde(rename = "Google.en-US-Wavenet-G")]
+            #[strum(to_string = "Google.en-US-Wavenet-G")]
+            WavenetG,
+            #[serde(rename = "Google.en-US-Wavenet-H")]
+            #[strum(to_string = "Google.en-US-Wavenet-H")]
+            WavenetH,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -267,7 +285,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnUs(super::super::Voice::Standard(super::Voice::Google(
+                Self::EnUs(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -276,26 +294,38 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.en-US-Standard-A")]
-            #[strum(to_string = "Google.en-US-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.en-US-Standard-B")]
-            #[strum(to_string = "Google.en-US-Standard-B")]
-            StandardB,
-            #[serde(rename = "Google.en-US-Standard-D")]
-            #[strum(to_string = "Google.en-US-Standard-D")]
-            StandardD,
-            #[serde(rename = "Google.en-US-Standard-I")]
-            #[strum(to_string = "Google.en-US-Standard-I")]
-            StandardI,
-            #[serde(rename = "Google.en-US-Standard-J")]
-            #[strum(to_string = "Google.en-US-Standard-J")]
-            StandardJ,
+            #[serde(rename = "Google.en-US-Neural2-A")]
+            #[strum(to_string = "Google.en-US-Neural2-A")]
+            Neural2A,
+            #[serde(rename = "Google.en-US-Neural2-D")]
+            #[strum(to_string = "Google.en-US-Neural2-D")]
+            Neural2D,
+            #[serde(rename = "Google.en-US-Neural2-I")]
+            #[strum(to_string = "Google.en-US-Neural2-I")]
+            Neural2I,
+            #[serde(rename = "Google.en-US-Neural2-J")]
+            #[strum(to_string = "Google.en-US-Neural2-J")]
+            Neural2J,
+            #[serde(rename = "Google.en-US-Wavenet-A")]
+            #[strum(to_string = "Google.en-US-Wavenet-A")]
+            WavenetA,
+            #[serde(rename = "Google.en-US-Wavenet-B")]
+            #[strum(to_string = "Google.en-US-Wavenet-B")]
+            WavenetB,
+            #[serde(rename = "Google.en-US-Wavenet-D")]
+            #[strum(to_string = "Google.en-US-Wavenet-D")]
+            WavenetD,
+            #[serde(rename = "Google.en-US-Wavenet-I")]
+            #[strum(to_string = "Google.en-US-Wavenet-I")]
+            WavenetI,
+            #[serde(rename = "Google.en-US-Wavenet-J")]
+            #[strum(to_string = "Google.en-US-Wavenet-J")]
+            WavenetJ,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -307,7 +337,7 @@ pub mod standard {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::EnUs(super::super::Voice::Standard(super::Voice::Google(
+                Self::EnUs(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -322,7 +352,7 @@ pub mod standard {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -341,102 +371,139 @@ pub mod standard {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Joey")]
-            #[strum(to_string = "Polly.Joey")]
-            Joey,
-            #[serde(rename = "Polly.Justin")]
-            #[strum(to_string = "Polly.Justin")]
-            Justin,
-            #[serde(rename = "Polly.Matthew")]
-            #[strum(to_string = "Polly.Matthew")]
-            Matthew,
+        pub enum Female {
+            #[serde(rename = "Polly.Danielle-Neural")]
+            #[strum(to_string = "Polly.Danielle-Neural")]
+            DanielleNeural,
+            #[serde(rename = "Polly.Joanna-Neural")]
+            #[strum(to_string = "Polly.Joanna-Neural")]
+            JoannaNeural,
+            #[serde(rename = "Polly.Kendra-Neural")]
+            #[strum(to_string = "Polly.Kendra-Neural")]
+            KendraNeural,
+            #[serde(rename = "Polly.Kimberly-Neural")]
+            #[strum(to_string = "Polly.Kimberly-Neural")]
+            KimberlyNeural,
+            #[serde(rename = "Polly.Ruth-Neural")]
+            #[strum(to_string = "Polly.Ruth-Neural")]
+            RuthNeural,
+            #[serde(rename = "Polly.Salli-Neural")]
+            #[strum(to_string = "Polly.Salli-Neural")]
+            SalliNeural,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EnUs(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EnUs(super::super::Voice::Neural(super::Voice::Polly(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum MaleChild {
-            #[serde(rename = "Polly.Kevin")]
-            #[strum(to_string = "Polly.Kevin")]
-            Kevin,
+        pub enum FemaleChild {
+            #[serde(rename = "Polly.Ivy-Neural")]
+            #[strum(to_string = "Polly.Ivy-Neural")]
+            IvyNeural,
         }
 
-        impl VoicePrice for MaleChild {
+        impl VoicePrice for FemaleChild {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for MaleChild {
+        impl VoiceGender for FemaleChild {
             fn gender(&self) -> Gender {
-                Gender::MaleChild
+                Gender::FemaleChild
             }
         }
 
-        impl From<MaleChild> for crate::twiml::Voice {
-            fn from(value: MaleChild) -> Self {
-                Self::EnUs(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::MaleChild(value),
+        impl From<FemaleChild> for crate::twiml::Voice {
+            fn from(value: FemaleChild) -> Self {
+                Self::EnUs(super::super::Voice::Neural(super::Voice::Polly(
+                    Voice::FemaleChild(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Ivy")]
-            #[strum(to_string = "Polly.Ivy")]
-            Ivy,
-            #[serde(rename = "Polly.Joanna")]
-            #[strum(to_string = "Polly.Joanna")]
-            Joanna,
-            #[serde(rename = "Polly.Kendra")]
-            #[strum(to_string = "Polly.Kendra")]
-            Kendra,
-            #[serde(rename = "Polly.Kimberly")]
-            #[strum(to_string = "Polly.Kimberly")]
-            Kimberly,
-            #[serde(rename = "Polly.Salli")]
-            #[strum(to_string = "Polly.Salli")]
-            Salli,
+        pub enum Male {
+            #[serde(rename = "Polly.Gregory-Neural")]
+            #[strum(to_string = "Polly.Gregory-Neural")]
+            GregoryNeural,
+            #[serde(rename = "Polly.Joey-Neural")]
+            #[strum(to_string = "Polly.Joey-Neural")]
+            JoeyNeural,
+            #[serde(rename = "Polly.Matthew-Neural")]
+            #[strum(to_string = "Polly.Matthew-Neural")]
+            MatthewNeural,
+            #[serde(rename = "Polly.Stephen-Neural")]
+            #[strum(to_string = "Polly.Stephen-Neural")]
+            StephenNeural,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EnUs(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EnUs(super::super::Voice::Neural(super::Voice::Polly(
+                    Voice::Male(value),
+                )))
+            }
+        }
+
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum MaleChild {
+            #[serde(rename = "Polly.Justin-Neural")]
+            #[strum(to_string = "Polly.Justin-Neural")]
+            JustinNeural,
+            #[serde(rename = "Polly.Kevin-Neural")]
+            #[strum(to_string = "Polly.Kevin-Neural")]
+            KevinNeural,
+        }
+
+        impl VoicePrice for MaleChild {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for MaleChild {
+            fn gender(&self) -> Gender {
+                Gender::MaleChild
+            }
+        }
+
+        impl From<MaleChild> for crate::twiml::Voice {
+            fn from(value: MaleChild) -> Self {
+                Self::EnUs(super::super::Voice::Neural(super::Voice::Polly(
+                    Voice::MaleChild(value),
                 )))
             }
         }
@@ -444,23 +511,25 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
+            Female(Female),
+            FemaleChild(FemaleChild),
             Male(Male),
             MaleChild(MaleChild),
-            Female(Female),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
+                    Voice::Female(_) => Gender::Female,
+                    Voice::FemaleChild(_) => Gender::FemaleChild,
                     Voice::Male(_) => Gender::Male,
                     Voice::MaleChild(_) => Gender::MaleChild,
-                    Voice::Female(_) => Gender::Female,
                 }
             }
         }
@@ -475,7 +544,7 @@ pub mod standard {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
@@ -489,67 +558,48 @@ pub mod standard {
     }
 }
 
-pub mod neural {
+pub mod standard {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum MaleChild {
-            #[serde(rename = "Polly.Justin-Neural")]
-            #[strum(to_string = "Polly.Justin-Neural")]
-            JustinNeural,
-            #[serde(rename = "Polly.Kevin-Neural")]
-            #[strum(to_string = "Polly.Kevin-Neural")]
-            KevinNeural,
-        }
-
-        impl VoicePrice for MaleChild {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for MaleChild {
-            fn gender(&self) -> Gender {
-                Gender::MaleChild
-            }
-        }
-
-        impl From<MaleChild> for crate::twiml::Voice {
-            fn from(value: MaleChild) -> Self {
-                Self::EnUs(super::super::Voice::Neural(super::Voice::Polly(
-                    Voice::MaleChild(value),
-                )))
-            }
-        }
-
-        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
-        #[non_exhaustive]
-        pub enum FemaleChild {
-            #[serde(rename = "Polly.Ivy-Neural")]
-            #[strum(to_string = "Polly.Ivy-Neural")]
-            IvyNeural,
+        pub enum Female {
+            #[serde(rename = "Google.en-US-Standard-C")]
+            #[strum(to_string = "Google.en-US-Standard-C")]
+            StandardC,
+            #[serde(rename = "Google.en-US-Standard-E")]
+            #[strum(to_string = "Google.en-US-Standard-E")]
+            StandardE,
+            #[serde(rename = "Google.en-US-Standard-F")]
+            #[strum(to_string = "Google.en-US-Standard-F")]
+            StandardF,
+            #[serde(rename = "Google.en-US-Standard-G")]
+            #[strum(to_string = "Google.en-US-Standard-G")]
+            StandardG,
+            #[serde(rename = "Google.en-US-Standard-H")]
+            #[strum(to_string = "Google.en-US-Standard-H")]
+            StandardH,
         }
 
-        impl VoicePrice for FemaleChild {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for FemaleChild {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::FemaleChild
+                Gender::Female
             }
         }
 
-        impl From<FemaleChild> for crate::twiml::Voice {
-            fn from(value: FemaleChild) -> Self {
-                Self::EnUs(super::super::Voice::Neural(super::Voice::Polly(
-                    Voice::FemaleChild(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EnUs(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
@@ -557,23 +607,26 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Polly.Gregory-Neural")]
-            #[strum(to_string = "Polly.Gregory-Neural")]
-            GregoryNeural,
-            #[serde(rename = "Polly.Joey-Neural")]
-            #[strum(to_string = "Polly.Joey-Neural")]
-            JoeyNeural,
-            #[serde(rename = "Polly.Matthew-Neural")]
-            #[strum(to_string = "Polly.Matthew-Neural")]
-            MatthewNeural,
-            #[serde(rename = "Polly.Stephen-Neural")]
-            #[strum(to_string = "Polly.Stephen-Neural")]
-            StephenNeural,
+            #[serde(rename = "Google.en-US-Standard-A")]
+            #[strum(to_string = "Google.en-US-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.en-US-Standard-B")]
+            #[strum(to_string = "Google.en-US-Standard-B")]
+            StandardB,
+            #[serde(rename = "Google.en-US-Standard-D")]
+            #[strum(to_string = "Google.en-US-Standard-D")]
+            StandardD,
+            #[serde(rename = "Google.en-US-Standard-I")]
+            #[strum(to_string = "Google.en-US-Standard-I")]
+            StandardI,
+            #[serde(rename = "Google.en-US-Standard-J")]
+            #[strum(to_string = "Google.en-US-Standard-J")]
+            StandardJ,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -585,123 +638,61 @@ pub mod neural {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::EnUs(super::super::Voice::Neural(super::Voice::Polly(
+                Self::EnUs(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
         }
 
-        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
-        #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Danielle-Neural")]
-            #[strum(to_string = "Polly.Danielle-Neural")]
-            DanielleNeural,
-            #[serde(rename = "Polly.Joanna-Neural")]
-            #[strum(to_string = "Polly.Joanna-Neural")]
-            JoannaNeural,
-            #[serde(rename = "Polly.Kendra-Neural")]
-            #[strum(to_string = "Polly.Kendra-Neural")]
-            KendraNeural,
-            #[serde(rename = "Polly.Kimberly-Neural")]
-            #[strum(to_string = "Polly.Kimberly-Neural")]
-            KimberlyNeural,
-            #[serde(rename = "Polly.Ruth-Neural")]
-            #[strum(to_string = "Polly.Ruth-Neural")]
-            RuthNeural,
-            #[serde(rename = "Polly.Salli-Neural")]
-            #[strum(to_string = "Polly.Salli-Neural")]
-            SalliNeural,
-        }
-
-        impl VoicePrice for Female {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Female {
-            fn gender(&self) -> Gender {
-                Gender::Female
-            }
-        }
-
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EnUs(super::super::Voice::Neural(super::Voice::Polly(
-                    Voice::Female(value),
-                )))
-            }
-        }
-
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            MaleChild(MaleChild),
-            FemaleChild(FemaleChild),
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::MaleChild(_) => Gender::MaleChild,
-                    Voice::FemaleChild(_) => Gender::FemaleChild,
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
     }
 
-    pub mod google {
+    pub mod polly {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.en-US-Neural2-C")]
-            #[strum(to_string = "Google.en-US-Neural2-C")]
-            Neural2C,
-            #[serde(rename = "Google.en-US-Neural2-E")]
-            #[strum(to_string = "Google.en-US-Neural2-E")]
-            Neural2E,
-            #[serde(rename = "Google.en-US-Neural2-F")]
-            #[strum(to_string = "Google.en-US-Neural2-F")]
-            Neural2F,
-            #[serde(rename = "Google.en-US-Neural2-G")]
-            #[strum(to_string = "Google.en-US-Neural2-G")]
-            Neural2G,
-            #[serde(rename = "Google.en-US-Neural2-H")]
-            #[strum(to_string = "Google.en-US-Neural2-H")]
-            Neural2H,
-            #[serde(rename = "Google.en-US-Wavenet-C")]
-            #[strum(to_string = "Google.en-US-Wavenet-C")]
-            WavenetC,
-            #[serde(rename = "Google.en-US-Wavenet-E")]
-            #[strum(to_string = "Google.en-US-Wavenet-E")]
-            WavenetE,
-            #[serde(rename = "Google.en-US-Wavenet-F")]
-            #[strum(to_string = "Google.en-US-Wavenet-F")]
-            WavenetF,
-            #[serde(rename = "Google.en-US-Wavenet-G")]
-            #[strum(to_string = "Google.en-US-Wavenet-G")]
-            WavenetG,
-            #[serde(rename = "Google.en-US-Wavenet-H")]
-            #[strum(to_string = "Google.en-US-Wavenet-H")]
-            WavenetH,
+            #[serde(rename = "Polly.Ivy")]
+            #[strum(to_string = "Polly.Ivy")]
+            Ivy,
+            #[serde(rename = "Polly.Joanna")]
+            #[strum(to_string = "Polly.Joanna")]
+            Joanna,
+            #[serde(rename = "Polly.Kendra")]
+            #[strum(to_string = "Polly.Kendra")]
+            Kendra,
+            #[serde(rename = "Polly.Kimberly")]
+            #[strum(to_string = "Polly.Kimberly")]
+            Kimberly,
+            #[serde(rename = "Polly.Salli")]
+            #[strum(to_string = "Polly.Salli")]
+            Salli,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -713,7 +704,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EnUs(super::super::Voice::Neural(super::Voice::Google(
+                Self::EnUs(super::super::Voice::Standard(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -722,38 +713,20 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.en-US-Neural2-A")]
-            #[strum(to_string = "Google.en-US-Neural2-A")]
-            Neural2A,
-            #[serde(rename = "Google.en-US-Neural2-D")]
-            #[strum(to_string = "Google.en-US-Neural2-D")]
-            Neural2D,
-            #[serde(rename = "Google.en-US-Neural2-I")]
-            #[strum(to_string = "Google.en-US-Neural2-I")]
-            Neural2I,
-            #[serde(rename = "Google.en-US-Neural2-J")]
-            #[strum(to_string = "Google.en-US-Neural2-J")]
-            Neural2J,
-            #[serde(rename = "Google.en-US-Wavenet-A")]
-            #[strum(to_string = "Google.en-US-Wavenet-A")]
-            WavenetA,
-            #[serde(rename = "Google.en-US-Wavenet-B")]
-            #[strum(to_string = "Google.en-US-Wavenet-B")]
-            WavenetB,
-            #[serde(rename = "Google.en-US-Wavenet-D")]
-            #[strum(to_string = "Google.en-US-Wavenet-D")]
-            WavenetD,
-            #[serde(rename = "Google.en-US-Wavenet-I")]
-            #[strum(to_string = "Google.en-US-Wavenet-I")]
-            WavenetI,
-            #[serde(rename = "Google.en-US-Wavenet-J")]
-            #[strum(to_string = "Google.en-US-Wavenet-J")]
-            WavenetJ,
+            #[serde(rename = "Polly.Joey")]
+            #[strum(to_string = "Polly.Joey")]
+            Joey,
+            #[serde(rename = "Polly.Justin")]
+            #[strum(to_string = "Polly.Justin")]
+            Justin,
+            #[serde(rename = "Polly.Matthew")]
+            #[strum(to_string = "Polly.Matthew")]
+            Matthew,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -765,22 +738,51 @@ pub mod neural {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::EnUs(super::super::Voice::Neural(super::Voice::Google(
+                Self::EnUs(super::super::Voice::Standard(super::Voice::Polly(
                     Voice::Male(value),
                 )))
             }
         }
 
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum MaleChild {
+            #[serde(rename = "Polly.Kevin")]
+            #[strum(to_string = "Polly.Kevin")]
+            Kevin,
+        }
+
+        impl VoicePrice for MaleChild {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for MaleChild {
+            fn gender(&self) -> Gender {
+                Gender::MaleChild
+            }
+        }
+
+        impl From<MaleChild> for crate::twiml::Voice {
+            fn from(value: MaleChild) -> Self {
+                Self::EnUs(super::super::Voice::Standard(super::Voice::Polly(
+                    Voice::MaleChild(value),
+                )))
+            }
+        }
+
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
             Female(Female),
             Male(Male),
+            MaleChild(MaleChild),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -789,6 +791,7 @@ pub mod neural {
                 match self {
                     Voice::Female(_) => Gender::Female,
                     Voice::Male(_) => Gender::Male,
+                    Voice::MaleChild(_) => Gender::MaleChild,
                 }
             }
         }
@@ -797,21 +800,21 @@ pub mod neural {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
@@ -821,15 +824,15 @@ pub mod neural {
 #[serde(untagged)]
 pub enum Voice {
     Generative(generative::Voice),
-    Standard(standard::Voice),
     Neural(neural::Voice),
+    Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
             Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
-            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
 }
@@ -838,8 +841,652 @@ impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
             Voice::Generative(voice) => voice.gender(),
-            Voice::Standard(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
+            Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.en-US-Chirp3-HD-Aoede",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.en-US-Chirp3-HD-Kore",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.en-US-Chirp3-HD-Leda",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.en-US-Chirp3-HD-Zephyr",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.en-US-Chirp3-HD-Charon",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.en-US-Chirp3-HD-Fenrir",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.en-US-Chirp3-HD-Orus",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.en-US-Chirp3-HD-Puck",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Female(generative::polly::Female::DanielleGenerative),
+        ))),
+        id: "Polly.Danielle-Generative",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Female(generative::polly::Female::JoannaGenerative),
+        ))),
+        id: "Polly.Joanna-Generative",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Female(generative::polly::Female::RuthGenerative),
+        ))),
+        id: "Polly.Ruth-Generative",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Male(generative::polly::Male::MatthewGenerative),
+        ))),
+        id: "Polly.Matthew-Generative",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Male(generative::polly::Male::StephenGenerative),
+        ))),
+        id: "Polly.Stephen-Generative",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2C),
+        ))),
+        id: "Google.en-US-Neural2-C",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2E),
+        ))),
+        id: "Google.en-US-Neural2-E",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2F),
+        ))),
+        id: "Google.en-US-Neural2-F",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2G),
+        ))),
+        id: "Google.en-US-Neural2-G",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2H),
+        ))),
+        id: "Google.en-US-Neural2-H",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetC),
+        ))),
+        id: "Google.en-US-Wavenet-C",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetE),
+        ))),
+        id: "Google.en-US-Wavenet-E",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetF),
+        ))),
+        id: "Google.en-US-Wavenet-F",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetG),
+        ))),
+        id: "Google.en-US-Wavenet-G",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetH),
+        ))),
+        id: "Google.en-US-Wavenet-H",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2A),
+        ))),
+        id: "Google.en-US-Neural2-A",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2D),
+        ))),
+        id: "Google.en-US-Neural2-D",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2I),
+        ))),
+        id: "Google.en-US-Neural2-I",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2J),
+        ))),
+        id: "Google.en-US-Neural2-J",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetA),
+        ))),
+        id: "Google.en-US-Wavenet-A",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.en-US-Wavenet-B",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetD),
+        ))),
+        id: "Google.en-US-Wavenet-D",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetI),
+        ))),
+        id: "Google.en-US-Wavenet-I",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetJ),
+        ))),
+        id: "Google.en-US-Wavenet-J",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::DanielleNeural),
+        ))),
+        id: "Polly.Danielle-Neural",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::JoannaNeural),
+        ))),
+        id: "Polly.Joanna-Neural",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::KendraNeural),
+        ))),
+        id: "Polly.Kendra-Neural",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::KimberlyNeural),
+        ))),
+        id: "Polly.Kimberly-Neural",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::RuthNeural),
+        ))),
+        id: "Polly.Ruth-Neural",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::SalliNeural),
+        ))),
+        id: "Polly.Salli-Neural",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::FemaleChild(neural::polly::FemaleChild::IvyNeural),
+        ))),
+        id: "Polly.Ivy-Neural",
+        language: Language::EnUs,
+        gender: Gender::FemaleChild,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Male(neural::polly::Male::GregoryNeural),
+        ))),
+        id: "Polly.Gregory-Neural",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Male(neural::polly::Male::JoeyNeural),
+        ))),
+        id: "Polly.Joey-Neural",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Male(neural::polly::Male::MatthewNeural),
+        ))),
+        id: "Polly.Matthew-Neural",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Male(neural::polly::Male::StephenNeural),
+        ))),
+        id: "Polly.Stephen-Neural",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::MaleChild(neural::polly::MaleChild::JustinNeural),
+        ))),
+        id: "Polly.Justin-Neural",
+        language: Language::EnUs,
+        gender: Gender::MaleChild,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::MaleChild(neural::polly::MaleChild::KevinNeural),
+        ))),
+        id: "Polly.Kevin-Neural",
+        language: Language::EnUs,
+        gender: Gender::MaleChild,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardC),
+        ))),
+        id: "Google.en-US-Standard-C",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardE),
+        ))),
+        id: "Google.en-US-Standard-E",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardF),
+        ))),
+        id: "Google.en-US-Standard-F",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardG),
+        ))),
+        id: "Google.en-US-Standard-G",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardH),
+        ))),
+        id: "Google.en-US-Standard-H",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardA),
+        ))),
+        id: "Google.en-US-Standard-A",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.en-US-Standard-B",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardD),
+        ))),
+        id: "Google.en-US-Standard-D",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardI),
+        ))),
+        id: "Google.en-US-Standard-I",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardJ),
+        ))),
+        id: "Google.en-US-Standard-J",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Ivy),
+        ))),
+        id: "Polly.Ivy",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Joanna),
+        ))),
+        id: "Polly.Joanna",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Kendra),
+        ))),
+        id: "Polly.Kendra",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Kimberly),
+        ))),
+        id: "Polly.Kimberly",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Salli),
+        ))),
+        id: "Polly.Salli",
+        language: Language::EnUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Male(standard::polly::Male::Joey),
+        ))),
+        id: "Polly.Joey",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Male(standard::polly::Male::Justin),
+        ))),
+        id: "Polly.Justin",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Male(standard::polly::Male::Matthew),
+        ))),
+        id: "Polly.Matthew",
+        language: Language::EnUs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EnUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::MaleChild(standard::polly::MaleChild::Kevin),
+        ))),
+        id: "Polly.Kevin",
+        language: Language::EnUs,
+        gender: Gender::MaleChild,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
@@ -4,13 +4,16 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-pub mod standard {
+pub mod generative {
     use super::*;
 
     pub mod google {
@@ -18,65 +21,74 @@ pub mod standard {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.es-ES-Standard-E")]
-            #[strum(to_string = "Google.es-ES-Standard-E")]
-            StandardE,
-            #[serde(rename = "Google.es-ES-Standard-G")]
-            #[strum(to_string = "Google.es-ES-Standard-G")]
-            StandardG,
+        pub enum Female {
+            #[serde(rename = "Google.es-ES-Chirp3-HD-Aoede")]
+            #[strum(to_string = "Google.es-ES-Chirp3-HD-Aoede")]
+            Chirp3HdAoede,
+            #[serde(rename = "Google.es-ES-Chirp3-HD-Kore")]
+            #[strum(to_string = "Google.es-ES-Chirp3-HD-Kore")]
+            Chirp3HdKore,
+            #[serde(rename = "Google.es-ES-Chirp3-HD-Leda")]
+            #[strum(to_string = "Google.es-ES-Chirp3-HD-Leda")]
+            Chirp3HdLeda,
+            #[serde(rename = "Google.es-ES-Chirp3-HD-Zephyr")]
+            #[strum(to_string = "Google.es-ES-Chirp3-HD-Zephyr")]
+            Chirp3HdZephyr,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EsEs(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EsEs(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.es-ES-Standard-A")]
-            #[strum(to_string = "Google.es-ES-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.es-ES-Standard-F")]
-            #[strum(to_string = "Google.es-ES-Standard-F")]
-            StandardF,
-            #[serde(rename = "Google.es-ES-Standard-H")]
-            #[strum(to_string = "Google.es-ES-Standard-H")]
-            StandardH,
+        pub enum Male {
+            #[serde(rename = "Google.es-ES-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.es-ES-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.es-ES-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.es-ES-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.es-ES-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.es-ES-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.es-ES-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.es-ES-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EsEs(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EsEs(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -84,21 +96,21 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -109,59 +121,56 @@ pub mod standard {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Enrique")]
-            #[strum(to_string = "Polly.Enrique")]
-            Enrique,
+        pub enum Female {
+            #[serde(rename = "Polly.Lucia-Generative")]
+            #[strum(to_string = "Polly.Lucia-Generative")]
+            LuciaGenerative,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EsEs(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EsEs(super::super::Voice::Generative(super::Voice::Polly(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Conchita")]
-            #[strum(to_string = "Polly.Conchita")]
-            Conchita,
-            #[serde(rename = "Polly.Lucia")]
-            #[strum(to_string = "Polly.Lucia")]
-            Lucia,
+        pub enum Male {
+            #[serde(rename = "Polly.Sergio-Generative")]
+            #[strum(to_string = "Polly.Sergio-Generative")]
+            SergioGenerative,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EsEs(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EsEs(super::super::Voice::Generative(super::Voice::Polly(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -169,21 +178,21 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -198,7 +207,7 @@ pub mod standard {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
         }
     }
 
@@ -212,7 +221,7 @@ pub mod standard {
     }
 }
 
-pub mod generative {
+pub mod neural {
     use super::*;
 
     pub mod google {
@@ -220,74 +229,68 @@ pub mod generative {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.es-ES-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.es-ES-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.es-ES-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.es-ES-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.es-ES-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.es-ES-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.es-ES-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.es-ES-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
+        pub enum Female {
+            #[serde(rename = "Google.es-ES-Neural2-H")]
+            #[strum(to_string = "Google.es-ES-Neural2-H")]
+            Neural2H,
+            #[serde(rename = "Google.es-ES-Wavenet-F")]
+            #[strum(to_string = "Google.es-ES-Wavenet-F")]
+            WavenetF,
+            #[serde(rename = "Google.es-ES-Wavenet-H")]
+            #[strum(to_string = "Google.es-ES-Wavenet-H")]
+            WavenetH,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EsEs(super::super::Voice::Generative(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EsEs(super::super::Voice::Neural(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.es-ES-Chirp3-HD-Aoede")]
-            #[strum(to_string = "Google.es-ES-Chirp3-HD-Aoede")]
-            Chirp3HdAoede,
-            #[serde(rename = "Google.es-ES-Chirp3-HD-Kore")]
-            #[strum(to_string = "Google.es-ES-Chirp3-HD-Kore")]
-            Chirp3HdKore,
-            #[serde(rename = "Google.es-ES-Chirp3-HD-Leda")]
-            #[strum(to_string = "Google.es-ES-Chirp3-HD-Leda")]
-            Chirp3HdLeda,
-            #[serde(rename = "Google.es-ES-Chirp3-HD-Zephyr")]
-            #[strum(to_string = "Google.es-ES-Chirp3-HD-Zephyr")]
-            Chirp3HdZephyr,
+        pub enum Male {
+            #[serde(rename = "Google.es-ES-Neural2-G")]
+            #[strum(to_string = "Google.es-ES-Neural2-G")]
+            Neural2G,
+            #[serde(rename = "Google.es-ES-Wavenet-E")]
+            #[strum(to_string = "Google.es-ES-Wavenet-E")]
+            WavenetE,
+            #[serde(rename = "Google.es-ES-Wavenet-G")]
+            #[strum(to_string = "Google.es-ES-Wavenet-G")]
+            WavenetG,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EsEs(super::super::Voice::Generative(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EsEs(super::super::Voice::Neural(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -295,21 +298,21 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -320,56 +323,56 @@ pub mod generative {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Sergio-Generative")]
-            #[strum(to_string = "Polly.Sergio-Generative")]
-            SergioGenerative,
+        pub enum Female {
+            #[serde(rename = "Polly.Lucia-Neural")]
+            #[strum(to_string = "Polly.Lucia-Neural")]
+            LuciaNeural,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EsEs(super::super::Voice::Generative(super::Voice::Polly(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EsEs(super::super::Voice::Neural(super::Voice::Polly(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Lucia-Generative")]
-            #[strum(to_string = "Polly.Lucia-Generative")]
-            LuciaGenerative,
+        pub enum Male {
+            #[serde(rename = "Polly.Sergio-Neural")]
+            #[strum(to_string = "Polly.Sergio-Neural")]
+            SergioNeural,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EsEs(super::super::Voice::Generative(super::Voice::Polly(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EsEs(super::super::Voice::Neural(super::Voice::Polly(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -377,21 +380,21 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -406,7 +409,7 @@ pub mod generative {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
@@ -420,7 +423,7 @@ pub mod generative {
     }
 }
 
-pub mod neural {
+pub mod standard {
     use super::*;
 
     pub mod google {
@@ -428,68 +431,65 @@ pub mod neural {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.es-ES-Neural2-G")]
-            #[strum(to_string = "Google.es-ES-Neural2-G")]
-            Neural2G,
-            #[serde(rename = "Google.es-ES-Wavenet-E")]
-            #[strum(to_string = "Google.es-ES-Wavenet-E")]
-            WavenetE,
-            #[serde(rename = "Google.es-ES-Wavenet-G")]
-            #[strum(to_string = "Google.es-ES-Wavenet-G")]
-            WavenetG,
+        pub enum Female {
+            #[serde(rename = "Google.es-ES-Standard-A")]
+            #[strum(to_string = "Google.es-ES-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.es-ES-Standard-F")]
+            #[strum(to_string = "Google.es-ES-Standard-F")]
+            StandardF,
+            #[serde(rename = "Google.es-ES-Standard-H")]
+            #[strum(to_string = "Google.es-ES-Standard-H")]
+            StandardH,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EsEs(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EsEs(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.es-ES-Neural2-H")]
-            #[strum(to_string = "Google.es-ES-Neural2-H")]
-            Neural2H,
-            #[serde(rename = "Google.es-ES-Wavenet-F")]
-            #[strum(to_string = "Google.es-ES-Wavenet-F")]
-            WavenetF,
-            #[serde(rename = "Google.es-ES-Wavenet-H")]
-            #[strum(to_string = "Google.es-ES-Wavenet-H")]
-            WavenetH,
+        pub enum Male {
+            #[serde(rename = "Google.es-ES-Standard-E")]
+            #[strum(to_string = "Google.es-ES-Standard-E")]
+            StandardE,
+            #[serde(rename = "Google.es-ES-Standard-G")]
+            #[strum(to_string = "Google.es-ES-Standard-G")]
+            StandardG,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EsEs(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EsEs(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -497,21 +497,21 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -522,56 +522,59 @@ pub mod neural {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Sergio-Neural")]
-            #[strum(to_string = "Polly.Sergio-Neural")]
-            SergioNeural,
+        pub enum Female {
+            #[serde(rename = "Polly.Conchita")]
+            #[strum(to_string = "Polly.Conchita")]
+            Conchita,
+            #[serde(rename = "Polly.Lucia")]
+            #[strum(to_string = "Polly.Lucia")]
+            Lucia,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EsEs(super::super::Voice::Neural(super::Voice::Polly(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EsEs(super::super::Voice::Standard(super::Voice::Polly(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Lucia-Neural")]
-            #[strum(to_string = "Polly.Lucia-Neural")]
-            LuciaNeural,
+        pub enum Male {
+            #[serde(rename = "Polly.Enrique")]
+            #[strum(to_string = "Polly.Enrique")]
+            Enrique,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EsEs(super::super::Voice::Neural(super::Voice::Polly(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EsEs(super::super::Voice::Standard(super::Voice::Polly(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -579,21 +582,21 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -608,7 +611,7 @@ pub mod neural {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
@@ -625,16 +628,16 @@ pub mod neural {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Voice {
-    Standard(standard::Voice),
     Generative(generative::Voice),
     Neural(neural::Voice),
+    Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
-            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
             Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
 }
@@ -642,9 +645,273 @@ impl VoicePrice for Voice {
 impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
-            Voice::Standard(voice) => voice.gender(),
             Voice::Generative(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
+            Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.es-ES-Chirp3-HD-Aoede",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.es-ES-Chirp3-HD-Kore",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.es-ES-Chirp3-HD-Leda",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.es-ES-Chirp3-HD-Zephyr",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.es-ES-Chirp3-HD-Charon",
+        language: Language::EsEs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.es-ES-Chirp3-HD-Fenrir",
+        language: Language::EsEs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.es-ES-Chirp3-HD-Orus",
+        language: Language::EsEs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.es-ES-Chirp3-HD-Puck",
+        language: Language::EsEs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Female(generative::polly::Female::LuciaGenerative),
+        ))),
+        id: "Polly.Lucia-Generative",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Male(generative::polly::Male::SergioGenerative),
+        ))),
+        id: "Polly.Sergio-Generative",
+        language: Language::EsEs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2H),
+        ))),
+        id: "Google.es-ES-Neural2-H",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetF),
+        ))),
+        id: "Google.es-ES-Wavenet-F",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetH),
+        ))),
+        id: "Google.es-ES-Wavenet-H",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2G),
+        ))),
+        id: "Google.es-ES-Neural2-G",
+        language: Language::EsEs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetE),
+        ))),
+        id: "Google.es-ES-Wavenet-E",
+        language: Language::EsEs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetG),
+        ))),
+        id: "Google.es-ES-Wavenet-G",
+        language: Language::EsEs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::LuciaNeural),
+        ))),
+        id: "Polly.Lucia-Neural",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Male(neural::polly::Male::SergioNeural),
+        ))),
+        id: "Polly.Sergio-Neural",
+        language: Language::EsEs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.es-ES-Standard-A",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardF),
+        ))),
+        id: "Google.es-ES-Standard-F",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardH),
+        ))),
+        id: "Google.es-ES-Standard-H",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardE),
+        ))),
+        id: "Google.es-ES-Standard-E",
+        language: Language::EsEs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardG),
+        ))),
+        id: "Google.es-ES-Standard-G",
+        language: Language::EsEs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Conchita),
+        ))),
+        id: "Polly.Conchita",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Lucia),
+        ))),
+        id: "Polly.Lucia",
+        language: Language::EsEs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsEs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Male(standard::polly::Male::Enrique),
+        ))),
+        id: "Polly.Enrique",
+        language: Language::EsEs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
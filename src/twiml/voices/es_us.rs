@@ -4,13 +4,16 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-pub mod neural {
+pub mod generative {
     use super::*;
 
     pub mod google {
@@ -18,68 +21,74 @@ pub mod neural {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.es-US-Neural2-B")]
-            #[strum(to_string = "Google.es-US-Neural2-B")]
-            Neural2B,
-            #[serde(rename = "Google.es-US-Neural2-C")]
-            #[strum(to_string = "Google.es-US-Neural2-C")]
-            Neural2C,
-            #[serde(rename = "Google.es-US-Wavenet-B")]
-            #[strum(to_string = "Google.es-US-Wavenet-B")]
-            WavenetB,
-            #[serde(rename = "Google.es-US-Wavenet-C")]
-            #[strum(to_string = "Google.es-US-Wavenet-C")]
-            WavenetC,
+        pub enum Female {
+            #[serde(rename = "Google.es-US-Chirp3-HD-Aoede")]
+            #[strum(to_string = "Google.es-US-Chirp3-HD-Aoede")]
+            Chirp3HdAoede,
+            #[serde(rename = "Google.es-US-Chirp3-HD-Kore")]
+            #[strum(to_string = "Google.es-US-Chirp3-HD-Kore")]
+            Chirp3HdKore,
+            #[serde(rename = "Google.es-US-Chirp3-HD-Leda")]
+            #[strum(to_string = "Google.es-US-Chirp3-HD-Leda")]
+            Chirp3HdLeda,
+            #[serde(rename = "Google.es-US-Chirp3-HD-Zephyr")]
+            #[strum(to_string = "Google.es-US-Chirp3-HD-Zephyr")]
+            Chirp3HdZephyr,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EsUs(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EsUs(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.es-US-Neural2-A")]
-            #[strum(to_string = "Google.es-US-Neural2-A")]
-            Neural2A,
-            #[serde(rename = "Google.es-US-Wavenet-A")]
-            #[strum(to_string = "Google.es-US-Wavenet-A")]
-            WavenetA,
+        pub enum Male {
+            #[serde(rename = "Google.es-US-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.es-US-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.es-US-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.es-US-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.es-US-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.es-US-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.es-US-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.es-US-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EsUs(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EsUs(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -87,21 +96,21 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -112,56 +121,56 @@ pub mod neural {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Pedro-Neural")]
-            #[strum(to_string = "Polly.Pedro-Neural")]
-            PedroNeural,
+        pub enum Female {
+            #[serde(rename = "Polly.Lupe-Generative")]
+            #[strum(to_string = "Polly.Lupe-Generative")]
+            LupeGenerative,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EsUs(super::super::Voice::Neural(super::Voice::Polly(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EsUs(super::super::Voice::Generative(super::Voice::Polly(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Lupe-Neural")]
-            #[strum(to_string = "Polly.Lupe-Neural")]
-            LupeNeural,
+        pub enum Male {
+            #[serde(rename = "Polly.Pedro-Generative")]
+            #[strum(to_string = "Polly.Pedro-Generative")]
+            PedroGenerative,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EsUs(super::super::Voice::Neural(super::Voice::Polly(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EsUs(super::super::Voice::Generative(super::Voice::Polly(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -169,21 +178,21 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -198,7 +207,7 @@ pub mod neural {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
         }
     }
 
@@ -212,7 +221,7 @@ pub mod neural {
     }
 }
 
-pub mod standard {
+pub mod neural {
     use super::*;
 
     pub mod google {
@@ -221,14 +230,17 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.es-US-Standard-A")]
-            #[strum(to_string = "Google.es-US-Standard-A")]
-            StandardA,
+            #[serde(rename = "Google.es-US-Neural2-A")]
+            #[strum(to_string = "Google.es-US-Neural2-A")]
+            Neural2A,
+            #[serde(rename = "Google.es-US-Wavenet-A")]
+            #[strum(to_string = "Google.es-US-Wavenet-A")]
+            WavenetA,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -240,7 +252,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EsUs(super::super::Voice::Standard(super::Voice::Google(
+                Self::EsUs(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -249,17 +261,23 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.es-US-Standard-B")]
-            #[strum(to_string = "Google.es-US-Standard-B")]
-            StandardB,
-            #[serde(rename = "Google.es-US-Standard-C")]
-            #[strum(to_string = "Google.es-US-Standard-C")]
-            StandardC,
+            #[serde(rename = "Google.es-US-Neural2-B")]
+            #[strum(to_string = "Google.es-US-Neural2-B")]
+            Neural2B,
+            #[serde(rename = "Google.es-US-Neural2-C")]
+            #[strum(to_string = "Google.es-US-Neural2-C")]
+            Neural2C,
+            #[serde(rename = "Google.es-US-Wavenet-B")]
+            #[strum(to_string = "Google.es-US-Wavenet-B")]
+            WavenetB,
+            #[serde(rename = "Google.es-US-Wavenet-C")]
+            #[strum(to_string = "Google.es-US-Wavenet-C")]
+            WavenetC,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -271,7 +289,7 @@ pub mod standard {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::EsUs(super::super::Voice::Standard(super::Voice::Google(
+                Self::EsUs(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -286,7 +304,7 @@ pub mod standard {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -305,62 +323,56 @@ pub mod standard {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Miguel")]
-            #[strum(to_string = "Polly.Miguel")]
-            Miguel,
+        pub enum Female {
+            #[serde(rename = "Polly.Lupe-Neural")]
+            #[strum(to_string = "Polly.Lupe-Neural")]
+            LupeNeural,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EsUs(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EsUs(super::super::Voice::Neural(super::Voice::Polly(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Lupe")]
-            #[strum(to_string = "Polly.Lupe")]
-            Lupe,
-            #[serde(rename = "Polly.Penelope")]
-            #[strum(to_string = "Polly.Penelope")]
-            Penelope,
-            #[serde(rename = "Polly.Penélope")]
-            #[strum(to_string = "Polly.Penélope")]
-            Penélope,
+        pub enum Male {
+            #[serde(rename = "Polly.Pedro-Neural")]
+            #[strum(to_string = "Polly.Pedro-Neural")]
+            PedroNeural,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EsUs(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EsUs(super::super::Voice::Neural(super::Voice::Polly(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -368,21 +380,21 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -397,7 +409,7 @@ pub mod standard {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
@@ -411,7 +423,7 @@ pub mod standard {
     }
 }
 
-pub mod generative {
+pub mod standard {
     use super::*;
 
     pub mod google {
@@ -419,74 +431,59 @@ pub mod generative {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.es-US-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.es-US-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.es-US-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.es-US-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.es-US-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.es-US-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.es-US-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.es-US-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
+        pub enum Female {
+            #[serde(rename = "Google.es-US-Standard-A")]
+            #[strum(to_string = "Google.es-US-Standard-A")]
+            StandardA,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::EsUs(super::super::Voice::Generative(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::EsUs(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.es-US-Chirp3-HD-Aoede")]
-            #[strum(to_string = "Google.es-US-Chirp3-HD-Aoede")]
-            Chirp3HdAoede,
-            #[serde(rename = "Google.es-US-Chirp3-HD-Kore")]
-            #[strum(to_string = "Google.es-US-Chirp3-HD-Kore")]
-            Chirp3HdKore,
-            #[serde(rename = "Google.es-US-Chirp3-HD-Leda")]
-            #[strum(to_string = "Google.es-US-Chirp3-HD-Leda")]
-            Chirp3HdLeda,
-            #[serde(rename = "Google.es-US-Chirp3-HD-Zephyr")]
-            #[strum(to_string = "Google.es-US-Chirp3-HD-Zephyr")]
-            Chirp3HdZephyr,
+        pub enum Male {
+            #[serde(rename = "Google.es-US-Standard-B")]
+            #[strum(to_string = "Google.es-US-Standard-B")]
+            StandardB,
+            #[serde(rename = "Google.es-US-Standard-C")]
+            #[strum(to_string = "Google.es-US-Standard-C")]
+            StandardC,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::EsUs(super::super::Voice::Generative(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::EsUs(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -494,21 +491,21 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -520,14 +517,20 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Lupe-Generative")]
-            #[strum(to_string = "Polly.Lupe-Generative")]
-            LupeGenerative,
+            #[serde(rename = "Polly.Lupe")]
+            #[strum(to_string = "Polly.Lupe")]
+            Lupe,
+            #[serde(rename = "Polly.Penelope")]
+            #[strum(to_string = "Polly.Penelope")]
+            Penelope,
+            #[serde(rename = "Polly.Penélope")]
+            #[strum(to_string = "Polly.Penélope")]
+            Penélope,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -539,7 +542,7 @@ pub mod generative {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::EsUs(super::super::Voice::Generative(super::Voice::Polly(
+                Self::EsUs(super::super::Voice::Standard(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -548,14 +551,14 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Polly.Pedro-Generative")]
-            #[strum(to_string = "Polly.Pedro-Generative")]
-            PedroGenerative,
+            #[serde(rename = "Polly.Miguel")]
+            #[strum(to_string = "Polly.Miguel")]
+            Miguel,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -567,7 +570,7 @@ pub mod generative {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::EsUs(super::super::Voice::Generative(super::Voice::Polly(
+                Self::EsUs(super::super::Voice::Standard(super::Voice::Polly(
                     Voice::Male(value),
                 )))
             }
@@ -582,7 +585,7 @@ pub mod generative {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -605,7 +608,7 @@ pub mod generative {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
@@ -622,16 +625,16 @@ pub mod generative {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Voice {
+    Generative(generative::Voice),
     Neural(neural::Voice),
     Standard(standard::Voice),
-    Generative(generative::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
+            Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
             Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
-            Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
         }
     }
 }
@@ -639,9 +642,263 @@ impl VoicePrice for Voice {
 impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
+            Voice::Generative(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
             Voice::Standard(voice) => voice.gender(),
-            Voice::Generative(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.es-US-Chirp3-HD-Aoede",
+        language: Language::EsUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.es-US-Chirp3-HD-Kore",
+        language: Language::EsUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.es-US-Chirp3-HD-Leda",
+        language: Language::EsUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.es-US-Chirp3-HD-Zephyr",
+        language: Language::EsUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.es-US-Chirp3-HD-Charon",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.es-US-Chirp3-HD-Fenrir",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.es-US-Chirp3-HD-Orus",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.es-US-Chirp3-HD-Puck",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Female(generative::polly::Female::LupeGenerative),
+        ))),
+        id: "Polly.Lupe-Generative",
+        language: Language::EsUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Generative(generative::Voice::Polly(
+            generative::polly::Voice::Male(generative::polly::Male::PedroGenerative),
+        ))),
+        id: "Polly.Pedro-Generative",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2A),
+        ))),
+        id: "Google.es-US-Neural2-A",
+        language: Language::EsUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.es-US-Wavenet-A",
+        language: Language::EsUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2B),
+        ))),
+        id: "Google.es-US-Neural2-B",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2C),
+        ))),
+        id: "Google.es-US-Neural2-C",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.es-US-Wavenet-B",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetC),
+        ))),
+        id: "Google.es-US-Wavenet-C",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::LupeNeural),
+        ))),
+        id: "Polly.Lupe-Neural",
+        language: Language::EsUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Male(neural::polly::Male::PedroNeural),
+        ))),
+        id: "Polly.Pedro-Neural",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.es-US-Standard-A",
+        language: Language::EsUs,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.es-US-Standard-B",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardC),
+        ))),
+        id: "Google.es-US-Standard-C",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Lupe),
+        ))),
+        id: "Polly.Lupe",
+        language: Language::EsUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Penelope),
+        ))),
+        id: "Polly.Penelope",
+        language: Language::EsUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Penélope),
+        ))),
+        id: "Polly.Penélope",
+        language: Language::EsUs,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::EsUs(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Male(standard::polly::Male::Miguel),
+        ))),
+        id: "Polly.Miguel",
+        language: Language::EsUs,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
@@ -4,7 +4,9 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE, VoiceInfo, VoiceTier,
+        },
     },
 };
 
@@ -130,62 +132,62 @@ pub mod standard {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.fil-PH-Standard-C")]
-            #[strum(to_string = "Google.fil-PH-Standard-C")]
-            StandardC,
-            #[serde(rename = "Google.fil-PH-Standard-D")]
-            #[strum(to_string = "Google.fil-PH-Standard-D")]
-            StandardD,
+        pub enum Female {
+            #[serde(rename = "Google.fil-PH-Standard-A")]
+            #[strum(to_string = "Google.fil-PH-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.fil-PH-Standard-B")]
+            #[strum(to_string = "Google.fil-PH-Standard-B")]
+            StandardB,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
                 Self::FilPh(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Male(value),
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.fil-PH-Standard-A")]
-            #[strum(to_string = "Google.fil-PH-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.fil-PH-Standard-B")]
-            #[strum(to_string = "Google.fil-PH-Standard-B")]
-            StandardB,
+        pub enum Male {
+            #[serde(rename = "Google.fil-PH-Standard-C")]
+            #[strum(to_string = "Google.fil-PH-Standard-C")]
+            StandardC,
+            #[serde(rename = "Google.fil-PH-Standard-D")]
+            #[strum(to_string = "Google.fil-PH-Standard-D")]
+            StandardD,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
                 Self::FilPh(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Female(value),
+                    Voice::Male(value),
                 )))
             }
         }
@@ -193,8 +195,8 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -206,8 +208,8 @@ pub mod standard {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -257,3 +259,87 @@ impl VoiceGender for Voice {
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::FilPh(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.fil-PH-Wavenet-A",
+        language: Language::FilPh,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FilPh(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetB),
+        ))),
+        id: "Google.fil-PH-Wavenet-B",
+        language: Language::FilPh,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FilPh(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetC),
+        ))),
+        id: "Google.fil-PH-Wavenet-C",
+        language: Language::FilPh,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FilPh(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetD),
+        ))),
+        id: "Google.fil-PH-Wavenet-D",
+        language: Language::FilPh,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FilPh(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.fil-PH-Standard-A",
+        language: Language::FilPh,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FilPh(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardB),
+        ))),
+        id: "Google.fil-PH-Standard-B",
+        language: Language::FilPh,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FilPh(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardC),
+        ))),
+        id: "Google.fil-PH-Standard-C",
+        language: Language::FilPh,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FilPh(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardD),
+        ))),
+        id: "Google.fil-PH-Standard-D",
+        language: Language::FilPh,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+];
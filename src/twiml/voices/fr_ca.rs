@@ -4,7 +4,10 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
@@ -16,43 +19,6 @@ pub mod generative {
     pub mod google {
         use super::*;
 
-        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
-        #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.fr-CA-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.fr-CA-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.fr-CA-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.fr-CA-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.fr-CA-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.fr-CA-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.fr-CA-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.fr-CA-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
-        }
-
-        impl VoicePrice for Male {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Male {
-            fn gender(&self) -> Gender {
-                Gender::Male
-            }
-        }
-
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::FrCa(super::super::Voice::Generative(super::Voice::Google(
-                    Voice::Male(value),
-                )))
-            }
-        }
-
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
@@ -90,11 +56,48 @@ pub mod generative {
             }
         }
 
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum Male {
+            #[serde(rename = "Google.fr-CA-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.fr-CA-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.fr-CA-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.fr-CA-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.fr-CA-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.fr-CA-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.fr-CA-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.fr-CA-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
+        }
+
+        impl VoicePrice for Male {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Male {
+            fn gender(&self) -> Gender {
+                Gender::Male
+            }
+        }
+
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::FrCa(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Male(value),
+                )))
+            }
+        }
+
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -106,8 +109,8 @@ pub mod generative {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -134,7 +137,7 @@ pub mod generative {
     }
 }
 
-pub mod standard {
+pub mod neural {
     use super::*;
 
     pub mod google {
@@ -143,17 +146,23 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.fr-CA-Standard-A")]
-            #[strum(to_string = "Google.fr-CA-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.fr-CA-Standard-C")]
-            #[strum(to_string = "Google.fr-CA-Standard-C")]
-            StandardC,
+            #[serde(rename = "Google.fr-CA-Neural2-A")]
+            #[strum(to_string = "Google.fr-CA-Neural2-A")]
+            Neural2A,
+            #[serde(rename = "Google.fr-CA-Neural2-C")]
+            #[strum(to_string = "Google.fr-CA-Neural2-C")]
+            Neural2C,
+            #[serde(rename = "Google.fr-CA-Wavenet-A")]
+            #[strum(to_string = "Google.fr-CA-Wavenet-A")]
+            WavenetA,
+            #[serde(rename = "Google.fr-CA-Wavenet-C")]
+            #[strum(to_string = "Google.fr-CA-Wavenet-C")]
+            WavenetC,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -165,7 +174,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::FrCa(super::super::Voice::Standard(super::Voice::Google(
+                Self::FrCa(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -174,17 +183,23 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.fr-CA-Standard-B")]
-            #[strum(to_string = "Google.fr-CA-Standard-B")]
-            StandardB,
-            #[serde(rename = "Google.fr-CA-Standard-D")]
-            #[strum(to_string = "Google.fr-CA-Standard-D")]
-            StandardD,
+            #[serde(rename = "Google.fr-CA-Neural2-B")]
+            #[strum(to_string = "Google.fr-CA-Neural2-B")]
+            Neural2B,
+            #[serde(rename = "Google.fr-CA-Neural2-D")]
+            #[strum(to_string = "Google.fr-CA-Neural2-D")]
+            Neural2D,
+            #[serde(rename = "Google.fr-CA-Wavenet-B")]
+            #[strum(to_string = "Google.fr-CA-Wavenet-B")]
+            WavenetB,
+            #[serde(rename = "Google.fr-CA-Wavenet-D")]
+            #[strum(to_string = "Google.fr-CA-Wavenet-D")]
+            WavenetD,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -196,7 +211,7 @@ pub mod standard {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::FrCa(super::super::Voice::Standard(super::Voice::Google(
+                Self::FrCa(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -211,7 +226,7 @@ pub mod standard {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -231,14 +246,14 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Chantal")]
-            #[strum(to_string = "Polly.Chantal")]
-            Chantal,
+            #[serde(rename = "Polly.Gabrielle-Neural")]
+            #[strum(to_string = "Polly.Gabrielle-Neural")]
+            GabrielleNeural,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -250,21 +265,50 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::FrCa(super::super::Voice::Standard(super::Voice::Polly(
+                Self::FrCa(super::super::Voice::Neural(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
         }
 
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum Male {
+            #[serde(rename = "Polly.Liam-Neural")]
+            #[strum(to_string = "Polly.Liam-Neural")]
+            LiamNeural,
+        }
+
+        impl VoicePrice for Male {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Male {
+            fn gender(&self) -> Gender {
+                Gender::Male
+            }
+        }
+
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::FrCa(super::super::Voice::Neural(super::Voice::Polly(
+                    Voice::Male(value),
+                )))
+            }
+        }
+
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -272,6 +316,7 @@ pub mod standard {
             fn gender(&self) -> Gender {
                 match self {
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -286,7 +331,7 @@ pub mod standard {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
@@ -300,7 +345,7 @@ pub mod standard {
     }
 }
 
-pub mod neural {
+pub mod standard {
     use super::*;
 
     pub mod google {
@@ -308,74 +353,62 @@ pub mod neural {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.fr-CA-Neural2-B")]
-            #[strum(to_string = "Google.fr-CA-Neural2-B")]
-            Neural2B,
-            #[serde(rename = "Google.fr-CA-Neural2-D")]
-            #[strum(to_string = "Google.fr-CA-Neural2-D")]
-            Neural2D,
-            #[serde(rename = "Google.fr-CA-Wavenet-B")]
-            #[strum(to_string = "Google.fr-CA-Wavenet-B")]
-            WavenetB,
-            #[serde(rename = "Google.fr-CA-Wavenet-D")]
-            #[strum(to_string = "Google.fr-CA-Wavenet-D")]
-            WavenetD,
+        pub enum Female {
+            #[serde(rename = "Google.fr-CA-Standard-A")]
+            #[strum(to_string = "Google.fr-CA-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.fr-CA-Standard-C")]
+            #[strum(to_string = "Google.fr-CA-Standard-C")]
+            StandardC,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::FrCa(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::FrCa(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.fr-CA-Neural2-A")]
-            #[strum(to_string = "Google.fr-CA-Neural2-A")]
-            Neural2A,
-            #[serde(rename = "Google.fr-CA-Neural2-C")]
-            #[strum(to_string = "Google.fr-CA-Neural2-C")]
-            Neural2C,
-            #[serde(rename = "Google.fr-CA-Wavenet-A")]
-            #[strum(to_string = "Google.fr-CA-Wavenet-A")]
-            WavenetA,
-            #[serde(rename = "Google.fr-CA-Wavenet-C")]
-            #[strum(to_string = "Google.fr-CA-Wavenet-C")]
-            WavenetC,
+        pub enum Male {
+            #[serde(rename = "Google.fr-CA-Standard-B")]
+            #[strum(to_string = "Google.fr-CA-Standard-B")]
+            StandardB,
+            #[serde(rename = "Google.fr-CA-Standard-D")]
+            #[strum(to_string = "Google.fr-CA-Standard-D")]
+            StandardD,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::FrCa(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::FrCa(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -383,21 +416,21 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -406,45 +439,17 @@ pub mod neural {
     pub mod polly {
         use super::*;
 
-        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
-        #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Liam-Neural")]
-            #[strum(to_string = "Polly.Liam-Neural")]
-            LiamNeural,
-        }
-
-        impl VoicePrice for Male {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Male {
-            fn gender(&self) -> Gender {
-                Gender::Male
-            }
-        }
-
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::FrCa(super::super::Voice::Neural(super::Voice::Polly(
-                    Voice::Male(value),
-                )))
-            }
-        }
-
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Gabrielle-Neural")]
-            #[strum(to_string = "Polly.Gabrielle-Neural")]
-            GabrielleNeural,
+            #[serde(rename = "Polly.Chantal")]
+            #[strum(to_string = "Polly.Chantal")]
+            Chantal,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -456,7 +461,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::FrCa(super::super::Voice::Neural(super::Voice::Polly(
+                Self::FrCa(super::super::Voice::Standard(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -465,20 +470,18 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
                 }
             }
@@ -494,7 +497,7 @@ pub mod neural {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
@@ -512,15 +515,15 @@ pub mod neural {
 #[serde(untagged)]
 pub enum Voice {
     Generative(generative::Voice),
-    Standard(standard::Voice),
     Neural(neural::Voice),
+    Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
             Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
-            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
 }
@@ -529,8 +532,242 @@ impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
             Voice::Generative(voice) => voice.gender(),
-            Voice::Standard(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
+            Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.fr-CA-Chirp3-HD-Aoede",
+        language: Language::FrCa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.fr-CA-Chirp3-HD-Kore",
+        language: Language::FrCa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.fr-CA-Chirp3-HD-Leda",
+        language: Language::FrCa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.fr-CA-Chirp3-HD-Zephyr",
+        language: Language::FrCa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.fr-CA-Chirp3-HD-Charon",
+        language: Language::FrCa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.fr-CA-Chirp3-HD-Fenrir",
+        language: Language::FrCa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.fr-CA-Chirp3-HD-Orus",
+        language: Language::FrCa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.fr-CA-Chirp3-HD-Puck",
+        language: Language::FrCa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2A),
+        ))),
+        id: "Google.fr-CA-Neural2-A",
+        language: Language::FrCa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2C),
+        ))),
+        id: "Google.fr-CA-Neural2-C",
+        language: Language::FrCa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.fr-CA-Wavenet-A",
+        language: Language::FrCa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetC),
+        ))),
+        id: "Google.fr-CA-Wavenet-C",
+        language: Language::FrCa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2B),
+        ))),
+        id: "Google.fr-CA-Neural2-B",
+        language: Language::FrCa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2D),
+        ))),
+        id: "Google.fr-CA-Neural2-D",
+        language: Language::FrCa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.fr-CA-Wavenet-B",
+        language: Language::FrCa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetD),
+        ))),
+        id: "Google.fr-CA-Wavenet-D",
+        language: Language::FrCa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::GabrielleNeural),
+        ))),
+        id: "Polly.Gabrielle-Neural",
+        language: Language::FrCa,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Male(neural::polly::Male::LiamNeural),
+        ))),
+        id: "Polly.Liam-Neural",
+        language: Language::FrCa,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.fr-CA-Standard-A",
+        language: Language::FrCa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardC),
+        ))),
+        id: "Google.fr-CA-Standard-C",
+        language: Language::FrCa,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.fr-CA-Standard-B",
+        language: Language::FrCa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardD),
+        ))),
+        id: "Google.fr-CA-Standard-D",
+        language: Language::FrCa,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::FrCa(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Chantal),
+        ))),
+        id: "Polly.Chantal",
+        language: Language::FrCa,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
@@ -2,7 +2,10 @@
 
 use crate::{
     PriceType,
-    twiml::{Gender, VoiceGender, VoicePrice, voices::STANDARD_VOICE_PRICE},
+    twiml::{
+        Gender, VoiceGender, VoicePrice,
+        voices::{Language, Provider, STANDARD_VOICE_PRICE, VoiceInfo, VoiceTier},
+    },
 };
 
 use serde::{Deserialize, Serialize};
@@ -103,3 +106,15 @@ impl VoiceGender for Voice {
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[VoiceInfo {
+    voice: crate::twiml::Voice::GlEs(Voice::Standard(standard::Voice::Google(
+        standard::google::Voice::Female(standard::google::Female::StandardB),
+    ))),
+    id: "Google.gl-ES-Standard-B",
+    language: Language::GlEs,
+    gender: Gender::Female,
+    provider: Provider::Google,
+    tier: VoiceTier::Standard,
+}];
@@ -4,7 +4,10 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
@@ -16,43 +19,6 @@ pub mod generative {
     pub mod google {
         use super::*;
 
-        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
-        #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.gu-IN-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.gu-IN-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.gu-IN-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.gu-IN-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.gu-IN-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.gu-IN-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.gu-IN-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.gu-IN-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
-        }
-
-        impl VoicePrice for Male {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Male {
-            fn gender(&self) -> Gender {
-                Gender::Male
-            }
-        }
-
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::GuIn(super::super::Voice::Generative(super::Voice::Google(
-                    Voice::Male(value),
-                )))
-            }
-        }
-
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
@@ -90,11 +56,48 @@ pub mod generative {
             }
         }
 
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum Male {
+            #[serde(rename = "Google.gu-IN-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.gu-IN-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.gu-IN-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.gu-IN-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.gu-IN-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.gu-IN-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.gu-IN-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.gu-IN-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
+        }
+
+        impl VoicePrice for Male {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Male {
+            fn gender(&self) -> Gender {
+                Gender::Male
+            }
+        }
+
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::GuIn(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Male(value),
+                )))
+            }
+        }
+
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -106,8 +109,8 @@ pub mod generative {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -134,7 +137,7 @@ pub mod generative {
     }
 }
 
-pub mod standard {
+pub mod neural {
     use super::*;
 
     pub mod google {
@@ -143,17 +146,17 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.gu-IN-Standard-A")]
-            #[strum(to_string = "Google.gu-IN-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.gu-IN-Standard-C")]
-            #[strum(to_string = "Google.gu-IN-Standard-C")]
-            StandardC,
+            #[serde(rename = "Google.gu-IN-Wavenet-A")]
+            #[strum(to_string = "Google.gu-IN-Wavenet-A")]
+            WavenetA,
+            #[serde(rename = "Google.gu-IN-Wavenet-C")]
+            #[strum(to_string = "Google.gu-IN-Wavenet-C")]
+            WavenetC,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -165,7 +168,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::GuIn(super::super::Voice::Standard(super::Voice::Google(
+                Self::GuIn(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -174,17 +177,17 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.gu-IN-Standard-B")]
-            #[strum(to_string = "Google.gu-IN-Standard-B")]
-            StandardB,
-            #[serde(rename = "Google.gu-IN-Standard-D")]
-            #[strum(to_string = "Google.gu-IN-Standard-D")]
-            StandardD,
+            #[serde(rename = "Google.gu-IN-Wavenet-B")]
+            #[strum(to_string = "Google.gu-IN-Wavenet-B")]
+            WavenetB,
+            #[serde(rename = "Google.gu-IN-Wavenet-D")]
+            #[strum(to_string = "Google.gu-IN-Wavenet-D")]
+            WavenetD,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -196,7 +199,7 @@ pub mod standard {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::GuIn(super::super::Voice::Standard(super::Voice::Google(
+                Self::GuIn(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -211,7 +214,7 @@ pub mod standard {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -233,7 +236,7 @@ pub mod standard {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
@@ -246,7 +249,7 @@ pub mod standard {
     }
 }
 
-pub mod neural {
+pub mod standard {
     use super::*;
 
     pub mod google {
@@ -255,17 +258,17 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.gu-IN-Wavenet-A")]
-            #[strum(to_string = "Google.gu-IN-Wavenet-A")]
-            WavenetA,
-            #[serde(rename = "Google.gu-IN-Wavenet-C")]
-            #[strum(to_string = "Google.gu-IN-Wavenet-C")]
-            WavenetC,
+            #[serde(rename = "Google.gu-IN-Standard-A")]
+            #[strum(to_string = "Google.gu-IN-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.gu-IN-Standard-C")]
+            #[strum(to_string = "Google.gu-IN-Standard-C")]
+            StandardC,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -277,7 +280,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::GuIn(super::super::Voice::Neural(super::Voice::Google(
+                Self::GuIn(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -286,17 +289,17 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.gu-IN-Wavenet-B")]
-            #[strum(to_string = "Google.gu-IN-Wavenet-B")]
-            WavenetB,
-            #[serde(rename = "Google.gu-IN-Wavenet-D")]
-            #[strum(to_string = "Google.gu-IN-Wavenet-D")]
-            WavenetD,
+            #[serde(rename = "Google.gu-IN-Standard-B")]
+            #[strum(to_string = "Google.gu-IN-Standard-B")]
+            StandardB,
+            #[serde(rename = "Google.gu-IN-Standard-D")]
+            #[strum(to_string = "Google.gu-IN-Standard-D")]
+            StandardD,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -308,7 +311,7 @@ pub mod neural {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::GuIn(super::super::Voice::Neural(super::Voice::Google(
+                Self::GuIn(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -323,7 +326,7 @@ pub mod neural {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -345,7 +348,7 @@ pub mod neural {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
@@ -362,15 +365,15 @@ pub mod neural {
 #[serde(untagged)]
 pub enum Voice {
     Generative(generative::Voice),
-    Standard(standard::Voice),
     Neural(neural::Voice),
+    Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
             Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
-            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
 }
@@ -379,8 +382,172 @@ impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
             Voice::Generative(voice) => voice.gender(),
-            Voice::Standard(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
+            Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.gu-IN-Chirp3-HD-Aoede",
+        language: Language::GuIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.gu-IN-Chirp3-HD-Kore",
+        language: Language::GuIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.gu-IN-Chirp3-HD-Leda",
+        language: Language::GuIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.gu-IN-Chirp3-HD-Zephyr",
+        language: Language::GuIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.gu-IN-Chirp3-HD-Charon",
+        language: Language::GuIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.gu-IN-Chirp3-HD-Fenrir",
+        language: Language::GuIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.gu-IN-Chirp3-HD-Orus",
+        language: Language::GuIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.gu-IN-Chirp3-HD-Puck",
+        language: Language::GuIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.gu-IN-Wavenet-A",
+        language: Language::GuIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetC),
+        ))),
+        id: "Google.gu-IN-Wavenet-C",
+        language: Language::GuIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.gu-IN-Wavenet-B",
+        language: Language::GuIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetD),
+        ))),
+        id: "Google.gu-IN-Wavenet-D",
+        language: Language::GuIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.gu-IN-Standard-A",
+        language: Language::GuIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardC),
+        ))),
+        id: "Google.gu-IN-Standard-C",
+        language: Language::GuIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.gu-IN-Standard-B",
+        language: Language::GuIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::GuIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardD),
+        ))),
+        id: "Google.gu-IN-Standard-D",
+        language: Language::GuIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+];
@@ -4,29 +4,41 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-pub mod neural {
+pub mod generative {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Kajal-Neural")]
-            #[strum(to_string = "Polly.Kajal-Neural")]
-            KajalNeural,
+            #[serde(rename = "Google.hi-IN-Chirp3-HD-Aoede")]
+            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Aoede")]
+            Chirp3HdAoede,
+            #[serde(rename = "Google.hi-IN-Chirp3-HD-Kore")]
+            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Kore")]
+            Chirp3HdKore,
+            #[serde(rename = "Google.hi-IN-Chirp3-HD-Leda")]
+            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Leda")]
+            Chirp3HdLeda,
+            #[serde(rename = "Google.hi-IN-Chirp3-HD-Zephyr")]
+            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Zephyr")]
+            Chirp3HdZephyr,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -38,59 +50,32 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::HiIn(super::super::Voice::Neural(super::Voice::Polly(
+                Self::HiIn(super::super::Voice::Generative(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
         }
 
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-        #[serde(untagged)]
-        pub enum Voice {
-            Female(Female),
-        }
-
-        impl VoicePrice for Voice {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Voice {
-            fn gender(&self) -> Gender {
-                match self {
-                    Voice::Female(_) => Gender::Female,
-                }
-            }
-        }
-    }
-
-    pub mod google {
-        use super::*;
-
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.hi-IN-Neural2-B")]
-            #[strum(to_string = "Google.hi-IN-Neural2-B")]
-            Neural2B,
-            #[serde(rename = "Google.hi-IN-Neural2-C")]
-            #[strum(to_string = "Google.hi-IN-Neural2-C")]
-            Neural2C,
-            #[serde(rename = "Google.hi-IN-Wavenet-B")]
-            #[strum(to_string = "Google.hi-IN-Wavenet-B")]
-            WavenetB,
-            #[serde(rename = "Google.hi-IN-Wavenet-C")]
-            #[strum(to_string = "Google.hi-IN-Wavenet-C")]
-            WavenetC,
-            #[serde(rename = "Google.hi-IN-Wavenet-F")]
-            #[strum(to_string = "Google.hi-IN-Wavenet-F")]
-            WavenetF,
+            #[serde(rename = "Google.hi-IN-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.hi-IN-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.hi-IN-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.hi-IN-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -102,70 +87,30 @@ pub mod neural {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::HiIn(super::super::Voice::Neural(super::Voice::Google(
+                Self::HiIn(super::super::Voice::Generative(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
         }
 
-        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
-        #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.hi-IN-Neural2-A")]
-            #[strum(to_string = "Google.hi-IN-Neural2-A")]
-            Neural2A,
-            #[serde(rename = "Google.hi-IN-Neural2-D")]
-            #[strum(to_string = "Google.hi-IN-Neural2-D")]
-            Neural2D,
-            #[serde(rename = "Google.hi-IN-Wavenet-A")]
-            #[strum(to_string = "Google.hi-IN-Wavenet-A")]
-            WavenetA,
-            #[serde(rename = "Google.hi-IN-Wavenet-D")]
-            #[strum(to_string = "Google.hi-IN-Wavenet-D")]
-            WavenetD,
-            #[serde(rename = "Google.hi-IN-Wavenet-E")]
-            #[strum(to_string = "Google.hi-IN-Wavenet-E")]
-            WavenetE,
-        }
-
-        impl VoicePrice for Female {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Female {
-            fn gender(&self) -> Gender {
-                Gender::Female
-            }
-        }
-
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::HiIn(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Female(value),
-                )))
-            }
-        }
-
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -174,27 +119,25 @@ pub mod neural {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
         Google(google::Voice),
     }
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
         }
     }
 
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
                 Voice::Google(voice) => voice.gender(),
             }
         }
     }
 }
 
-pub mod standard {
+pub mod neural {
     use super::*;
 
     pub mod google {
@@ -202,68 +145,80 @@ pub mod standard {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.hi-IN-Standard-B")]
-            #[strum(to_string = "Google.hi-IN-Standard-B")]
-            StandardB,
-            #[serde(rename = "Google.hi-IN-Standard-C")]
-            #[strum(to_string = "Google.hi-IN-Standard-C")]
-            StandardC,
-            #[serde(rename = "Google.hi-IN-Standard-F")]
-            #[strum(to_string = "Google.hi-IN-Standard-F")]
-            StandardF,
+        pub enum Female {
+            #[serde(rename = "Google.hi-IN-Neural2-A")]
+            #[strum(to_string = "Google.hi-IN-Neural2-A")]
+            Neural2A,
+            #[serde(rename = "Google.hi-IN-Neural2-D")]
+            #[strum(to_string = "Google.hi-IN-Neural2-D")]
+            Neural2D,
+            #[serde(rename = "Google.hi-IN-Wavenet-A")]
+            #[strum(to_string = "Google.hi-IN-Wavenet-A")]
+            WavenetA,
+            #[serde(rename = "Google.hi-IN-Wavenet-D")]
+            #[strum(to_string = "Google.hi-IN-Wavenet-D")]
+            WavenetD,
+            #[serde(rename = "Google.hi-IN-Wavenet-E")]
+            #[strum(to_string = "Google.hi-IN-Wavenet-E")]
+            WavenetE,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::HiIn(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::HiIn(super::super::Voice::Neural(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.hi-IN-Standard-A")]
-            #[strum(to_string = "Google.hi-IN-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.hi-IN-Standard-D")]
-            #[strum(to_string = "Google.hi-IN-Standard-D")]
-            StandardD,
-            #[serde(rename = "Google.hi-IN-Standard-E")]
-            #[strum(to_string = "Google.hi-IN-Standard-E")]
-            StandardE,
+        pub enum Male {
+            #[serde(rename = "Google.hi-IN-Neural2-B")]
+            #[strum(to_string = "Google.hi-IN-Neural2-B")]
+            Neural2B,
+            #[serde(rename = "Google.hi-IN-Neural2-C")]
+            #[strum(to_string = "Google.hi-IN-Neural2-C")]
+            Neural2C,
+            #[serde(rename = "Google.hi-IN-Wavenet-B")]
+            #[strum(to_string = "Google.hi-IN-Wavenet-B")]
+            WavenetB,
+            #[serde(rename = "Google.hi-IN-Wavenet-C")]
+            #[strum(to_string = "Google.hi-IN-Wavenet-C")]
+            WavenetC,
+            #[serde(rename = "Google.hi-IN-Wavenet-F")]
+            #[strum(to_string = "Google.hi-IN-Wavenet-F")]
+            WavenetF,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::HiIn(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::HiIn(super::super::Voice::Neural(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -271,21 +226,21 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -297,14 +252,14 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Aditi")]
-            #[strum(to_string = "Polly.Aditi")]
-            Aditi,
+            #[serde(rename = "Polly.Kajal-Neural")]
+            #[strum(to_string = "Polly.Kajal-Neural")]
+            KajalNeural,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -316,7 +271,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::HiIn(super::super::Voice::Standard(super::Voice::Polly(
+                Self::HiIn(super::super::Voice::Neural(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -330,7 +285,7 @@ pub mod standard {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -352,7 +307,7 @@ pub mod standard {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
@@ -366,32 +321,63 @@ pub mod standard {
     }
 }
 
-pub mod generative {
+pub mod standard {
     use super::*;
 
     pub mod google {
         use super::*;
 
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum Female {
+            #[serde(rename = "Google.hi-IN-Standard-A")]
+            #[strum(to_string = "Google.hi-IN-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.hi-IN-Standard-D")]
+            #[strum(to_string = "Google.hi-IN-Standard-D")]
+            StandardD,
+            #[serde(rename = "Google.hi-IN-Standard-E")]
+            #[strum(to_string = "Google.hi-IN-Standard-E")]
+            StandardE,
+        }
+
+        impl VoicePrice for Female {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Female {
+            fn gender(&self) -> Gender {
+                Gender::Female
+            }
+        }
+
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::HiIn(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Female(value),
+                )))
+            }
+        }
+
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.hi-IN-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.hi-IN-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.hi-IN-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.hi-IN-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
+            #[serde(rename = "Google.hi-IN-Standard-B")]
+            #[strum(to_string = "Google.hi-IN-Standard-B")]
+            StandardB,
+            #[serde(rename = "Google.hi-IN-Standard-C")]
+            #[strum(to_string = "Google.hi-IN-Standard-C")]
+            StandardC,
+            #[serde(rename = "Google.hi-IN-Standard-F")]
+            #[strum(to_string = "Google.hi-IN-Standard-F")]
+            StandardF,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -403,32 +389,49 @@ pub mod generative {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::HiIn(super::super::Voice::Generative(super::Voice::Google(
+                Self::HiIn(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
         }
 
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+        #[serde(untagged)]
+        pub enum Voice {
+            Female(Female),
+            Male(Male),
+        }
+
+        impl VoicePrice for Voice {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Voice {
+            fn gender(&self) -> Gender {
+                match self {
+                    Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
+                }
+            }
+        }
+    }
+
+    pub mod polly {
+        use super::*;
+
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.hi-IN-Chirp3-HD-Aoede")]
-            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Aoede")]
-            Chirp3HdAoede,
-            #[serde(rename = "Google.hi-IN-Chirp3-HD-Kore")]
-            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Kore")]
-            Chirp3HdKore,
-            #[serde(rename = "Google.hi-IN-Chirp3-HD-Leda")]
-            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Leda")]
-            Chirp3HdLeda,
-            #[serde(rename = "Google.hi-IN-Chirp3-HD-Zephyr")]
-            #[strum(to_string = "Google.hi-IN-Chirp3-HD-Zephyr")]
-            Chirp3HdZephyr,
+            #[serde(rename = "Polly.Aditi")]
+            #[strum(to_string = "Polly.Aditi")]
+            Aditi,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -440,7 +443,7 @@ pub mod generative {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::HiIn(super::super::Voice::Generative(super::Voice::Google(
+                Self::HiIn(super::super::Voice::Standard(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -449,20 +452,18 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
                 }
             }
@@ -473,11 +474,12 @@ pub mod generative {
     #[serde(untagged)]
     pub enum Voice {
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
@@ -485,6 +487,7 @@ pub mod generative {
         fn gender(&self) -> Gender {
             match self {
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
@@ -493,16 +496,16 @@ pub mod generative {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Voice {
+    Generative(generative::Voice),
     Neural(neural::Voice),
     Standard(standard::Voice),
-    Generative(generative::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
+            Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
             Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
-            Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
         }
     }
 }
@@ -510,9 +513,273 @@ impl VoicePrice for Voice {
 impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
+            Voice::Generative(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
             Voice::Standard(voice) => voice.gender(),
-            Voice::Generative(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.hi-IN-Chirp3-HD-Aoede",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.hi-IN-Chirp3-HD-Kore",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.hi-IN-Chirp3-HD-Leda",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.hi-IN-Chirp3-HD-Zephyr",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.hi-IN-Chirp3-HD-Charon",
+        language: Language::HiIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.hi-IN-Chirp3-HD-Fenrir",
+        language: Language::HiIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.hi-IN-Chirp3-HD-Orus",
+        language: Language::HiIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.hi-IN-Chirp3-HD-Puck",
+        language: Language::HiIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2A),
+        ))),
+        id: "Google.hi-IN-Neural2-A",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2D),
+        ))),
+        id: "Google.hi-IN-Neural2-D",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.hi-IN-Wavenet-A",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetD),
+        ))),
+        id: "Google.hi-IN-Wavenet-D",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetE),
+        ))),
+        id: "Google.hi-IN-Wavenet-E",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2B),
+        ))),
+        id: "Google.hi-IN-Neural2-B",
+        language: Language::HiIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2C),
+        ))),
+        id: "Google.hi-IN-Neural2-C",
+        language: Language::HiIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.hi-IN-Wavenet-B",
+        language: Language::HiIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetC),
+        ))),
+        id: "Google.hi-IN-Wavenet-C",
+        language: Language::HiIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetF),
+        ))),
+        id: "Google.hi-IN-Wavenet-F",
+        language: Language::HiIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::KajalNeural),
+        ))),
+        id: "Polly.Kajal-Neural",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.hi-IN-Standard-A",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardD),
+        ))),
+        id: "Google.hi-IN-Standard-D",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardE),
+        ))),
+        id: "Google.hi-IN-Standard-E",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.hi-IN-Standard-B",
+        language: Language::HiIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardC),
+        ))),
+        id: "Google.hi-IN-Standard-C",
+        language: Language::HiIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardF),
+        ))),
+        id: "Google.hi-IN-Standard-F",
+        language: Language::HiIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::HiIn(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Aditi),
+        ))),
+        id: "Polly.Aditi",
+        language: Language::HiIn,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
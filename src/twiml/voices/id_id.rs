@@ -4,13 +4,16 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-pub mod standard {
+pub mod generative {
     use super::*;
 
     pub mod google {
@@ -19,17 +22,23 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.id-ID-Standard-A")]
-            #[strum(to_string = "Google.id-ID-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.id-ID-Standard-D")]
-            #[strum(to_string = "Google.id-ID-Standard-D")]
-            StandardD,
+            #[serde(rename = "Google.id-ID-Chirp3-HD-Aoede")]
+            #[strum(to_string = "Google.id-ID-Chirp3-HD-Aoede")]
+            Chirp3HdAoede,
+            #[serde(rename = "Google.id-ID-Chirp3-HD-Kore")]
+            #[strum(to_string = "Google.id-ID-Chirp3-HD-Kore")]
+            Chirp3HdKore,
+            #[serde(rename = "Google.id-ID-Chirp3-HD-Leda")]
+            #[strum(to_string = "Google.id-ID-Chirp3-HD-Leda")]
+            Chirp3HdLeda,
+            #[serde(rename = "Google.id-ID-Chirp3-HD-Zephyr")]
+            #[strum(to_string = "Google.id-ID-Chirp3-HD-Zephyr")]
+            Chirp3HdZephyr,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -41,7 +50,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::IdId(super::super::Voice::Standard(super::Voice::Google(
+                Self::IdId(super::super::Voice::Generative(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -50,17 +59,23 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.id-ID-Standard-B")]
-            #[strum(to_string = "Google.id-ID-Standard-B")]
-            StandardB,
-            #[serde(rename = "Google.id-ID-Standard-C")]
-            #[strum(to_string = "Google.id-ID-Standard-C")]
-            StandardC,
+            #[serde(rename = "Google.id-ID-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.id-ID-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.id-ID-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.id-ID-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.id-ID-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.id-ID-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.id-ID-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.id-ID-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -72,7 +87,7 @@ pub mod standard {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::IdId(super::super::Voice::Standard(super::Voice::Google(
+                Self::IdId(super::super::Voice::Generative(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -87,7 +102,7 @@ pub mod standard {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -109,7 +124,7 @@ pub mod standard {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
         }
     }
 
@@ -234,7 +249,7 @@ pub mod neural {
     }
 }
 
-pub mod generative {
+pub mod standard {
     use super::*;
 
     pub mod google {
@@ -243,23 +258,17 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.id-ID-Chirp3-HD-Aoede")]
-            #[strum(to_string = "Google.id-ID-Chirp3-HD-Aoede")]
-            Chirp3HdAoede,
-            #[serde(rename = "Google.id-ID-Chirp3-HD-Kore")]
-            #[strum(to_string = "Google.id-ID-Chirp3-HD-Kore")]
-            Chirp3HdKore,
-            #[serde(rename = "Google.id-ID-Chirp3-HD-Leda")]
-            #[strum(to_string = "Google.id-ID-Chirp3-HD-Leda")]
-            Chirp3HdLeda,
-            #[serde(rename = "Google.id-ID-Chirp3-HD-Zephyr")]
-            #[strum(to_string = "Google.id-ID-Chirp3-HD-Zephyr")]
-            Chirp3HdZephyr,
+            #[serde(rename = "Google.id-ID-Standard-A")]
+            #[strum(to_string = "Google.id-ID-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.id-ID-Standard-D")]
+            #[strum(to_string = "Google.id-ID-Standard-D")]
+            StandardD,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -271,7 +280,7 @@ pub mod generative {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::IdId(super::super::Voice::Generative(super::Voice::Google(
+                Self::IdId(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -280,23 +289,17 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.id-ID-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.id-ID-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.id-ID-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.id-ID-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.id-ID-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.id-ID-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.id-ID-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.id-ID-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
+            #[serde(rename = "Google.id-ID-Standard-B")]
+            #[strum(to_string = "Google.id-ID-Standard-B")]
+            StandardB,
+            #[serde(rename = "Google.id-ID-Standard-C")]
+            #[strum(to_string = "Google.id-ID-Standard-C")]
+            StandardC,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -308,7 +311,7 @@ pub mod generative {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::IdId(super::super::Voice::Generative(super::Voice::Google(
+                Self::IdId(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -323,7 +326,7 @@ pub mod generative {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -345,7 +348,7 @@ pub mod generative {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
@@ -361,16 +364,16 @@ pub mod generative {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Voice {
-    Standard(standard::Voice),
-    Neural(neural::Voice),
     Generative(generative::Voice),
+    Neural(neural::Voice),
+    Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
-            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
-            Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
             Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
+            Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
 }
@@ -378,9 +381,173 @@ impl VoicePrice for Voice {
 impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
-            Voice::Standard(voice) => voice.gender(),
-            Voice::Neural(voice) => voice.gender(),
             Voice::Generative(voice) => voice.gender(),
+            Voice::Neural(voice) => voice.gender(),
+            Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.id-ID-Chirp3-HD-Aoede",
+        language: Language::IdId,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.id-ID-Chirp3-HD-Kore",
+        language: Language::IdId,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.id-ID-Chirp3-HD-Leda",
+        language: Language::IdId,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.id-ID-Chirp3-HD-Zephyr",
+        language: Language::IdId,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.id-ID-Chirp3-HD-Charon",
+        language: Language::IdId,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.id-ID-Chirp3-HD-Fenrir",
+        language: Language::IdId,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.id-ID-Chirp3-HD-Orus",
+        language: Language::IdId,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.id-ID-Chirp3-HD-Puck",
+        language: Language::IdId,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.id-ID-Wavenet-A",
+        language: Language::IdId,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetD),
+        ))),
+        id: "Google.id-ID-Wavenet-D",
+        language: Language::IdId,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.id-ID-Wavenet-B",
+        language: Language::IdId,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetC),
+        ))),
+        id: "Google.id-ID-Wavenet-C",
+        language: Language::IdId,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.id-ID-Standard-A",
+        language: Language::IdId,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardD),
+        ))),
+        id: "Google.id-ID-Standard-D",
+        language: Language::IdId,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.id-ID-Standard-B",
+        language: Language::IdId,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::IdId(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardC),
+        ))),
+        id: "Google.id-ID-Standard-C",
+        language: Language::IdId,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+];
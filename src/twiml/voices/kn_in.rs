@@ -4,7 +4,10 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
@@ -16,43 +19,6 @@ pub mod generative {
     pub mod google {
         use super::*;
 
-        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
-        #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.kn-IN-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.kn-IN-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.kn-IN-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.kn-IN-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.kn-IN-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.kn-IN-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.kn-IN-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.kn-IN-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
-        }
-
-        impl VoicePrice for Male {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Male {
-            fn gender(&self) -> Gender {
-                Gender::Male
-            }
-        }
-
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::KnIn(super::super::Voice::Generative(super::Voice::Google(
-                    Voice::Male(value),
-                )))
-            }
-        }
-
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
@@ -90,11 +56,48 @@ pub mod generative {
             }
         }
 
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum Male {
+            #[serde(rename = "Google.kn-IN-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.kn-IN-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.kn-IN-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.kn-IN-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.kn-IN-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.kn-IN-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.kn-IN-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.kn-IN-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
+        }
+
+        impl VoicePrice for Male {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Male {
+            fn gender(&self) -> Gender {
+                Gender::Male
+            }
+        }
+
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::KnIn(super::super::Voice::Generative(super::Voice::Google(
+                    Voice::Male(value),
+                )))
+            }
+        }
+
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -106,8 +109,8 @@ pub mod generative {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -142,62 +145,62 @@ pub mod neural {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.kn-IN-Wavenet-B")]
-            #[strum(to_string = "Google.kn-IN-Wavenet-B")]
-            WavenetB,
-            #[serde(rename = "Google.kn-IN-Wavenet-D")]
-            #[strum(to_string = "Google.kn-IN-Wavenet-D")]
-            WavenetD,
+        pub enum Female {
+            #[serde(rename = "Google.kn-IN-Wavenet-A")]
+            #[strum(to_string = "Google.kn-IN-Wavenet-A")]
+            WavenetA,
+            #[serde(rename = "Google.kn-IN-Wavenet-C")]
+            #[strum(to_string = "Google.kn-IN-Wavenet-C")]
+            WavenetC,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
                 Self::KnIn(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Male(value),
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.kn-IN-Wavenet-A")]
-            #[strum(to_string = "Google.kn-IN-Wavenet-A")]
-            WavenetA,
-            #[serde(rename = "Google.kn-IN-Wavenet-C")]
-            #[strum(to_string = "Google.kn-IN-Wavenet-C")]
-            WavenetC,
+        pub enum Male {
+            #[serde(rename = "Google.kn-IN-Wavenet-B")]
+            #[strum(to_string = "Google.kn-IN-Wavenet-B")]
+            WavenetB,
+            #[serde(rename = "Google.kn-IN-Wavenet-D")]
+            #[strum(to_string = "Google.kn-IN-Wavenet-D")]
+            WavenetD,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
                 Self::KnIn(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Female(value),
+                    Voice::Male(value),
                 )))
             }
         }
@@ -205,8 +208,8 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -218,8 +221,8 @@ pub mod neural {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -254,62 +257,62 @@ pub mod standard {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.kn-IN-Standard-B")]
-            #[strum(to_string = "Google.kn-IN-Standard-B")]
-            StandardB,
-            #[serde(rename = "Google.kn-IN-Standard-D")]
-            #[strum(to_string = "Google.kn-IN-Standard-D")]
-            StandardD,
+        pub enum Female {
+            #[serde(rename = "Google.kn-IN-Standard-A")]
+            #[strum(to_string = "Google.kn-IN-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.kn-IN-Standard-C")]
+            #[strum(to_string = "Google.kn-IN-Standard-C")]
+            StandardC,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
                 Self::KnIn(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Male(value),
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.kn-IN-Standard-A")]
-            #[strum(to_string = "Google.kn-IN-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.kn-IN-Standard-C")]
-            #[strum(to_string = "Google.kn-IN-Standard-C")]
-            StandardC,
+        pub enum Male {
+            #[serde(rename = "Google.kn-IN-Standard-B")]
+            #[strum(to_string = "Google.kn-IN-Standard-B")]
+            StandardB,
+            #[serde(rename = "Google.kn-IN-Standard-D")]
+            #[strum(to_string = "Google.kn-IN-Standard-D")]
+            StandardD,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
                 Self::KnIn(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Female(value),
+                    Voice::Male(value),
                 )))
             }
         }
@@ -317,8 +320,8 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -330,8 +333,8 @@ pub mod standard {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -384,3 +387,167 @@ impl VoiceGender for Voice {
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.kn-IN-Chirp3-HD-Aoede",
+        language: Language::KnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.kn-IN-Chirp3-HD-Kore",
+        language: Language::KnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.kn-IN-Chirp3-HD-Leda",
+        language: Language::KnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.kn-IN-Chirp3-HD-Zephyr",
+        language: Language::KnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.kn-IN-Chirp3-HD-Charon",
+        language: Language::KnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.kn-IN-Chirp3-HD-Fenrir",
+        language: Language::KnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.kn-IN-Chirp3-HD-Orus",
+        language: Language::KnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.kn-IN-Chirp3-HD-Puck",
+        language: Language::KnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.kn-IN-Wavenet-A",
+        language: Language::KnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetC),
+        ))),
+        id: "Google.kn-IN-Wavenet-C",
+        language: Language::KnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.kn-IN-Wavenet-B",
+        language: Language::KnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetD),
+        ))),
+        id: "Google.kn-IN-Wavenet-D",
+        language: Language::KnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.kn-IN-Standard-A",
+        language: Language::KnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardC),
+        ))),
+        id: "Google.kn-IN-Standard-C",
+        language: Language::KnIn,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.kn-IN-Standard-B",
+        language: Language::KnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KnIn(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardD),
+        ))),
+        id: "Google.kn-IN-Standard-D",
+        language: Language::KnIn,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+];
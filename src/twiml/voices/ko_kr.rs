@@ -4,13 +4,16 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-pub mod standard {
+pub mod generative {
     use super::*;
 
     pub mod google {
@@ -19,17 +22,23 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.ko-KR-Standard-A")]
-            #[strum(to_string = "Google.ko-KR-Standard-A")]
-            StandardA,
-            #[serde(rename = "Google.ko-KR-Standard-B")]
-            #[strum(to_string = "Google.ko-KR-Standard-B")]
-            StandardB,
+            #[serde(rename = "Google.ko-KR-Chirp3-HD-Aoede")]
+            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Aoede")]
+            Chirp3HdAoede,
+            #[serde(rename = "Google.ko-KR-Chirp3-HD-Kore")]
+            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Kore")]
+            Chirp3HdKore,
+            #[serde(rename = "Google.ko-KR-Chirp3-HD-Leda")]
+            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Leda")]
+            Chirp3HdLeda,
+            #[serde(rename = "Google.ko-KR-Chirp3-HD-Zephyr")]
+            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Zephyr")]
+            Chirp3HdZephyr,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -41,7 +50,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::KoKr(super::super::Voice::Standard(super::Voice::Google(
+                Self::KoKr(super::super::Voice::Generative(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -50,17 +59,23 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.ko-KR-Standard-C")]
-            #[strum(to_string = "Google.ko-KR-Standard-C")]
-            StandardC,
-            #[serde(rename = "Google.ko-KR-Standard-D")]
-            #[strum(to_string = "Google.ko-KR-Standard-D")]
-            StandardD,
+            #[serde(rename = "Google.ko-KR-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.ko-KR-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.ko-KR-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.ko-KR-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -72,7 +87,7 @@ pub mod standard {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::KoKr(super::super::Voice::Standard(super::Voice::Google(
+                Self::KoKr(super::super::Voice::Generative(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -87,7 +102,7 @@ pub mod standard {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -101,68 +116,15 @@ pub mod standard {
         }
     }
 
-    pub mod polly {
-        use super::*;
-
-        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
-        #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Seoyeon")]
-            #[strum(to_string = "Polly.Seoyeon")]
-            Seoyeon,
-        }
-
-        impl VoicePrice for Female {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Female {
-            fn gender(&self) -> Gender {
-                Gender::Female
-            }
-        }
-
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::KoKr(super::super::Voice::Standard(super::Voice::Polly(
-                    Voice::Female(value),
-                )))
-            }
-        }
-
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-        #[serde(untagged)]
-        pub enum Voice {
-            Female(Female),
-        }
-
-        impl VoicePrice for Voice {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Voice {
-            fn gender(&self) -> Gender {
-                match self {
-                    Voice::Female(_) => Gender::Female,
-                }
-            }
-        }
-    }
-
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
         Google(google::Voice),
-        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
         }
     }
 
@@ -170,13 +132,12 @@ pub mod standard {
         fn gender(&self) -> Gender {
             match self {
                 Voice::Google(voice) => voice.gender(),
-                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
 }
 
-pub mod generative {
+pub mod neural {
     use super::*;
 
     pub mod google {
@@ -185,23 +146,23 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.ko-KR-Chirp3-HD-Aoede")]
-            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Aoede")]
-            Chirp3HdAoede,
-            #[serde(rename = "Google.ko-KR-Chirp3-HD-Kore")]
-            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Kore")]
-            Chirp3HdKore,
-            #[serde(rename = "Google.ko-KR-Chirp3-HD-Leda")]
-            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Leda")]
-            Chirp3HdLeda,
-            #[serde(rename = "Google.ko-KR-Chirp3-HD-Zephyr")]
-            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Zephyr")]
-            Chirp3HdZephyr,
+            #[serde(rename = "Google.ko-KR-Neural2-A")]
+            #[strum(to_string = "Google.ko-KR-Neural2-A")]
+            Neural2A,
+            #[serde(rename = "Google.ko-KR-Neural2-B")]
+            #[strum(to_string = "Google.ko-KR-Neural2-B")]
+            Neural2B,
+            #[serde(rename = "Google.ko-KR-Wavenet-A")]
+            #[strum(to_string = "Google.ko-KR-Wavenet-A")]
+            WavenetA,
+            #[serde(rename = "Google.ko-KR-Wavenet-B")]
+            #[strum(to_string = "Google.ko-KR-Wavenet-B")]
+            WavenetB,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -213,7 +174,7 @@ pub mod generative {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::KoKr(super::super::Voice::Generative(super::Voice::Google(
+                Self::KoKr(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -222,23 +183,20 @@ pub mod generative {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.ko-KR-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.ko-KR-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.ko-KR-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.ko-KR-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.ko-KR-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
+            #[serde(rename = "Google.ko-KR-Neural2-C")]
+            #[strum(to_string = "Google.ko-KR-Neural2-C")]
+            Neural2C,
+            #[serde(rename = "Google.ko-KR-Wavenet-C")]
+            #[strum(to_string = "Google.ko-KR-Wavenet-C")]
+            WavenetC,
+            #[serde(rename = "Google.ko-KR-Wavenet-D")]
+            #[strum(to_string = "Google.ko-KR-Wavenet-D")]
+            WavenetD,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -250,7 +208,7 @@ pub mod generative {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::KoKr(super::super::Voice::Generative(super::Voice::Google(
+                Self::KoKr(super::super::Voice::Neural(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -265,7 +223,7 @@ pub mod generative {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
@@ -279,15 +237,68 @@ pub mod generative {
         }
     }
 
+    pub mod polly {
+        use super::*;
+
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum Female {
+            #[serde(rename = "Polly.Seoyeon-Neural")]
+            #[strum(to_string = "Polly.Seoyeon-Neural")]
+            SeoyeonNeural,
+        }
+
+        impl VoicePrice for Female {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Female {
+            fn gender(&self) -> Gender {
+                Gender::Female
+            }
+        }
+
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::KoKr(super::super::Voice::Neural(super::Voice::Polly(
+                    Voice::Female(value),
+                )))
+            }
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+        #[serde(untagged)]
+        pub enum Voice {
+            Female(Female),
+        }
+
+        impl VoicePrice for Voice {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Voice {
+            fn gender(&self) -> Gender {
+                match self {
+                    Voice::Female(_) => Gender::Female,
+                }
+            }
+        }
+    }
+
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
         }
     }
 
@@ -295,12 +306,13 @@ pub mod generative {
         fn gender(&self) -> Gender {
             match self {
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
 }
 
-pub mod neural {
+pub mod standard {
     use super::*;
 
     pub mod google {
@@ -308,71 +320,62 @@ pub mod neural {
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.ko-KR-Neural2-C")]
-            #[strum(to_string = "Google.ko-KR-Neural2-C")]
-            Neural2C,
-            #[serde(rename = "Google.ko-KR-Wavenet-C")]
-            #[strum(to_string = "Google.ko-KR-Wavenet-C")]
-            WavenetC,
-            #[serde(rename = "Google.ko-KR-Wavenet-D")]
-            #[strum(to_string = "Google.ko-KR-Wavenet-D")]
-            WavenetD,
+        pub enum Female {
+            #[serde(rename = "Google.ko-KR-Standard-A")]
+            #[strum(to_string = "Google.ko-KR-Standard-A")]
+            StandardA,
+            #[serde(rename = "Google.ko-KR-Standard-B")]
+            #[strum(to_string = "Google.ko-KR-Standard-B")]
+            StandardB,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::KoKr(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::KoKr(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.ko-KR-Neural2-A")]
-            #[strum(to_string = "Google.ko-KR-Neural2-A")]
-            Neural2A,
-            #[serde(rename = "Google.ko-KR-Neural2-B")]
-            #[strum(to_string = "Google.ko-KR-Neural2-B")]
-            Neural2B,
-            #[serde(rename = "Google.ko-KR-Wavenet-A")]
-            #[strum(to_string = "Google.ko-KR-Wavenet-A")]
-            WavenetA,
-            #[serde(rename = "Google.ko-KR-Wavenet-B")]
-            #[strum(to_string = "Google.ko-KR-Wavenet-B")]
-            WavenetB,
+        pub enum Male {
+            #[serde(rename = "Google.ko-KR-Standard-C")]
+            #[strum(to_string = "Google.ko-KR-Standard-C")]
+            StandardC,
+            #[serde(rename = "Google.ko-KR-Standard-D")]
+            #[strum(to_string = "Google.ko-KR-Standard-D")]
+            StandardD,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::KoKr(super::super::Voice::Neural(super::Voice::Google(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::KoKr(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -380,21 +383,21 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -406,14 +409,14 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Seoyeon-Neural")]
-            #[strum(to_string = "Polly.Seoyeon-Neural")]
-            SeoyeonNeural,
+            #[serde(rename = "Polly.Seoyeon")]
+            #[strum(to_string = "Polly.Seoyeon")]
+            Seoyeon,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -425,7 +428,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::KoKr(super::super::Voice::Neural(super::Voice::Polly(
+                Self::KoKr(super::super::Voice::Standard(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -439,7 +442,7 @@ pub mod neural {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -461,7 +464,7 @@ pub mod neural {
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
@@ -478,16 +481,16 @@ pub mod neural {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Voice {
-    Standard(standard::Voice),
     Generative(generative::Voice),
     Neural(neural::Voice),
+    Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
-            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
             Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
             Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
 }
@@ -495,9 +498,223 @@ impl VoicePrice for Voice {
 impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
-            Voice::Standard(voice) => voice.gender(),
             Voice::Generative(voice) => voice.gender(),
             Voice::Neural(voice) => voice.gender(),
+            Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.ko-KR-Chirp3-HD-Aoede",
+        language: Language::KoKr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.ko-KR-Chirp3-HD-Kore",
+        language: Language::KoKr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.ko-KR-Chirp3-HD-Leda",
+        language: Language::KoKr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.ko-KR-Chirp3-HD-Zephyr",
+        language: Language::KoKr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.ko-KR-Chirp3-HD-Charon",
+        language: Language::KoKr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.ko-KR-Chirp3-HD-Fenrir",
+        language: Language::KoKr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.ko-KR-Chirp3-HD-Orus",
+        language: Language::KoKr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.ko-KR-Chirp3-HD-Puck",
+        language: Language::KoKr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2A),
+        ))),
+        id: "Google.ko-KR-Neural2-A",
+        language: Language::KoKr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2B),
+        ))),
+        id: "Google.ko-KR-Neural2-B",
+        language: Language::KoKr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetA),
+        ))),
+        id: "Google.ko-KR-Wavenet-A",
+        language: Language::KoKr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetB),
+        ))),
+        id: "Google.ko-KR-Wavenet-B",
+        language: Language::KoKr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2C),
+        ))),
+        id: "Google.ko-KR-Neural2-C",
+        language: Language::KoKr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetC),
+        ))),
+        id: "Google.ko-KR-Wavenet-C",
+        language: Language::KoKr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetD),
+        ))),
+        id: "Google.ko-KR-Wavenet-D",
+        language: Language::KoKr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::SeoyeonNeural),
+        ))),
+        id: "Polly.Seoyeon-Neural",
+        language: Language::KoKr,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardA),
+        ))),
+        id: "Google.ko-KR-Standard-A",
+        language: Language::KoKr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardB),
+        ))),
+        id: "Google.ko-KR-Standard-B",
+        language: Language::KoKr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardC),
+        ))),
+        id: "Google.ko-KR-Standard-C",
+        language: Language::KoKr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardD),
+        ))),
+        id: "Google.ko-KR-Standard-D",
+        language: Language::KoKr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::KoKr(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Seoyeon),
+        ))),
+        id: "Polly.Seoyeon",
+        language: Language::KoKr,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
@@ -2,7 +2,10 @@
 
 use crate::{
     PriceType,
-    twiml::{Gender, VoiceGender, VoicePrice, voices::STANDARD_VOICE_PRICE},
+    twiml::{
+        Gender, VoiceGender, VoicePrice,
+        voices::{Language, Provider, STANDARD_VOICE_PRICE, VoiceInfo, VoiceTier},
+    },
 };
 
 use serde::{Deserialize, Serialize};
@@ -103,3 +106,15 @@ impl VoiceGender for Voice {
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[VoiceInfo {
+    voice: crate::twiml::Voice::LvLv(Voice::Standard(standard::Voice::Google(
+        standard::google::Voice::Male(standard::google::Male::StandardB),
+    ))),
+    id: "Google.lv-LV-Standard-B",
+    language: Language::LvLv,
+    gender: Gender::Male,
+    provider: Provider::Google,
+    tier: VoiceTier::Standard,
+}];
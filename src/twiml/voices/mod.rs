@@ -4,10 +4,10 @@
 
 use crate::PriceType;
 
-/// Current price of Neural voices per 100 chars as of 2025-05-25 22:10 UTC
-pub const NEURAL_VOICE_PRICE: f64 = 0.0032;
 /// Current price of Generative voices per 100 chars as of 2025-05-25 22:10 UTC
 pub const GENERATIVE_VOICE_PRICE: f64 = 0.013;
+/// Current price of Neural voices per 100 chars as of 2025-05-25 22:10 UTC
+pub const NEURAL_VOICE_PRICE: f64 = 0.0032;
 /// Current price of Standard voices per 100 chars as of 2025-05-25 22:10 UTC
 pub const STANDARD_VOICE_PRICE: f64 = 0.0008;
 
@@ -436,7 +436,200 @@ pub enum Language {
     YueHk,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+impl Language {
+    /// Every compiled-in language
+    pub const ALL: &[Language] = &[
+        #[cfg(feature = "af-za")]
+        Language::AfZa,
+        #[cfg(feature = "ar-ae")]
+        Language::ArAe,
+        #[cfg(feature = "ar-xa")]
+        Language::ArXa,
+        #[cfg(feature = "arb")]
+        Language::Arb,
+        #[cfg(feature = "bg-bg")]
+        Language::BgBg,
+        #[cfg(feature = "bn-in")]
+        Language::BnIn,
+        #[cfg(feature = "ca-es")]
+        Language::CaEs,
+        #[cfg(feature = "cmn-cn")]
+        Language::CmnCn,
+        #[cfg(feature = "cmn-tw")]
+        Language::CmnTw,
+        #[cfg(feature = "cs-cz")]
+        Language::CsCz,
+        #[cfg(feature = "cy-gb")]
+        Language::CyGb,
+        #[cfg(feature = "da-dk")]
+        Language::DaDk,
+        #[cfg(feature = "de-at")]
+        Language::DeAt,
+        #[cfg(feature = "de-de")]
+        Language::DeDe,
+        #[cfg(feature = "el-gr")]
+        Language::ElGr,
+        #[cfg(feature = "en-au")]
+        Language::EnAu,
+        #[cfg(feature = "en-gb")]
+        Language::EnGb,
+        #[cfg(feature = "en-gb-wls")]
+        Language::EnGbWls,
+        #[cfg(feature = "en-ie")]
+        Language::EnIe,
+        #[cfg(feature = "en-in")]
+        Language::EnIn,
+        #[cfg(feature = "en-nz")]
+        Language::EnNz,
+        #[cfg(feature = "en-us")]
+        Language::EnUs,
+        #[cfg(feature = "en-za")]
+        Language::EnZa,
+        #[cfg(feature = "es-es")]
+        Language::EsEs,
+        #[cfg(feature = "es-mx")]
+        Language::EsMx,
+        #[cfg(feature = "es-us")]
+        Language::EsUs,
+        #[cfg(feature = "eu-es")]
+        Language::EuEs,
+        #[cfg(feature = "fi-fi")]
+        Language::FiFi,
+        #[cfg(feature = "fil-ph")]
+        Language::FilPh,
+        #[cfg(feature = "fr-be")]
+        Language::FrBe,
+        #[cfg(feature = "fr-ca")]
+        Language::FrCa,
+        #[cfg(feature = "fr-fr")]
+        Language::FrFr,
+        #[cfg(feature = "gl-es")]
+        Language::GlEs,
+        #[cfg(feature = "gu-in")]
+        Language::GuIn,
+        #[cfg(feature = "he-il")]
+        Language::HeIl,
+        #[cfg(feature = "hi-in")]
+        Language::HiIn,
+        #[cfg(feature = "hu-hu")]
+        Language::HuHu,
+        #[cfg(feature = "id-id")]
+        Language::IdId,
+        #[cfg(feature = "is-is")]
+        Language::IsIs,
+        #[cfg(feature = "it-it")]
+        Language::ItIt,
+        #[cfg(feature = "ja-jp")]
+        Language::JaJp,
+        #[cfg(feature = "kn-in")]
+        Language::KnIn,
+        #[cfg(feature = "ko-kr")]
+        Language::KoKr,
+        #[cfg(feature = "lt-lt")]
+        Language::LtLt,
+        #[cfg(feature = "lv-lv")]
+        Language::LvLv,
+        #[cfg(feature = "ml-in")]
+        Language::MlIn,
+        #[cfg(feature = "mr-in")]
+        Language::MrIn,
+        #[cfg(feature = "ms-my")]
+        Language::MsMy,
+        #[cfg(feature = "nb-no")]
+        Language::NbNo,
+        #[cfg(feature = "nl-be")]
+        Language::NlBe,
+        #[cfg(feature = "nl-nl")]
+        Language::NlNl,
+        #[cfg(feature = "pa-in")]
+        Language::PaIn,
+        #[cfg(feature = "pl-pl")]
+        Language::PlPl,
+        #[cfg(feature = "pt-br")]
+        Language::PtBr,
+        #[cfg(feature = "pt-pt")]
+        Language::PtPt,
+        #[cfg(feature = "ro-ro")]
+        Language::RoRo,
+        #[cfg(feature = "ru-ru")]
+        Language::RuRu,
+        #[cfg(feature = "sk-sk")]
+        Language::SkSk,
+        #[cfg(feature = "sv-se")]
+        Language::SvSe,
+        #[cfg(feature = "ta-in")]
+        Language::TaIn,
+        #[cfg(feature = "te-in")]
+        Language::TeIn,
+        #[cfg(feature = "th-th")]
+        Language::ThTh,
+        #[cfg(feature = "tr-tr")]
+        Language::TrTr,
+        #[cfg(feature = "vi-vn")]
+        Language::ViVn,
+        #[cfg(feature = "yue-cn")]
+        Language::YueCn,
+        #[cfg(feature = "yue-hk")]
+        Language::YueHk,
+    ];
+}
+
+/// Text-to-speech engine behind a voice
+#[derive(
+    Debug, Clone, Copy, strum::Display, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[non_exhaustive]
+pub enum Provider {
+    Google,
+    Polly,
+}
+
+/// Pricing tier of a voice, from cheapest to most expensive
+#[derive(
+    Debug, Clone, Copy, strum::Display, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[non_exhaustive]
+pub enum VoiceTier {
+    Standard,
+    Neural,
+    Generative,
+}
+
+/// Catalog entry describing a voice, see [`Voice::all`] and [`Voice::info`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoiceInfo {
+    pub voice: Voice,
+    /// Value of the `voice` attribute, e.g. `Polly.Joanna-Neural`
+    pub id: &'static str,
+    pub language: Language,
+    pub gender: Gender,
+    pub provider: Provider,
+    pub tier: VoiceTier,
+}
+
+impl VoicePrice for VoiceInfo {
+    fn price(&self) -> Option<PriceType> {
+        self.tier.price()
+    }
+}
+
+impl VoiceGender for VoiceInfo {
+    fn gender(&self) -> Gender {
+        self.gender
+    }
+}
+
+impl VoicePrice for VoiceTier {
+    fn price(&self) -> Option<PriceType> {
+        match self {
+            VoiceTier::Standard => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
+            VoiceTier::Neural => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            VoiceTier::Generative => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum Voice {
     #[serde(alias = "man")]
@@ -641,16 +834,32 @@ pub enum Voice {
     #[cfg(feature = "yue-hk")]
     #[serde(untagged)]
     YueHk(yue_hk::Voice),
+
+    /// A voice id that isn't in the catalog of the compiled-in languages, kept as-is
+    #[serde(untagged)]
+    Other(String),
 }
 
 impl std::str::FromStr for Voice {
     type Err = serde_plain::Error;
 
+    /// Unknown voice ids become [`Voice::Other`], see [`Voice::from_str_strict`]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         serde_plain::from_str(s)
     }
 }
 
+impl Voice {
+    /// Like [`Voice::from_str`](std::str::FromStr::from_str), but fails for voice
+    /// ids that aren't in the catalog of the compiled-in languages.
+    pub fn from_str_strict(s: &str) -> Result<Self, serde_plain::Error> {
+        match serde_plain::from_str(s)? {
+            Voice::Other(id) => Err(serde::de::Error::custom(format!("unknown voice: {id}"))),
+            voice => Ok(voice),
+        }
+    }
+}
+
 impl std::fmt::Display for Voice {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         serde_plain::to_string(self)
@@ -796,147 +1005,434 @@ impl VoicePrice for Voice {
             Voice::YueCn(yue_cn) => yue_cn.price(),
             #[cfg(feature = "yue-hk")]
             Voice::YueHk(yue_hk) => yue_hk.price(),
+            Voice::Other(_) => None,
         }
     }
 }
 
-impl VoiceGender for Voice {
-    fn gender(&self) -> Gender {
+impl Voice {
+    /// Gender of the voice, `None` for [`Voice::Other`]
+    pub fn gender(&self) -> Option<Gender> {
+        match self {
+            Voice::Man => Some(Gender::Male),
+            Voice::Woman => Some(Gender::Female),
+            #[cfg(feature = "af-za")]
+            Voice::AfZa(af_za) => Some(af_za.gender()),
+            #[cfg(feature = "ar-ae")]
+            Voice::ArAe(ar_ae) => Some(ar_ae.gender()),
+            #[cfg(feature = "ar-xa")]
+            Voice::ArXa(ar_xa) => Some(ar_xa.gender()),
+            #[cfg(feature = "arb")]
+            Voice::Arb(arb) => Some(arb.gender()),
+            #[cfg(feature = "bg-bg")]
+            Voice::BgBg(bg_bg) => Some(bg_bg.gender()),
+            #[cfg(feature = "bn-in")]
+            Voice::BnIn(bn_in) => Some(bn_in.gender()),
+            #[cfg(feature = "ca-es")]
+            Voice::CaEs(ca_es) => Some(ca_es.gender()),
+            #[cfg(feature = "cmn-cn")]
+            Voice::CmnCn(cmn_cn) => Some(cmn_cn.gender()),
+            #[cfg(feature = "cmn-tw")]
+            Voice::CmnTw(cmn_tw) => Some(cmn_tw.gender()),
+            #[cfg(feature = "cs-cz")]
+            Voice::CsCz(cs_cz) => Some(cs_cz.gender()),
+            #[cfg(feature = "cy-gb")]
+            Voice::CyGb(cy_gb) => Some(cy_gb.gender()),
+            #[cfg(feature = "da-dk")]
+            Voice::DaDk(da_dk) => Some(da_dk.gender()),
+            #[cfg(feature = "de-at")]
+            Voice::DeAt(de_at) => Some(de_at.gender()),
+            #[cfg(feature = "de-de")]
+            Voice::DeDe(de_de) => Some(de_de.gender()),
+            #[cfg(feature = "el-gr")]
+            Voice::ElGr(el_gr) => Some(el_gr.gender()),
+            #[cfg(feature = "en-au")]
+            Voice::EnAu(en_au) => Some(en_au.gender()),
+            #[cfg(feature = "en-gb")]
+            Voice::EnGb(en_gb) => Some(en_gb.gender()),
+            #[cfg(feature = "en-gb-wls")]
+            Voice::EnGbWls(en_gb_wls) => Some(en_gb_wls.gender()),
+            #[cfg(feature = "en-ie")]
+            Voice::EnIe(en_ie) => Some(en_ie.gender()),
+            #[cfg(feature = "en-in")]
+            Voice::EnIn(en_in) => Some(en_in.gender()),
+            #[cfg(feature = "en-nz")]
+            Voice::EnNz(en_nz) => Some(en_nz.gender()),
+            #[cfg(feature = "en-us")]
+            Voice::EnUs(en_us) => Some(en_us.gender()),
+            #[cfg(feature = "en-za")]
+            Voice::EnZa(en_za) => Some(en_za.gender()),
+            #[cfg(feature = "es-es")]
+            Voice::EsEs(es_es) => Some(es_es.gender()),
+            #[cfg(feature = "es-mx")]
+            Voice::EsMx(es_mx) => Some(es_mx.gender()),
+            #[cfg(feature = "es-us")]
+            Voice::EsUs(es_us) => Some(es_us.gender()),
+            #[cfg(feature = "eu-es")]
+            Voice::EuEs(eu_es) => Some(eu_es.gender()),
+            #[cfg(feature = "fi-fi")]
+            Voice::FiFi(fi_fi) => Some(fi_fi.gender()),
+            #[cfg(feature = "fil-ph")]
+            Voice::FilPh(fil_ph) => Some(fil_ph.gender()),
+            #[cfg(feature = "fr-be")]
+            Voice::FrBe(fr_be) => Some(fr_be.gender()),
+            #[cfg(feature = "fr-ca")]
+            Voice::FrCa(fr_ca) => Some(fr_ca.gender()),
+            #[cfg(feature = "fr-fr")]
+            Voice::FrFr(fr_fr) => Some(fr_fr.gender()),
+            #[cfg(feature = "gl-es")]
+            Voice::GlEs(gl_es) => Some(gl_es.gender()),
+            #[cfg(feature = "gu-in")]
+            Voice::GuIn(gu_in) => Some(gu_in.gender()),
+            #[cfg(feature = "he-il")]
+            Voice::HeIl(he_il) => Some(he_il.gender()),
+            #[cfg(feature = "hi-in")]
+            Voice::HiIn(hi_in) => Some(hi_in.gender()),
+            #[cfg(feature = "hu-hu")]
+            Voice::HuHu(hu_hu) => Some(hu_hu.gender()),
+            #[cfg(feature = "id-id")]
+            Voice::IdId(id_id) => Some(id_id.gender()),
+            #[cfg(feature = "is-is")]
+            Voice::IsIs(is_is) => Some(is_is.gender()),
+            #[cfg(feature = "it-it")]
+            Voice::ItIt(it_it) => Some(it_it.gender()),
+            #[cfg(feature = "ja-jp")]
+            Voice::JaJp(ja_jp) => Some(ja_jp.gender()),
+            #[cfg(feature = "kn-in")]
+            Voice::KnIn(kn_in) => Some(kn_in.gender()),
+            #[cfg(feature = "ko-kr")]
+            Voice::KoKr(ko_kr) => Some(ko_kr.gender()),
+            #[cfg(feature = "lt-lt")]
+            Voice::LtLt(lt_lt) => Some(lt_lt.gender()),
+            #[cfg(feature = "lv-lv")]
+            Voice::LvLv(lv_lv) => Some(lv_lv.gender()),
+            #[cfg(feature = "ml-in")]
+            Voice::MlIn(ml_in) => Some(ml_in.gender()),
+            #[cfg(feature = "mr-in")]
+            Voice::MrIn(mr_in) => Some(mr_in.gender()),
+            #[cfg(feature = "ms-my")]
+            Voice::MsMy(ms_my) => Some(ms_my.gender()),
+            #[cfg(feature = "nb-no")]
+            Voice::NbNo(nb_no) => Some(nb_no.gender()),
+            #[cfg(feature = "nl-be")]
+            Voice::NlBe(nl_be) => Some(nl_be.gender()),
+            #[cfg(feature = "nl-nl")]
+            Voice::NlNl(nl_nl) => Some(nl_nl.gender()),
+            #[cfg(feature = "pa-in")]
+            Voice::PaIn(pa_in) => Some(pa_in.gender()),
+            #[cfg(feature = "pl-pl")]
+            Voice::PlPl(pl_pl) => Some(pl_pl.gender()),
+            #[cfg(feature = "pt-br")]
+            Voice::PtBr(pt_br) => Some(pt_br.gender()),
+            #[cfg(feature = "pt-pt")]
+            Voice::PtPt(pt_pt) => Some(pt_pt.gender()),
+            #[cfg(feature = "ro-ro")]
+            Voice::RoRo(ro_ro) => Some(ro_ro.gender()),
+            #[cfg(feature = "ru-ru")]
+            Voice::RuRu(ru_ru) => Some(ru_ru.gender()),
+            #[cfg(feature = "sk-sk")]
+            Voice::SkSk(sk_sk) => Some(sk_sk.gender()),
+            #[cfg(feature = "sv-se")]
+            Voice::SvSe(sv_se) => Some(sv_se.gender()),
+            #[cfg(feature = "ta-in")]
+            Voice::TaIn(ta_in) => Some(ta_in.gender()),
+            #[cfg(feature = "te-in")]
+            Voice::TeIn(te_in) => Some(te_in.gender()),
+            #[cfg(feature = "th-th")]
+            Voice::ThTh(th_th) => Some(th_th.gender()),
+            #[cfg(feature = "tr-tr")]
+            Voice::TrTr(tr_tr) => Some(tr_tr.gender()),
+            #[cfg(feature = "vi-vn")]
+            Voice::ViVn(vi_vn) => Some(vi_vn.gender()),
+            #[cfg(feature = "yue-cn")]
+            Voice::YueCn(yue_cn) => Some(yue_cn.gender()),
+            #[cfg(feature = "yue-hk")]
+            Voice::YueHk(yue_hk) => Some(yue_hk.gender()),
+            Voice::Other(_) => None,
+        }
+    }
+}
+
+const CATALOG: &[&[VoiceInfo]] = &[
+    #[cfg(feature = "af-za")]
+    af_za::VOICES,
+    #[cfg(feature = "ar-ae")]
+    ar_ae::VOICES,
+    #[cfg(feature = "ar-xa")]
+    ar_xa::VOICES,
+    #[cfg(feature = "arb")]
+    arb::VOICES,
+    #[cfg(feature = "bg-bg")]
+    bg_bg::VOICES,
+    #[cfg(feature = "bn-in")]
+    bn_in::VOICES,
+    #[cfg(feature = "ca-es")]
+    ca_es::VOICES,
+    #[cfg(feature = "cmn-cn")]
+    cmn_cn::VOICES,
+    #[cfg(feature = "cmn-tw")]
+    cmn_tw::VOICES,
+    #[cfg(feature = "cs-cz")]
+    cs_cz::VOICES,
+    #[cfg(feature = "cy-gb")]
+    cy_gb::VOICES,
+    #[cfg(feature = "da-dk")]
+    da_dk::VOICES,
+    #[cfg(feature = "de-at")]
+    de_at::VOICES,
+    #[cfg(feature = "de-de")]
+    de_de::VOICES,
+    #[cfg(feature = "el-gr")]
+    el_gr::VOICES,
+    #[cfg(feature = "en-au")]
+    en_au::VOICES,
+    #[cfg(feature = "en-gb")]
+    en_gb::VOICES,
+    #[cfg(feature = "en-gb-wls")]
+    en_gb_wls::VOICES,
+    #[cfg(feature = "en-ie")]
+    en_ie::VOICES,
+    #[cfg(feature = "en-in")]
+    en_in::VOICES,
+    #[cfg(feature = "en-nz")]
+    en_nz::VOICES,
+    #[cfg(feature = "en-us")]
+    en_us::VOICES,
+    #[cfg(feature = "en-za")]
+    en_za::VOICES,
+    #[cfg(feature = "es-es")]
+    es_es::VOICES,
+    #[cfg(feature = "es-mx")]
+    es_mx::VOICES,
+    #[cfg(feature = "es-us")]
+    es_us::VOICES,
+    #[cfg(feature = "eu-es")]
+    eu_es::VOICES,
+    #[cfg(feature = "fi-fi")]
+    fi_fi::VOICES,
+    #[cfg(feature = "fil-ph")]
+    fil_ph::VOICES,
+    #[cfg(feature = "fr-be")]
+    fr_be::VOICES,
+    #[cfg(feature = "fr-ca")]
+    fr_ca::VOICES,
+    #[cfg(feature = "fr-fr")]
+    fr_fr::VOICES,
+    #[cfg(feature = "gl-es")]
+    gl_es::VOICES,
+    #[cfg(feature = "gu-in")]
+    gu_in::VOICES,
+    #[cfg(feature = "he-il")]
+    he_il::VOICES,
+    #[cfg(feature = "hi-in")]
+    hi_in::VOICES,
+    #[cfg(feature = "hu-hu")]
+    hu_hu::VOICES,
+    #[cfg(feature = "id-id")]
+    id_id::VOICES,
+    #[cfg(feature = "is-is")]
+    is_is::VOICES,
+    #[cfg(feature = "it-it")]
+    it_it::VOICES,
+    #[cfg(feature = "ja-jp")]
+    ja_jp::VOICES,
+    #[cfg(feature = "kn-in")]
+    kn_in::VOICES,
+    #[cfg(feature = "ko-kr")]
+    ko_kr::VOICES,
+    #[cfg(feature = "lt-lt")]
+    lt_lt::VOICES,
+    #[cfg(feature = "lv-lv")]
+    lv_lv::VOICES,
+    #[cfg(feature = "ml-in")]
+    ml_in::VOICES,
+    #[cfg(feature = "mr-in")]
+    mr_in::VOICES,
+    #[cfg(feature = "ms-my")]
+    ms_my::VOICES,
+    #[cfg(feature = "nb-no")]
+    nb_no::VOICES,
+    #[cfg(feature = "nl-be")]
+    nl_be::VOICES,
+    #[cfg(feature = "nl-nl")]
+    nl_nl::VOICES,
+    #[cfg(feature = "pa-in")]
+    pa_in::VOICES,
+    #[cfg(feature = "pl-pl")]
+    pl_pl::VOICES,
+    #[cfg(feature = "pt-br")]
+    pt_br::VOICES,
+    #[cfg(feature = "pt-pt")]
+    pt_pt::VOICES,
+    #[cfg(feature = "ro-ro")]
+    ro_ro::VOICES,
+    #[cfg(feature = "ru-ru")]
+    ru_ru::VOICES,
+    #[cfg(feature = "sk-sk")]
+    sk_sk::VOICES,
+    #[cfg(feature = "sv-se")]
+    sv_se::VOICES,
+    #[cfg(feature = "ta-in")]
+    ta_in::VOICES,
+    #[cfg(feature = "te-in")]
+    te_in::VOICES,
+    #[cfg(feature = "th-th")]
+    th_th::VOICES,
+    #[cfg(feature = "tr-tr")]
+    tr_tr::VOICES,
+    #[cfg(feature = "vi-vn")]
+    vi_vn::VOICES,
+    #[cfg(feature = "yue-cn")]
+    yue_cn::VOICES,
+    #[cfg(feature = "yue-hk")]
+    yue_hk::VOICES,
+];
+
+impl Voice {
+    /// Every voice of the compiled-in languages, grouped by language.
+    ///
+    /// The basic `man` and `woman` voices aren't tied to a language and aren't included.
+    pub fn all() -> impl Iterator<Item = &'static VoiceInfo> {
+        CATALOG.iter().flat_map(|voices| voices.iter())
+    }
+
+    /// Catalog entry for this voice, `None` for the basic `man` and `woman` voices, for
+    /// deprecated voices Twilio no longer lists and for [`Voice::Other`].
+    pub fn info(&self) -> Option<&'static VoiceInfo> {
         match self {
-            Voice::Man => Gender::Male,
-            Voice::Woman => Gender::Female,
+            Voice::Man | Voice::Woman | Voice::Other(_) => None,
             #[cfg(feature = "af-za")]
-            Voice::AfZa(af_za) => af_za.gender(),
+            Voice::AfZa(_) => af_za::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "ar-ae")]
-            Voice::ArAe(ar_ae) => ar_ae.gender(),
+            Voice::ArAe(_) => ar_ae::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "ar-xa")]
-            Voice::ArXa(ar_xa) => ar_xa.gender(),
+            Voice::ArXa(_) => ar_xa::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "arb")]
-            Voice::Arb(arb) => arb.gender(),
+            Voice::Arb(_) => arb::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "bg-bg")]
-            Voice::BgBg(bg_bg) => bg_bg.gender(),
+            Voice::BgBg(_) => bg_bg::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "bn-in")]
-            Voice::BnIn(bn_in) => bn_in.gender(),
+            Voice::BnIn(_) => bn_in::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "ca-es")]
-            Voice::CaEs(ca_es) => ca_es.gender(),
+            Voice::CaEs(_) => ca_es::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "cmn-cn")]
-            Voice::CmnCn(cmn_cn) => cmn_cn.gender(),
+            Voice::CmnCn(_) => cmn_cn::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "cmn-tw")]
-            Voice::CmnTw(cmn_tw) => cmn_tw.gender(),
+            Voice::CmnTw(_) => cmn_tw::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "cs-cz")]
-            Voice::CsCz(cs_cz) => cs_cz.gender(),
+            Voice::CsCz(_) => cs_cz::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "cy-gb")]
-            Voice::CyGb(cy_gb) => cy_gb.gender(),
+            Voice::CyGb(_) => cy_gb::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "da-dk")]
-            Voice::DaDk(da_dk) => da_dk.gender(),
+            Voice::DaDk(_) => da_dk::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "de-at")]
-            Voice::DeAt(de_at) => de_at.gender(),
+            Voice::DeAt(_) => de_at::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "de-de")]
-            Voice::DeDe(de_de) => de_de.gender(),
+            Voice::DeDe(_) => de_de::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "el-gr")]
-            Voice::ElGr(el_gr) => el_gr.gender(),
+            Voice::ElGr(_) => el_gr::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "en-au")]
-            Voice::EnAu(en_au) => en_au.gender(),
+            Voice::EnAu(_) => en_au::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "en-gb")]
-            Voice::EnGb(en_gb) => en_gb.gender(),
+            Voice::EnGb(_) => en_gb::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "en-gb-wls")]
-            Voice::EnGbWls(en_gb_wls) => en_gb_wls.gender(),
+            Voice::EnGbWls(_) => en_gb_wls::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "en-ie")]
-            Voice::EnIe(en_ie) => en_ie.gender(),
+            Voice::EnIe(_) => en_ie::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "en-in")]
-            Voice::EnIn(en_in) => en_in.gender(),
+            Voice::EnIn(_) => en_in::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "en-nz")]
-            Voice::EnNz(en_nz) => en_nz.gender(),
+            Voice::EnNz(_) => en_nz::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "en-us")]
-            Voice::EnUs(en_us) => en_us.gender(),
+            Voice::EnUs(_) => en_us::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "en-za")]
-            Voice::EnZa(en_za) => en_za.gender(),
+            Voice::EnZa(_) => en_za::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "es-es")]
-            Voice::EsEs(es_es) => es_es.gender(),
+            Voice::EsEs(_) => es_es::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "es-mx")]
-            Voice::EsMx(es_mx) => es_mx.gender(),
+            Voice::EsMx(_) => es_mx::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "es-us")]
-            Voice::EsUs(es_us) => es_us.gender(),
+            Voice::EsUs(_) => es_us::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "eu-es")]
-            Voice::EuEs(eu_es) => eu_es.gender(),
+            Voice::EuEs(_) => eu_es::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "fi-fi")]
-            Voice::FiFi(fi_fi) => fi_fi.gender(),
+            Voice::FiFi(_) => fi_fi::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "fil-ph")]
-            Voice::FilPh(fil_ph) => fil_ph.gender(),
+            Voice::FilPh(_) => fil_ph::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "fr-be")]
-            Voice::FrBe(fr_be) => fr_be.gender(),
+            Voice::FrBe(_) => fr_be::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "fr-ca")]
-            Voice::FrCa(fr_ca) => fr_ca.gender(),
+            Voice::FrCa(_) => fr_ca::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "fr-fr")]
-            Voice::FrFr(fr_fr) => fr_fr.gender(),
+            Voice::FrFr(_) => fr_fr::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "gl-es")]
-            Voice::GlEs(gl_es) => gl_es.gender(),
+            Voice::GlEs(_) => gl_es::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "gu-in")]
-            Voice::GuIn(gu_in) => gu_in.gender(),
+            Voice::GuIn(_) => gu_in::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "he-il")]
-            Voice::HeIl(he_il) => he_il.gender(),
+            Voice::HeIl(_) => he_il::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "hi-in")]
-            Voice::HiIn(hi_in) => hi_in.gender(),
+            Voice::HiIn(_) => hi_in::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "hu-hu")]
-            Voice::HuHu(hu_hu) => hu_hu.gender(),
+            Voice::HuHu(_) => hu_hu::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "id-id")]
-            Voice::IdId(id_id) => id_id.gender(),
+            Voice::IdId(_) => id_id::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "is-is")]
-            Voice::IsIs(is_is) => is_is.gender(),
+            Voice::IsIs(_) => is_is::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "it-it")]
-            Voice::ItIt(it_it) => it_it.gender(),
+            Voice::ItIt(_) => it_it::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "ja-jp")]
-            Voice::JaJp(ja_jp) => ja_jp.gender(),
+            Voice::JaJp(_) => ja_jp::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "kn-in")]
-            Voice::KnIn(kn_in) => kn_in.gender(),
+            Voice::KnIn(_) => kn_in::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "ko-kr")]
-            Voice::KoKr(ko_kr) => ko_kr.gender(),
+            Voice::KoKr(_) => ko_kr::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "lt-lt")]
-            Voice::LtLt(lt_lt) => lt_lt.gender(),
+            Voice::LtLt(_) => lt_lt::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "lv-lv")]
-            Voice::LvLv(lv_lv) => lv_lv.gender(),
+            Voice::LvLv(_) => lv_lv::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "ml-in")]
-            Voice::MlIn(ml_in) => ml_in.gender(),
+            Voice::MlIn(_) => ml_in::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "mr-in")]
-            Voice::MrIn(mr_in) => mr_in.gender(),
+            Voice::MrIn(_) => mr_in::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "ms-my")]
-            Voice::MsMy(ms_my) => ms_my.gender(),
+            Voice::MsMy(_) => ms_my::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "nb-no")]
-            Voice::NbNo(nb_no) => nb_no.gender(),
+            Voice::NbNo(_) => nb_no::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "nl-be")]
-            Voice::NlBe(nl_be) => nl_be.gender(),
+            Voice::NlBe(_) => nl_be::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "nl-nl")]
-            Voice::NlNl(nl_nl) => nl_nl.gender(),
+            Voice::NlNl(_) => nl_nl::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "pa-in")]
-            Voice::PaIn(pa_in) => pa_in.gender(),
+            Voice::PaIn(_) => pa_in::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "pl-pl")]
-            Voice::PlPl(pl_pl) => pl_pl.gender(),
+            Voice::PlPl(_) => pl_pl::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "pt-br")]
-            Voice::PtBr(pt_br) => pt_br.gender(),
+            Voice::PtBr(_) => pt_br::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "pt-pt")]
-            Voice::PtPt(pt_pt) => pt_pt.gender(),
+            Voice::PtPt(_) => pt_pt::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "ro-ro")]
-            Voice::RoRo(ro_ro) => ro_ro.gender(),
+            Voice::RoRo(_) => ro_ro::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "ru-ru")]
-            Voice::RuRu(ru_ru) => ru_ru.gender(),
+            Voice::RuRu(_) => ru_ru::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "sk-sk")]
-            Voice::SkSk(sk_sk) => sk_sk.gender(),
+            Voice::SkSk(_) => sk_sk::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "sv-se")]
-            Voice::SvSe(sv_se) => sv_se.gender(),
+            Voice::SvSe(_) => sv_se::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "ta-in")]
-            Voice::TaIn(ta_in) => ta_in.gender(),
+            Voice::TaIn(_) => ta_in::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "te-in")]
-            Voice::TeIn(te_in) => te_in.gender(),
+            Voice::TeIn(_) => te_in::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "th-th")]
-            Voice::ThTh(th_th) => th_th.gender(),
+            Voice::ThTh(_) => th_th::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "tr-tr")]
-            Voice::TrTr(tr_tr) => tr_tr.gender(),
+            Voice::TrTr(_) => tr_tr::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "vi-vn")]
-            Voice::ViVn(vi_vn) => vi_vn.gender(),
+            Voice::ViVn(_) => vi_vn::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "yue-cn")]
-            Voice::YueCn(yue_cn) => yue_cn.gender(),
+            Voice::YueCn(_) => yue_cn::VOICES.iter().find(|info| info.voice == *self),
             #[cfg(feature = "yue-hk")]
-            Voice::YueHk(yue_hk) => yue_hk.gender(),
+            Voice::YueHk(_) => yue_hk::VOICES.iter().find(|info| info.voice == *self),
         }
     }
 }
@@ -4,35 +4,41 @@ use crate::{
     PriceType,
     twiml::{
         Gender, VoiceGender, VoicePrice,
-        voices::{GENERATIVE_VOICE_PRICE, NEURAL_VOICE_PRICE, STANDARD_VOICE_PRICE},
+        voices::{
+            GENERATIVE_VOICE_PRICE, Language, NEURAL_VOICE_PRICE, Provider, STANDARD_VOICE_PRICE,
+            VoiceInfo, VoiceTier,
+        },
     },
 };
 
 use serde::{Deserialize, Serialize};
 
-pub mod standard {
+pub mod generative {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Polly.Camila")]
-            #[strum(to_string = "Polly.Camila")]
-            Camila,
-            #[serde(rename = "Polly.Vitoria")]
-            #[strum(to_string = "Polly.Vitoria")]
-            Vitoria,
-            #[serde(rename = "Polly.Vitória")]
-            #[strum(to_string = "Polly.Vitória")]
-            Vitória,
+            #[serde(rename = "Google.pt-BR-Chirp3-HD-Aoede")]
+            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Aoede")]
+            Chirp3HdAoede,
+            #[serde(rename = "Google.pt-BR-Chirp3-HD-Kore")]
+            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Kore")]
+            Chirp3HdKore,
+            #[serde(rename = "Google.pt-BR-Chirp3-HD-Leda")]
+            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Leda")]
+            Chirp3HdLeda,
+            #[serde(rename = "Google.pt-BR-Chirp3-HD-Zephyr")]
+            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Zephyr")]
+            Chirp3HdZephyr,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -44,7 +50,7 @@ pub mod standard {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::PtBr(super::super::Voice::Standard(super::Voice::Polly(
+                Self::PtBr(super::super::Voice::Generative(super::Voice::Google(
                     Voice::Female(value),
                 )))
             }
@@ -53,14 +59,23 @@ pub mod standard {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Polly.Ricardo")]
-            #[strum(to_string = "Polly.Ricardo")]
-            Ricardo,
+            #[serde(rename = "Google.pt-BR-Chirp3-HD-Charon")]
+            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Charon")]
+            Chirp3HdCharon,
+            #[serde(rename = "Google.pt-BR-Chirp3-HD-Fenrir")]
+            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Fenrir")]
+            Chirp3HdFenrir,
+            #[serde(rename = "Google.pt-BR-Chirp3-HD-Orus")]
+            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Orus")]
+            Chirp3HdOrus,
+            #[serde(rename = "Google.pt-BR-Chirp3-HD-Puck")]
+            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Puck")]
+            Chirp3HdPuck,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -72,7 +87,7 @@ pub mod standard {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::PtBr(super::super::Voice::Standard(super::Voice::Polly(
+                Self::PtBr(super::super::Voice::Generative(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
@@ -87,7 +102,7 @@ pub mod standard {
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
             }
         }
 
@@ -101,111 +116,21 @@ pub mod standard {
         }
     }
 
-    pub mod google {
-        use super::*;
-
-        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
-        #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Google.pt-BR-Standard-B")]
-            #[strum(to_string = "Google.pt-BR-Standard-B")]
-            StandardB,
-            #[serde(rename = "Google.pt-BR-Standard-E")]
-            #[strum(to_string = "Google.pt-BR-Standard-E")]
-            StandardE,
-        }
-
-        impl VoicePrice for Male {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Male {
-            fn gender(&self) -> Gender {
-                Gender::Male
-            }
-        }
-
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::PtBr(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Male(value),
-                )))
-            }
-        }
-
-        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
-        #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Google.pt-BR-Standard-C")]
-            #[strum(to_string = "Google.pt-BR-Standard-C")]
-            StandardC,
-            #[serde(rename = "Google.pt-BR-Standard-D")]
-            #[strum(to_string = "Google.pt-BR-Standard-D")]
-            StandardD,
-        }
-
-        impl VoicePrice for Female {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Female {
-            fn gender(&self) -> Gender {
-                Gender::Female
-            }
-        }
-
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::PtBr(super::super::Voice::Standard(super::Voice::Google(
-                    Voice::Female(value),
-                )))
-            }
-        }
-
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-        #[serde(untagged)]
-        pub enum Voice {
-            Male(Male),
-            Female(Female),
-        }
-
-        impl VoicePrice for Voice {
-            fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
-            }
-        }
-
-        impl VoiceGender for Voice {
-            fn gender(&self) -> Gender {
-                match self {
-                    Voice::Male(_) => Gender::Male,
-                    Voice::Female(_) => Gender::Female,
-                }
-            }
-        }
-    }
-
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
         Google(google::Voice),
     }
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
         }
     }
 
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
                 Voice::Google(voice) => voice.gender(),
             }
         }
@@ -215,67 +140,76 @@ pub mod standard {
 pub mod neural {
     use super::*;
 
-    pub mod polly {
+    pub mod google {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Male {
-            #[serde(rename = "Polly.Thiago-Neural")]
-            #[strum(to_string = "Polly.Thiago-Neural")]
-            ThiagoNeural,
+        pub enum Female {
+            #[serde(rename = "Google.pt-BR-Neural2-A")]
+            #[strum(to_string = "Google.pt-BR-Neural2-A")]
+            Neural2A,
+            #[serde(rename = "Google.pt-BR-Neural2-C")]
+            #[strum(to_string = "Google.pt-BR-Neural2-C")]
+            Neural2C,
+            #[serde(rename = "Google.pt-BR-Wavenet-C")]
+            #[strum(to_string = "Google.pt-BR-Wavenet-C")]
+            WavenetC,
+            #[serde(rename = "Google.pt-BR-Wavenet-D")]
+            #[strum(to_string = "Google.pt-BR-Wavenet-D")]
+            WavenetD,
         }
 
-        impl VoicePrice for Male {
+        impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Male {
+        impl VoiceGender for Female {
             fn gender(&self) -> Gender {
-                Gender::Male
+                Gender::Female
             }
         }
 
-        impl From<Male> for crate::twiml::Voice {
-            fn from(value: Male) -> Self {
-                Self::PtBr(super::super::Voice::Neural(super::Voice::Polly(
-                    Voice::Male(value),
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::PtBr(super::super::Voice::Neural(super::Voice::Google(
+                    Voice::Female(value),
                 )))
             }
         }
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
-        pub enum Female {
-            #[serde(rename = "Polly.Camila-Neural")]
-            #[strum(to_string = "Polly.Camila-Neural")]
-            CamilaNeural,
-            #[serde(rename = "Polly.Vitoria-Neural")]
-            #[strum(to_string = "Polly.Vitoria-Neural")]
-            VitoriaNeural,
-            #[serde(rename = "Polly.Vitória-Neural")]
-            #[strum(to_string = "Polly.Vitória-Neural")]
-            VitóriaNeural,
+        pub enum Male {
+            #[serde(rename = "Google.pt-BR-Neural2-B")]
+            #[strum(to_string = "Google.pt-BR-Neural2-B")]
+            Neural2B,
+            #[serde(rename = "Google.pt-BR-Wavenet-B")]
+            #[strum(to_string = "Google.pt-BR-Wavenet-B")]
+            WavenetB,
+            #[serde(rename = "Google.pt-BR-Wavenet-E")]
+            #[strum(to_string = "Google.pt-BR-Wavenet-E")]
+            WavenetE,
         }
 
-        impl VoicePrice for Female {
+        impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
                 crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE)
             }
         }
 
-        impl VoiceGender for Female {
+        impl VoiceGender for Male {
             fn gender(&self) -> Gender {
-                Gender::Female
+                Gender::Male
             }
         }
 
-        impl From<Female> for crate::twiml::Voice {
-            fn from(value: Female) -> Self {
-                Self::PtBr(super::super::Voice::Neural(super::Voice::Polly(
-                    Voice::Female(value),
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::PtBr(super::super::Voice::Neural(super::Voice::Google(
+                    Voice::Male(value),
                 )))
             }
         }
@@ -283,8 +217,8 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
@@ -296,31 +230,28 @@ pub mod neural {
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
     }
 
-    pub mod google {
+    pub mod polly {
         use super::*;
 
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.pt-BR-Neural2-A")]
-            #[strum(to_string = "Google.pt-BR-Neural2-A")]
-            Neural2A,
-            #[serde(rename = "Google.pt-BR-Neural2-C")]
-            #[strum(to_string = "Google.pt-BR-Neural2-C")]
-            Neural2C,
-            #[serde(rename = "Google.pt-BR-Wavenet-C")]
-            #[strum(to_string = "Google.pt-BR-Wavenet-C")]
-            WavenetC,
-            #[serde(rename = "Google.pt-BR-Wavenet-D")]
-            #[strum(to_string = "Google.pt-BR-Wavenet-D")]
-            WavenetD,
+            #[serde(rename = "Polly.Camila-Neural")]
+            #[strum(to_string = "Polly.Camila-Neural")]
+            CamilaNeural,
+            #[serde(rename = "Polly.Vitoria-Neural")]
+            #[strum(to_string = "Polly.Vitoria-Neural")]
+            VitoriaNeural,
+            #[serde(rename = "Polly.Vitória-Neural")]
+            #[strum(to_string = "Polly.Vitória-Neural")]
+            VitóriaNeural,
         }
 
         impl VoicePrice for Female {
@@ -337,7 +268,7 @@ pub mod neural {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::PtBr(super::super::Voice::Neural(super::Voice::Google(
+                Self::PtBr(super::super::Voice::Neural(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
@@ -346,15 +277,9 @@ pub mod neural {
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.pt-BR-Neural2-B")]
-            #[strum(to_string = "Google.pt-BR-Neural2-B")]
-            Neural2B,
-            #[serde(rename = "Google.pt-BR-Wavenet-B")]
-            #[strum(to_string = "Google.pt-BR-Wavenet-B")]
-            WavenetB,
-            #[serde(rename = "Google.pt-BR-Wavenet-E")]
-            #[strum(to_string = "Google.pt-BR-Wavenet-E")]
-            WavenetE,
+            #[serde(rename = "Polly.Thiago-Neural")]
+            #[strum(to_string = "Polly.Thiago-Neural")]
+            ThiagoNeural,
         }
 
         impl VoicePrice for Male {
@@ -371,7 +296,7 @@ pub mod neural {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::PtBr(super::super::Voice::Neural(super::Voice::Google(
+                Self::PtBr(super::super::Voice::Neural(super::Voice::Polly(
                     Voice::Male(value),
                 )))
             }
@@ -403,8 +328,8 @@ pub mod neural {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
     #[serde(untagged)]
     pub enum Voice {
-        Polly(polly::Voice),
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
@@ -416,39 +341,64 @@ pub mod neural {
     impl VoiceGender for Voice {
         fn gender(&self) -> Gender {
             match self {
-                Voice::Polly(voice) => voice.gender(),
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
 }
 
-pub mod generative {
+pub mod standard {
     use super::*;
 
     pub mod google {
         use super::*;
 
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum Female {
+            #[serde(rename = "Google.pt-BR-Standard-C")]
+            #[strum(to_string = "Google.pt-BR-Standard-C")]
+            StandardC,
+            #[serde(rename = "Google.pt-BR-Standard-D")]
+            #[strum(to_string = "Google.pt-BR-Standard-D")]
+            StandardD,
+        }
+
+        impl VoicePrice for Female {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Female {
+            fn gender(&self) -> Gender {
+                Gender::Female
+            }
+        }
+
+        impl From<Female> for crate::twiml::Voice {
+            fn from(value: Female) -> Self {
+                Self::PtBr(super::super::Voice::Standard(super::Voice::Google(
+                    Voice::Female(value),
+                )))
+            }
+        }
+
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Male {
-            #[serde(rename = "Google.pt-BR-Chirp3-HD-Charon")]
-            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Charon")]
-            Chirp3HdCharon,
-            #[serde(rename = "Google.pt-BR-Chirp3-HD-Fenrir")]
-            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Fenrir")]
-            Chirp3HdFenrir,
-            #[serde(rename = "Google.pt-BR-Chirp3-HD-Orus")]
-            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Orus")]
-            Chirp3HdOrus,
-            #[serde(rename = "Google.pt-BR-Chirp3-HD-Puck")]
-            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Puck")]
-            Chirp3HdPuck,
+            #[serde(rename = "Google.pt-BR-Standard-B")]
+            #[strum(to_string = "Google.pt-BR-Standard-B")]
+            StandardB,
+            #[serde(rename = "Google.pt-BR-Standard-E")]
+            #[strum(to_string = "Google.pt-BR-Standard-E")]
+            StandardE,
         }
 
         impl VoicePrice for Male {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -460,32 +410,55 @@ pub mod generative {
 
         impl From<Male> for crate::twiml::Voice {
             fn from(value: Male) -> Self {
-                Self::PtBr(super::super::Voice::Generative(super::Voice::Google(
+                Self::PtBr(super::super::Voice::Standard(super::Voice::Google(
                     Voice::Male(value),
                 )))
             }
         }
 
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+        #[serde(untagged)]
+        pub enum Voice {
+            Female(Female),
+            Male(Male),
+        }
+
+        impl VoicePrice for Voice {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Voice {
+            fn gender(&self) -> Gender {
+                match self {
+                    Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
+                }
+            }
+        }
+    }
+
+    pub mod polly {
+        use super::*;
+
         #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
         #[non_exhaustive]
         pub enum Female {
-            #[serde(rename = "Google.pt-BR-Chirp3-HD-Aoede")]
-            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Aoede")]
-            Chirp3HdAoede,
-            #[serde(rename = "Google.pt-BR-Chirp3-HD-Kore")]
-            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Kore")]
-            Chirp3HdKore,
-            #[serde(rename = "Google.pt-BR-Chirp3-HD-Leda")]
-            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Leda")]
-            Chirp3HdLeda,
-            #[serde(rename = "Google.pt-BR-Chirp3-HD-Zephyr")]
-            #[strum(to_string = "Google.pt-BR-Chirp3-HD-Zephyr")]
-            Chirp3HdZephyr,
+            #[serde(rename = "Polly.Camila")]
+            #[strum(to_string = "Polly.Camila")]
+            Camila,
+            #[serde(rename = "Polly.Vitoria")]
+            #[strum(to_string = "Polly.Vitoria")]
+            Vitoria,
+            #[serde(rename = "Polly.Vitória")]
+            #[strum(to_string = "Polly.Vitória")]
+            Vitória,
         }
 
         impl VoicePrice for Female {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
@@ -497,30 +470,58 @@ pub mod generative {
 
         impl From<Female> for crate::twiml::Voice {
             fn from(value: Female) -> Self {
-                Self::PtBr(super::super::Voice::Generative(super::Voice::Google(
+                Self::PtBr(super::super::Voice::Standard(super::Voice::Polly(
                     Voice::Female(value),
                 )))
             }
         }
 
+        #[derive(Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize, Deserialize)]
+        #[non_exhaustive]
+        pub enum Male {
+            #[serde(rename = "Polly.Ricardo")]
+            #[strum(to_string = "Polly.Ricardo")]
+            Ricardo,
+        }
+
+        impl VoicePrice for Male {
+            fn price(&self) -> Option<PriceType> {
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
+            }
+        }
+
+        impl VoiceGender for Male {
+            fn gender(&self) -> Gender {
+                Gender::Male
+            }
+        }
+
+        impl From<Male> for crate::twiml::Voice {
+            fn from(value: Male) -> Self {
+                Self::PtBr(super::super::Voice::Standard(super::Voice::Polly(
+                    Voice::Male(value),
+                )))
+            }
+        }
+
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
         #[serde(untagged)]
         pub enum Voice {
-            Male(Male),
             Female(Female),
+            Male(Male),
         }
 
         impl VoicePrice for Voice {
             fn price(&self) -> Option<PriceType> {
-                crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+                crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
             }
         }
 
         impl VoiceGender for Voice {
             fn gender(&self) -> Gender {
                 match self {
-                    Voice::Male(_) => Gender::Male,
                     Voice::Female(_) => Gender::Female,
+                    Voice::Male(_) => Gender::Male,
                 }
             }
         }
@@ -530,11 +531,12 @@ pub mod generative {
     #[serde(untagged)]
     pub enum Voice {
         Google(google::Voice),
+        Polly(polly::Voice),
     }
 
     impl VoicePrice for Voice {
         fn price(&self) -> Option<PriceType> {
-            crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE)
+            crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE)
         }
     }
 
@@ -542,6 +544,7 @@ pub mod generative {
         fn gender(&self) -> Gender {
             match self {
                 Voice::Google(voice) => voice.gender(),
+                Voice::Polly(voice) => voice.gender(),
             }
         }
     }
@@ -550,16 +553,16 @@ pub mod generative {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Voice {
-    Standard(standard::Voice),
-    Neural(neural::Voice),
     Generative(generative::Voice),
+    Neural(neural::Voice),
+    Standard(standard::Voice),
 }
 impl VoicePrice for Voice {
     fn price(&self) -> Option<PriceType> {
         match self {
-            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
-            Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
             Voice::Generative(_) => crate::price_type_from_f64_ok(GENERATIVE_VOICE_PRICE),
+            Voice::Neural(_) => crate::price_type_from_f64_ok(NEURAL_VOICE_PRICE),
+            Voice::Standard(_) => crate::price_type_from_f64_ok(STANDARD_VOICE_PRICE),
         }
     }
 }
@@ -567,9 +570,283 @@ impl VoicePrice for Voice {
 impl VoiceGender for Voice {
     fn gender(&self) -> Gender {
         match self {
-            Voice::Standard(voice) => voice.gender(),
-            Voice::Neural(voice) => voice.gender(),
             Voice::Generative(voice) => voice.gender(),
+            Voice::Neural(voice) => voice.gender(),
+            Voice::Standard(voice) => voice.gender(),
         }
     }
 }
+
+/// Every voice for this language, see [`crate::twiml::Voice::all`]
+pub const VOICES: &[VoiceInfo] = &[
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdAoede),
+        ))),
+        id: "Google.pt-BR-Chirp3-HD-Aoede",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdKore),
+        ))),
+        id: "Google.pt-BR-Chirp3-HD-Kore",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdLeda),
+        ))),
+        id: "Google.pt-BR-Chirp3-HD-Leda",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Female(generative::google::Female::Chirp3HdZephyr),
+        ))),
+        id: "Google.pt-BR-Chirp3-HD-Zephyr",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdCharon),
+        ))),
+        id: "Google.pt-BR-Chirp3-HD-Charon",
+        language: Language::PtBr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdFenrir),
+        ))),
+        id: "Google.pt-BR-Chirp3-HD-Fenrir",
+        language: Language::PtBr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdOrus),
+        ))),
+        id: "Google.pt-BR-Chirp3-HD-Orus",
+        language: Language::PtBr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Generative(generative::Voice::Google(
+            generative::google::Voice::Male(generative::google::Male::Chirp3HdPuck),
+        ))),
+        id: "Google.pt-BR-Chirp3-HD-Puck",
+        language: Language::PtBr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Generative,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2A),
+        ))),
+        id: "Google.pt-BR-Neural2-A",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::Neural2C),
+        ))),
+        id: "Google.pt-BR-Neural2-C",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetC),
+        ))),
+        id: "Google.pt-BR-Wavenet-C",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Female(neural::google::Female::WavenetD),
+        ))),
+        id: "Google.pt-BR-Wavenet-D",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::Neural2B),
+        ))),
+        id: "Google.pt-BR-Neural2-B",
+        language: Language::PtBr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetB),
+        ))),
+        id: "Google.pt-BR-Wavenet-B",
+        language: Language::PtBr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Neural(neural::Voice::Google(
+            neural::google::Voice::Male(neural::google::Male::WavenetE),
+        ))),
+        id: "Google.pt-BR-Wavenet-E",
+        language: Language::PtBr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::CamilaNeural),
+        ))),
+        id: "Polly.Camila-Neural",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::VitoriaNeural),
+        ))),
+        id: "Polly.Vitoria-Neural",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Female(neural::polly::Female::VitóriaNeural),
+        ))),
+        id: "Polly.Vitória-Neural",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Neural(neural::Voice::Polly(
+            neural::polly::Voice::Male(neural::polly::Male::ThiagoNeural),
+        ))),
+        id: "Polly.Thiago-Neural",
+        language: Language::PtBr,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Neural,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardC),
+        ))),
+        id: "Google.pt-BR-Standard-C",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Female(standard::google::Female::StandardD),
+        ))),
+        id: "Google.pt-BR-Standard-D",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardB),
+        ))),
+        id: "Google.pt-BR-Standard-B",
+        language: Language::PtBr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Standard(standard::Voice::Google(
+            standard::google::Voice::Male(standard::google::Male::StandardE),
+        ))),
+        id: "Google.pt-BR-Standard-E",
+        language: Language::PtBr,
+        gender: Gender::Male,
+        provider: Provider::Google,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Camila),
+        ))),
+        id: "Polly.Camila",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Vitoria),
+        ))),
+        id: "Polly.Vitoria",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Female(standard::polly::Female::Vitória),
+        ))),
+        id: "Polly.Vitória",
+        language: Language::PtBr,
+        gender: Gender::Female,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+    VoiceInfo {
+        voice: crate::twiml::Voice::PtBr(Voice::Standard(standard::Voice::Polly(
+            standard::polly::Voice::Male(standard::polly::Male::Ricardo),
+        ))),
+        id: "Polly.Ricardo",
+        language: Language::PtBr,
+        gender: Gender::Male,
+        provider: Provider::Polly,
+        tier: VoiceTier::Standard,
+    },
+];
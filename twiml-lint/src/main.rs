@@ -0,0 +1,538 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use twilio_voice::TwilioError;
+use twilio_voice::cost::GeneratedPrices;
+use twilio_voice::twiml::{FormatOptions, Response, Severity, scan_source};
+
+const USAGE: &str = "\
+Usage: twiml-lint [OPTIONS] <PATH>...
+
+Checks TwiML files (or directories of *.xml files) for problems Twilio would
+reject or that probably don't do what was intended.
+
+Options:
+  --cost            Print the itemised text-to-speech cost of each file
+  --pretty          Print each file pretty-printed
+  --indent <N>      Spaces per nesting level for --pretty (default 4)
+  --canonical       Print each file in canonical form (sorted attributes,
+                    normalized whitespace outside <Say>)
+  --write           With --pretty or --canonical, rewrite the files in place
+  --check           With --pretty or --canonical, fail if a file isn't formatted
+  --deny-warnings   Exit with an error status on warnings too
+  -h, --help        Print this help
+
+Exit status: 0 when clean, 1 when problems were found, 2 on usage or I/O errors.";
+
+/// Position prefix of parse errors, e.g. `Reader: 2:14 Unexpected closing tag`
+static POSITION_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(\d+):(\d+):?\s*").unwrap());
+
+/// Voice TwiML verbs Twilio accepts that twilio-voice doesn't model. They're skipped
+/// without a diagnostic, but files containing them can't be formatted or costed.
+/// https://www.twilio.com/docs/voice/twiml
+const UNMODELED_VERBS: &[&str] = &[
+    "Connect", "Dial", "Echo", "Enqueue", "Leave", "Pay", "Record", "Refer", "Reject", "Sms",
+    "Start", "Stop",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Pretty,
+    Canonical,
+}
+
+#[derive(Debug, Default)]
+struct Options {
+    cost: bool,
+    format: Option<Format>,
+    indent: Option<usize>,
+    write: bool,
+    check: bool,
+    deny_warnings: bool,
+    help: bool,
+    paths: Vec<PathBuf>,
+}
+
+/// A problem at a position in a file
+struct Diagnostic {
+    severity: Severity,
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+/// Entry point: lints every file given on the command line
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1);
+    match run(args, &mut io::stdout(), &mut io::stderr()) {
+        Ok(code) => ExitCode::from(code),
+        Err(_) => ExitCode::from(2),
+    }
+}
+
+/// Runs the linter, returning the exit status
+fn run(
+    args: impl Iterator<Item = String>,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> io::Result<u8> {
+    let options = match parse_args(args) {
+        Ok(options) => options,
+        Err(e) => {
+            writeln!(err, "{e}\n\n{USAGE}")?;
+            return Ok(2);
+        }
+    };
+
+    if options.help {
+        writeln!(out, "{USAGE}")?;
+        return Ok(0);
+    }
+
+    let files = match collect_files(&options.paths) {
+        Ok(files) => files,
+        Err(e) => {
+            writeln!(err, "error: {e}")?;
+            return Ok(2);
+        }
+    };
+
+    let (mut errors, mut warnings) = (0, 0);
+    for file in &files {
+        let source = match fs::read_to_string(file) {
+            Ok(source) => source,
+            Err(e) => {
+                writeln!(err, "error: {}: {e}", file.display())?;
+                return Ok(2);
+            }
+        };
+
+        let Linted {
+            response,
+            diagnostics,
+            unmodeled,
+        } = lint(&source);
+        for d in &diagnostics {
+            writeln!(
+                err,
+                "{}:{}:{}: {}: {}",
+                file.display(),
+                d.line,
+                d.column,
+                d.severity,
+                d.message
+            )?;
+            match d.severity {
+                Severity::Error => errors += 1,
+                Severity::Warning => warnings += 1,
+            }
+        }
+        if let Some(name) = unmodeled.first()
+            && (options.cost || options.format.is_some())
+        {
+            writeln!(
+                err,
+                "{}: error: can't format or cost <{name}>, it isn't supported",
+                file.display()
+            )?;
+            errors += 1;
+        }
+        let Some(response) = response else {
+            continue;
+        };
+
+        if options.cost {
+            let report = response.cost_report(&GeneratedPrices);
+            writeln!(out, "{}: estimated TTS cost", file.display())?;
+            for line in report.to_string().lines() {
+                writeln!(out, "  {line}")?;
+            }
+        }
+
+        if let Some(format) = options.format {
+            let formatted = match format {
+                Format::Pretty => {
+                    let mut format_options = FormatOptions::default();
+                    if let Some(indent) = options.indent {
+                        format_options.indent = indent;
+                    }
+                    response.to_xml_pretty_with(&format_options)
+                }
+                Format::Canonical => response.to_xml_canonical(),
+            };
+            let unchanged = formatted.trim_end() == source.trim_end();
+            if options.check {
+                if !unchanged {
+                    writeln!(err, "{}: error: not formatted", file.display())?;
+                    errors += 1;
+                }
+            } else if options.write {
+                if !unchanged && let Err(e) = fs::write(file, format!("{}\n", formatted.trim_end()))
+                {
+                    writeln!(err, "error: {}: {e}", file.display())?;
+                    return Ok(2);
+                }
+            } else {
+                if files.len() > 1 {
+                    writeln!(out, "==> {} <==", file.display())?;
+                }
+                writeln!(out, "{}", formatted.trim_end())?;
+            }
+        }
+    }
+
+    if errors + warnings > 0 {
+        writeln!(
+            err,
+            "{errors} error(s), {warnings} warning(s) in {} file(s)",
+            files.len()
+        )?;
+    }
+
+    if errors > 0 || (options.deny_warnings && warnings > 0) {
+        Ok(1)
+    } else {
+        Ok(0)
+    }
+}
+
+/// Parses the command line into [`Options`]
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--cost" => options.cost = true,
+            "--pretty" => options.format = Some(Format::Pretty),
+            "--canonical" => options.format = Some(Format::Canonical),
+            "--indent" => {
+                let indent = args.next().ok_or("--indent needs a value")?;
+                options.indent = Some(
+                    indent
+                        .parse()
+                        .map_err(|_| format!("invalid --indent value: {indent}"))?,
+                );
+            }
+            "--write" => options.write = true,
+            "--check" => options.check = true,
+            "--deny-warnings" => options.deny_warnings = true,
+            "-h" | "--help" => options.help = true,
+            flag if flag.starts_with('-') => return Err(format!("unknown option: {flag}")),
+            path => options.paths.push(path.into()),
+        }
+    }
+
+    if options.help {
+        return Ok(options);
+    }
+    if options.paths.is_empty() {
+        return Err("no input files".to_string());
+    }
+    if (options.write || options.check) && options.format.is_none() {
+        return Err("--write and --check need --pretty or --canonical".to_string());
+    }
+    if options.write && options.check {
+        return Err("--write and --check can't be combined".to_string());
+    }
+    Ok(options)
+}
+
+/// Expands directories into the *.xml files they contain, sorted
+fn collect_files(paths: &[PathBuf]) -> std::io::Result<Vec<PathBuf>> {
+    fn walk(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+        let mut entries = fs::read_dir(dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        entries.sort();
+        for path in entries {
+            if path.is_dir() {
+                walk(&path, files)?;
+            } else if path.extension().is_some_and(|ext| ext == "xml") {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            walk(path, &mut files)?;
+        } else {
+            files.push(path.clone());
+        }
+    }
+    Ok(files)
+}
+
+/// Parses and validates a TwiML document.
+///
+/// An element that doesn't parse is reported, blanked out and parsing retried, so the rest
+/// of the document is still checked. The response is only returned if nothing was blanked,
+/// as printing or rewriting it would lose those elements.
+struct Linted {
+    /// The parsed file, unless parts of it had to be skipped
+    response: Option<Response>,
+    diagnostics: Vec<Diagnostic>,
+    /// Valid verbs that were skipped because they aren't modeled
+    unmodeled: Vec<String>,
+}
+
+fn lint(source: &str) -> Linted {
+    let mut text = source.to_string();
+    let mut diagnostics = Vec::new();
+    let mut unmodeled = Vec::new();
+    let mut recovered = false;
+    let response = loop {
+        let message = match Response::from_str_exact(&text) {
+            Ok(response) => break response,
+            Err(TwilioError::ResponseDeser(message, _)) => message,
+            Err(e) => e.to_string(),
+        };
+        let diagnostic = parse_error(&message);
+        let element = scan_source(&text)
+            .into_iter()
+            .enumerate()
+            .skip(1)
+            .find(|(_, e)| (e.line, e.column) == (diagnostic.line, diagnostic.column));
+        let Some((_, element)) = element else {
+            // Nothing to skip, so the rest can't be checked
+            diagnostics.push(diagnostic);
+            return Linted {
+                response: None,
+                diagnostics,
+                unmodeled,
+            };
+        };
+        if UNMODELED_VERBS.contains(&element.name.as_str()) {
+            unmodeled.push(element.name.clone());
+        } else {
+            diagnostics.push(diagnostic);
+        }
+        blank_element(&mut text, element.offset);
+        recovered = true;
+    };
+
+    let elements = scan_source(&text);
+    diagnostics.extend(response.validate().into_iter().map(|issue| {
+        let (line, column) = elements
+            .get(issue.element)
+            .map(|e| (e.line, e.column))
+            .unwrap_or((1, 1));
+        Diagnostic {
+            severity: issue.severity,
+            line,
+            column,
+            message: format!("{} ({})", issue.message, issue.path),
+        }
+    }));
+    diagnostics.sort_by_key(|d| (d.line, d.column));
+
+    Linted {
+        response: (!recovered).then_some(response),
+        diagnostics,
+        unmodeled,
+    }
+}
+
+/// Replaces the element starting at `offset`, with its content, by spaces. Line breaks are
+/// kept so that the positions of the other elements don't change.
+fn blank_element(text: &mut String, offset: usize) {
+    let name_end = text[offset + 1..]
+        .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+        .map_or(text.len(), |i| offset + 1 + i);
+    let name = text[offset + 1..name_end].to_string();
+    let tag_end = find_tag_end(text, name_end);
+
+    // Up to the matching end tag, unless self-closing
+    let mut end = tag_end;
+    if !text[..tag_end].ends_with("/>") {
+        let (open, close) = (format!("<{name}"), format!("</{name}>"));
+        let mut depth = 1;
+        let mut pos = tag_end;
+        while depth > 0 {
+            let next_open = text[pos..].find(&open).map(|i| pos + i);
+            let Some(next_close) = text[pos..].find(&close).map(|i| pos + i) else {
+                end = text.len();
+                break;
+            };
+            match next_open {
+                Some(next_open) if next_open < next_close => {
+                    depth += 1;
+                    pos = find_tag_end(text, next_open + open.len());
+                }
+                _ => {
+                    depth -= 1;
+                    pos = next_close + close.len();
+                    end = pos;
+                }
+            }
+        }
+    }
+
+    let blank = text[offset..end]
+        .chars()
+        .map(|c| if c == '\n' { '\n' } else { ' ' })
+        .collect::<String>();
+    text.replace_range(offset..end, &blank);
+}
+
+/// Offset just past the `>` of the tag, ignoring `>` in quoted attribute values
+fn find_tag_end(text: &str, from: usize) -> usize {
+    let mut quote = None;
+    for (i, c) in text[from..].char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return from + i + 1,
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+/// Turns a parse error message into a diagnostic, using the position it mentions if any
+fn parse_error(message: &str) -> Diagnostic {
+    let (line, column, message) = match POSITION_REGEX.captures(message) {
+        Some(caps) => {
+            let whole = caps.get(0).unwrap();
+            let message = format!("{}{}", &message[..whole.start()], &message[whole.end()..]);
+            (
+                caps[1].parse().unwrap_or(1),
+                caps[2].parse().unwrap_or(1),
+                message,
+            )
+        }
+        None => (1, 1, message.to_string()),
+    };
+
+    Diagnostic {
+        severity: Severity::Error,
+        line,
+        column,
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    /// Runs the linter, returning the exit status, stdout and stderr
+    fn lint_files(args: &[&str]) -> (u8, String, String) {
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+        let code = run(args.iter().map(|s| s.to_string()), &mut out, &mut err).unwrap();
+        (
+            code,
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_lint_reports_every_issue() {
+        let source = "<Response>\n  <Bogus/>\n  <Say voice=\"Polly.Nobody\">Hi</Say>\n  <Hangup/><Beep/>\n</Response>";
+        let linted = lint(source);
+        assert!(linted.response.is_none());
+        let found = linted
+            .diagnostics
+            .iter()
+            .map(|d| (d.line, d.column, d.severity))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            [
+                (2, 3, Severity::Error),
+                (3, 3, Severity::Warning),
+                (4, 12, Severity::Error)
+            ]
+        );
+        assert!(linted.diagnostics[1].message.contains("Polly.Nobody"));
+
+        let linted = lint("<Response><Say>Hi</Say></Response>");
+        assert!(linted.response.is_some());
+        assert!(linted.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_lint_skips_unmodeled_verbs() {
+        let source = "<Response>\n  <Say>Hi</Say>\n  <Dial><Number>+15551234567</Number></Dial>\n  <Record maxLength=\"20\"/>\n</Response>";
+        let linted = lint(source);
+        assert!(linted.diagnostics.is_empty());
+        assert_eq!(linted.unmodeled, ["Dial", "Record"]);
+        assert!(linted.response.is_none());
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &[&str]| parse_args(args.iter().map(|s| s.to_string()));
+        assert!(parse(&["--check", "a.xml"]).is_err());
+        assert!(parse(&["--canonical", "--write", "--check", "a.xml"]).is_err());
+        assert!(parse(&["--bogus", "a.xml"]).is_err());
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["--help"]).unwrap().help);
+
+        let options = parse(&["--pretty", "--indent", "2", "--deny-warnings", "a.xml"]).unwrap();
+        assert_eq!(options.format, Some(Format::Pretty));
+        assert_eq!(options.indent, Some(2));
+        assert!(options.deny_warnings);
+    }
+
+    #[test]
+    fn test_exit_status() {
+        let dir = std::env::temp_dir().join(format!("twiml-lint-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let write = |name: &str, source: &str| {
+            let path = dir.join(name);
+            fs::write(&path, source).unwrap();
+            path.display().to_string()
+        };
+        let clean = write(
+            "clean.xml",
+            &Response::from_str("<Response><Hangup/></Response>")
+                .unwrap()
+                .to_xml_canonical(),
+        );
+        let warning = write(
+            "warning.xml",
+            "<Response>\n  <Say voice=\"Polly.Nobody\">Hi</Say>\n</Response>\n",
+        );
+        let error = write("error.xml", "<Response>\n  <Bogus/>\n</Response>\n");
+        let dial = write(
+            "dial.xml",
+            "<Response>\n  <Dial>+15551234567</Dial>\n</Response>\n",
+        );
+
+        assert_eq!(lint_files(&[&clean]), (0, String::new(), String::new()));
+
+        let (code, _, err) = lint_files(&[&warning]);
+        assert_eq!(code, 0);
+        assert!(err.starts_with(&format!("{warning}:2:3: warning: ")));
+        assert_eq!(lint_files(&["--deny-warnings", &warning]).0, 1);
+
+        let (code, _, err) = lint_files(&[&error]);
+        assert_eq!(code, 1);
+        assert!(err.starts_with(&format!("{error}:2:3: error: ")));
+
+        assert_eq!(lint_files(&[&dial]), (0, String::new(), String::new()));
+        let (code, _, err) = lint_files(&["--canonical", &dial]);
+        assert_eq!(code, 1);
+        assert!(err.contains("can't format or cost <Dial>"));
+
+        assert_eq!(lint_files(&["--canonical", "--check", &clean]).0, 0);
+        let (code, _, err) = lint_files(&["--pretty", "--indent", "8", "--check", &clean]);
+        assert_eq!(code, 1);
+        assert!(err.contains("not formatted"));
+
+        let missing = dir.join("missing.xml").display().to_string();
+        assert_eq!(lint_files(&[&missing]).0, 2);
+        assert_eq!(lint_files(&["--write", &clean]).0, 2);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}